@@ -0,0 +1,8 @@
+-- Create player_states table
+-- One row per player: the ECS state a student leaves behind when they
+-- go idle, restored when they next log in.
+CREATE TABLE player_states (
+    player_id TEXT PRIMARY KEY,
+    state JSONB NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -1,21 +1,13 @@
+use crate::domain::persona_logic::ArchetypeCalculationResult;
+use crate::domain::player::get_simulated_character;
 use crate::game::components::*;
 use bevy::prelude::*;
 use common::{PlayerCharacter, QUEST_DATA};
 
-pub fn process_command(world: &mut World, command_text: String) -> PlayerCharacter {
-    let mut player_dto = crate::domain::player::get_simulated_character(); // Use as base
-
-    let mut query = world.query::<(
-        &Name,
-        &mut Persona,
-        &mut VirtueTopology,
-        &mut CognitiveLoad,
-        &mut StoryProgress,
-        &mut Level,
-        &mut Experience,
-    )>();
-
-    for (name, mut persona, _virtues, _load, mut progress, _level, _xp) in query.iter_mut(world) {
+/// Applies a typed quest command ("go north") to one student and returns
+/// their updated character.
+pub fn process_command(world: &mut World, entity: Entity, command_text: String) -> PlayerCharacter {
+    if let Some(mut progress) = world.get_mut::<StoryProgress>(entity) {
         // Logic
         if command_text.starts_with("set_archetype") {
             let parts: Vec<&str> = command_text.splitn(3, ' ').collect();
@@ -25,42 +17,128 @@ pub fn process_command(world: &mut World, command_text: String) -> PlayerCharact
                 info!("Archetype setting not fully implemented in ECS yet");
             }
         } else if let (Some(quest_id), Some(step_id)) = (
-            progress.current_quest_id.as_deref(),
-            progress.current_step_id.as_deref(),
+            progress.current_quest_id.clone(),
+            progress.current_step_id.clone(),
         ) {
-            if let Some(quest) = QUEST_DATA.get(quest_id) {
-                if let Some(step) = quest.steps.get(step_id) {
+            if let Some(quest) = QUEST_DATA.get(&quest_id) {
+                if let Some(step) = quest.steps.get(&step_id) {
                     let command_lower = command_text.trim().to_lowercase();
-                    if let Some(choice) = step.choices.iter().find(|c| {
-                        // Check archetype requirement against Persona
-                        // Need to map int id to Enum or string
-                        true // Bypass for now
-                    }) {
-                        if let Some(choice) =
-                            step.choices.iter().find(|c| c.command == command_lower)
-                        {
-                            if let Some(next_step_data) = quest.steps.get(&choice.next_step) {
-                                progress.current_step_id = Some(choice.next_step.clone());
-                                progress.current_step_description =
-                                    next_step_data.description.clone();
-                                info!("Quest advanced to step: {}", choice.next_step);
-                            }
+                    // TODO: check each choice's required_archetype_id against Persona
+                    if let Some(choice) = step.choices.iter().find(|c| c.command == command_lower) {
+                        if let Some(next_step_data) = quest.steps.get(&choice.next_step) {
+                            progress.current_step_id = Some(choice.next_step.clone());
+                            progress.current_step_description = next_step_data.description.clone();
+                            progress.history.push(step_id);
+                            info!("Quest advanced to step: {}", choice.next_step);
                         }
                     }
                 }
             }
         }
+    }
 
-        // Map back to DTO
+    player_character(world, entity)
+}
+
+/// A student entity as the frontend's PlayerCharacter. What the ECS
+/// doesn't track (race, class, backstory, ...) comes from the simulated
+/// character.
+pub fn player_character(world: &World, entity: Entity) -> PlayerCharacter {
+    let mut player_dto = get_simulated_character(); // Use as base
+    let Ok(student) = world.get_entity(entity) else {
+        return player_dto;
+    };
+
+    // Map back to DTO
+    if let Some(name) = student.get::<Name>() {
         player_dto.name = name.as_str().to_string();
+    }
+    if let Some(progress) = student.get::<StoryProgress>() {
         player_dto.current_quest_id = progress.current_quest_id.clone();
         player_dto.current_step_id = progress.current_step_id.clone();
         player_dto.current_step_description = progress.current_step_description.clone();
         player_dto.inventory = progress.inventory.clone();
         player_dto.quest_flags = progress.quest_flags.clone();
         player_dto.learned_vocab = progress.learned_vocab.clone();
-        // Map other fields as needed
     }
+    if let Some(archetype) = student.get::<ArchetypeStats>() {
+        player_dto.primary_archetype_id = archetype.primary_archetype_id;
+        player_dto.stats = archetype.stats.clone();
+    }
+    // Map other fields as needed
 
     player_dto
 }
+
+/// Records a persona quiz result on a student: their archetype and its stat buffs.
+pub fn apply_quiz_result(world: &mut World, entity: Entity, result: ArchetypeCalculationResult) {
+    if let Some(mut persona) = world.get_mut::<Persona>(entity) {
+        persona.archetype = archetype_from_name(&result.primary_archetype.name);
+    }
+    if let Some(mut archetype) = world.get_mut::<ArchetypeStats>(entity) {
+        archetype.primary_archetype_id = Some(result.primary_archetype.id);
+        archetype.stats = result.stats;
+    }
+}
+
+/// Maps a quiz archetype's name ("The Sage") onto the ECS Archetype.
+fn archetype_from_name(name: &str) -> Archetype {
+    let name = name.to_lowercase();
+    if name.contains("sage") {
+        Archetype::Sage
+    } else if name.contains("hero") {
+        Archetype::Hero
+    } else if name.contains("jester") {
+        Archetype::Jester
+    } else {
+        Archetype::Novice
+    }
+}
+
+/// Nudges a student's virtues for a branching choice about a VAAM subject
+/// word. This is the pedagogical core of the "topological choice mapping":
+/// different concepts reinforce different virtues.
+pub fn apply_subject_word(v: &mut VirtueTopology, subject_word: &str) {
+    match subject_word.to_lowercase().as_str() {
+        "presence" => {
+            v.spirituality += 0.05;  // Grounding
+            v.self_efficacy += 0.02; // Willingness to engage
+        }
+        "bias" => {
+            v.competence += 0.05;    // Inquiry / curiosity
+            v.self_esteem += 0.03;   // Facing uncomfortable truths
+        }
+        "growth" | "resilience" => {
+            v.honor += 0.05;         // Persistence
+            v.self_efficacy += 0.04; // Agency
+            v.self_esteem += 0.03;   // Self-worth
+        }
+        "self-reflection" | "withdrawal" => {
+            v.compassion += 0.04;    // Self-compassion
+            v.interdependence += 0.03;
+        }
+        "conflict" => {
+            v.valor += 0.04;
+            v.self_efficacy += 0.03;
+        }
+        _ => {
+            // Unknown subject words still reward presence
+            v.self_efficacy += 0.01;
+        }
+    }
+}
+
+/// The frontend's view of a student's virtues.
+pub fn virtue_snapshot(v: &VirtueTopology) -> common::expert::VirtueSnapshot {
+    common::expert::VirtueSnapshot {
+        self_efficacy: v.self_efficacy,
+        self_esteem: v.self_esteem,
+        interdependence: v.interdependence,
+        compassion: v.compassion,
+        valor: v.valor,
+        inquiry: v.competence,          // Map competence → inquiry
+        resilience: v.honor,            // Map honor → resilience
+        presence: v.spirituality,       // Map spirituality → presence
+        total_choices: 0,               // TODO: add counter to VirtueTopology
+    }
+}
@@ -1,5 +1,6 @@
 pub mod game_logic;
 pub mod player;
+pub mod player_store;
 pub mod persona_logic;
 pub mod reflection_model;
 pub mod vaam;
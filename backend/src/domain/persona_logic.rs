@@ -2,6 +2,7 @@ use sqlx::{PgPool, Row};
 use std::collections::HashMap;
 use common::{QuizSubmission, Archetype};
 
+#[derive(Debug, Clone)]
 pub struct ArchetypeCalculationResult {
     pub primary_archetype: Archetype,
     pub stats: HashMap<String, i32>,
@@ -6,6 +6,10 @@ use common::{
     RACE_DATA_MAP,       // Our static map of race data
 };
 
+/// The user id of the simulated player; requests that don't name a
+/// player act as them.
+pub const SIMULATED_USER_ID: &str = "user_sim_001";
+
 // --- Simulated Database Function ---
 // This creates a "Totem" character on-the-fly for testing.
 // (Unchanged from previous version)
@@ -29,7 +33,7 @@ pub fn get_simulated_character() -> PlayerCharacter {
     };
     let character = PlayerCharacter {
         id: "char_sim_totem_001".to_string(),
-        user_id: "user_sim_001".to_string(),
+        user_id: SIMULATED_USER_ID.to_string(),
         name: template.name,
         race_name: template.race_name,
         class_name: template.class_name,
@@ -0,0 +1,138 @@
+use crate::game::roster::{Departure, PlayerDirectory, PlayerState};
+use crate::Result;
+use sqlx::{PgPool, Row};
+use std::future::Future;
+use std::time::Duration;
+use tokio::sync::mpsc::UnboundedReceiver;
+
+/// Loads the state a player left behind, if they've played before.
+pub async fn load_player_state(pool: &PgPool, player_id: &str) -> Result<Option<PlayerState>> {
+    let row = sqlx::query("SELECT state FROM player_states WHERE player_id = $1")
+        .bind(player_id)
+        .fetch_optional(pool)
+        .await?;
+
+    Ok(row.and_then(|row| {
+        let state: serde_json::Value = row.get("state");
+        match serde_json::from_value(state) {
+            Ok(state) => Some(state),
+            Err(e) => {
+                // A row we can't read shouldn't lock the player out; they start fresh.
+                tracing::error!("Unreadable state for player {}: {:?}", player_id, e);
+                None
+            }
+        }
+    }))
+}
+
+pub async fn save_player_state(pool: &PgPool, player_id: &str, state: &PlayerState) -> Result<()> {
+    let state_json = serde_json::to_value(state).map_err(|_| crate::AppError::InternalServerError)?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO player_states (player_id, state, updated_at)
+        VALUES ($1, $2, NOW())
+        ON CONFLICT (player_id) DO UPDATE
+        SET state = EXCLUDED.state,
+            updated_at = NOW()
+        "#,
+    )
+    .bind(player_id)
+    .bind(state_json)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// The first wait before retrying a save that failed. Each retry
+/// waits twice as long as the last, up to `SAVE_RETRY_MAX`.
+const SAVE_RETRY_START: Duration = Duration::from_millis(500);
+const SAVE_RETRY_MAX: Duration = Duration::from_secs(30);
+
+/// Saves players as the world hands them over: as they go idle, at each
+/// checkpoint, and at shutdown. Runs until the world stops.
+/// In simulation mode there's nowhere to save to, so departures are dropped.
+pub async fn save_departures(
+    pool: Option<PgPool>,
+    departures: UnboundedReceiver<Departure>,
+    players: PlayerDirectory,
+) {
+    match pool {
+        Some(pool) => {
+            keep_saving(departures, players, SAVE_RETRY_START, |player_id, state| {
+                let pool = pool.clone();
+                async move { save_player_state(&pool, &player_id, &state).await }
+            })
+            .await
+        }
+        None => keep_saving(departures, players, SAVE_RETRY_START, |_, _| async { Ok(()) }).await,
+    }
+}
+
+/// Saves each departure in turn, retrying a failed save with backoff
+/// until it's written. The directory stops holding a departed player's
+/// state only once it has been: until then a player who comes back
+/// picks it up from there.
+async fn keep_saving<F, Fut>(
+    mut departures: UnboundedReceiver<Departure>,
+    players: PlayerDirectory,
+    first_retry: Duration,
+    mut save: F,
+) where
+    F: FnMut(String, PlayerState) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    while let Some((player_id, state)) = departures.recv().await {
+        let mut wait = first_retry;
+        while let Err(e) = save(player_id.clone(), state.clone()).await {
+            tracing::error!("Failed to save player {}, retrying in {:?}: {:?}", player_id, wait, e);
+            tokio::time::sleep(wait).await;
+            wait = (wait * 2).min(SAVE_RETRY_MAX);
+        }
+        players.saved(&player_id, &state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::roster::roster;
+    use crate::AppError;
+    use bevy::prelude::World;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_failed_saves_stay_pending_and_are_retried() {
+        let (directory, mut roster, departures) = roster(Duration::from_secs(60));
+        let mut world = World::new();
+        let state = PlayerState { level: 3, ..Default::default() };
+        roster.join(&mut world, "ana".to_string(), state);
+        roster.leave_all(&mut world);
+        // The world stops, so the saver ends once the queue is drained
+        drop(roster);
+
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let store = {
+            let attempts = attempts.clone();
+            let directory = directory.clone();
+            move |player_id: String, saving: PlayerState| {
+                let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+                // Still pending while the store is down
+                assert_eq!(directory.departing(&player_id).as_ref(), Some(&saving));
+                async move {
+                    if attempt < 2 {
+                        Err(AppError::InternalServerError)
+                    } else {
+                        Ok(())
+                    }
+                }
+            }
+        };
+        keep_saving(departures, directory.clone(), Duration::from_millis(1), store).await;
+
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        assert!(directory.departing("ana").is_none());
+    }
+}
@@ -6,6 +6,8 @@ use axum::{
 use serde_json::json;
 use thiserror::Error;
 
+use crate::game::bus::CommandError;
+
 /// A unified error type for the entire Daydream Backend.
 /// This prevents "Stringly Typed" errors and ensures privacy.
 #[derive(Error, Debug)]
@@ -24,6 +26,27 @@ pub enum AppError {
 
     #[error("Internal Server Error")]
     InternalServerError,
+
+    #[error("Server busy")]
+    Busy,
+
+    #[error("Request timed out")]
+    Timeout,
+}
+
+/// How the Bevy world failing to answer a command surfaces over HTTP.
+impl From<CommandError> for AppError {
+    fn from(e: CommandError) -> Self {
+        match e {
+            CommandError::Busy => AppError::Busy,
+            CommandError::TimedOut => AppError::Timeout,
+            CommandError::NotInWorld => AppError::NotFound,
+            CommandError::WorldGone => {
+                tracing::error!("Command Error: {}", e);
+                AppError::InternalServerError
+            }
+        }
+    }
 }
 
 /// A custom Result type for our application.
@@ -45,6 +68,8 @@ impl IntoResponse for AppError {
                 (StatusCode::INTERNAL_SERVER_ERROR, "Internal Server Error")
             }
             AppError::InternalServerError => (StatusCode::INTERNAL_SERVER_ERROR, "Internal Server Error"),
+            AppError::Busy => (StatusCode::SERVICE_UNAVAILABLE, "Server busy, try again"),
+            AppError::Timeout => (StatusCode::GATEWAY_TIMEOUT, "Request timed out"),
         };
 
         let body = Json(json!({
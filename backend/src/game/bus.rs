@@ -0,0 +1,317 @@
+// --- Command Bus ---
+// The typed way into the Bevy world from Axum handlers. A handler sends
+// a Command over a bounded channel; apply_commands drains it at the top
+// of each frame, with full access to the World, and answers on the
+// command's oneshot reply.
+//
+// The channel's capacity is the backpressure: a handler waits for room,
+// and gives up with Busy if the world can't make room before its
+// deadline. The same deadline covers the reply, so a stalled world
+// answers TimedOut rather than hanging the request.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use common::expert::VirtueSnapshot;
+use common::PlayerCharacter;
+use thiserror::Error;
+use tokio::sync::mpsc;
+use tokio::sync::oneshot;
+use tokio::time::{timeout_at, Instant};
+
+use crate::domain::game_logic::{
+    apply_quiz_result, apply_subject_word, player_character, process_command, virtue_snapshot,
+};
+use crate::domain::persona_logic::ArchetypeCalculationResult;
+use crate::game::components::VirtueTopology;
+use crate::game::roster::{PlayerState, Roster};
+
+/// Commands that can be waiting for the world at once.
+pub const BUS_CAPACITY: usize = 256;
+
+/// How long a handler waits for room on the bus and for its reply, together.
+pub const REPLY_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Where the world answers a command.
+pub type Reply<T> = oneshot::Sender<Result<T, CommandError>>;
+
+/// Everything the HTTP layer can ask of the world.
+#[derive(Debug)]
+pub enum Command {
+    /// Bring a player into the world with this state, unless they're
+    /// already there.
+    Join { player_id: String, state: Box<PlayerState> },
+    /// The player's character as it stands.
+    Character {
+        player_id: String,
+        reply: Reply<PlayerCharacter>,
+    },
+    /// A typed quest command ("go north").
+    Submit {
+        player_id: String,
+        command_text: String,
+        reply: Reply<PlayerCharacter>,
+    },
+    /// The persona quiz's scored result.
+    Quiz {
+        player_id: String,
+        result: ArchetypeCalculationResult,
+        reply: Reply<PlayerCharacter>,
+    },
+    /// A branching choice about a VAAM subject word.
+    Choice {
+        player_id: String,
+        subject_word: String,
+        reply: Reply<VirtueSnapshot>,
+    },
+    /// The server is going down: every player departs, to be saved, and
+    /// the world stops.
+    Shutdown { reply: Reply<()> },
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandError {
+    #[error("The world's command queue is full")]
+    Busy,
+
+    #[error("The world didn't answer in time")]
+    TimedOut,
+
+    #[error("The world has stopped")]
+    WorldGone,
+
+    #[error("Player is not in the world")]
+    NotInWorld,
+}
+
+/// The HTTP side of the bus. Cheap to clone into AppState.
+#[derive(Clone)]
+pub struct CommandBus {
+    commands: mpsc::Sender<Command>,
+    timeout: Duration,
+}
+
+impl CommandBus {
+    /// Queues a command, waiting for room until the deadline.
+    pub async fn send(&self, command: Command) -> Result<(), CommandError> {
+        self.send_by(Instant::now() + self.timeout, command).await
+    }
+
+    /// Sends the command built around a reply, and waits for the world's answer.
+    pub async fn request<T>(
+        &self,
+        command: impl FnOnce(Reply<T>) -> Command,
+    ) -> Result<T, CommandError> {
+        let deadline = Instant::now() + self.timeout;
+        let (reply, answer) = oneshot::channel();
+        self.send_by(deadline, command(reply)).await?;
+        match timeout_at(deadline, answer).await {
+            Ok(Ok(result)) => result,
+            // The world dropped the command without answering
+            Ok(Err(_)) => Err(CommandError::WorldGone),
+            Err(_) => Err(CommandError::TimedOut),
+        }
+    }
+
+    async fn send_by(&self, deadline: Instant, command: Command) -> Result<(), CommandError> {
+        match timeout_at(deadline, self.commands.send(command)).await {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(_)) => Err(CommandError::WorldGone),
+            Err(_) => Err(CommandError::Busy),
+        }
+    }
+}
+
+/// The world side of the bus.
+#[derive(Resource)]
+pub struct BusInbox(mpsc::Receiver<Command>);
+
+/// Builds both halves of the bus.
+pub fn channel(capacity: usize, timeout: Duration) -> (CommandBus, BusInbox) {
+    let (commands, inbox) = mpsc::channel(capacity);
+    (CommandBus { commands, timeout }, BusInbox(inbox))
+}
+
+// --- Systems ---
+
+/// Applies the commands waiting on the bus, oldest first. Exclusive, so a
+/// player who joins is there for the next command. Takes at most a full
+/// queue per frame, so a flood can't stall the frame.
+pub fn apply_commands(world: &mut World) {
+    world.resource_scope(|world, mut inbox: Mut<BusInbox>| {
+        world.resource_scope(|world, mut roster: Mut<Roster>| {
+            for _ in 0..inbox.0.max_capacity() {
+                let Ok(command) = inbox.0.try_recv() else {
+                    break;
+                };
+                apply(world, &mut roster, command);
+            }
+        });
+    });
+}
+
+fn apply(world: &mut World, roster: &mut Roster, command: Command) {
+    match command {
+        Command::Join { player_id, state } => roster.join(world, player_id, *state),
+        Command::Character { player_id, reply } => {
+            let result = roster
+                .touch(world, &player_id)
+                .map(|entity| player_character(world, entity));
+            answer(reply, result);
+        }
+        Command::Submit { player_id, command_text, reply } => {
+            let result = roster
+                .touch(world, &player_id)
+                .map(|entity| process_command(world, entity, command_text));
+            answer(reply, result);
+        }
+        Command::Quiz { player_id, result, reply } => {
+            let result = roster.touch(world, &player_id).map(|entity| {
+                apply_quiz_result(world, entity, result);
+                player_character(world, entity)
+            });
+            answer(reply, result);
+        }
+        Command::Choice { player_id, subject_word, reply } => {
+            let mut virtues = roster
+                .touch(world, &player_id)
+                .and_then(|entity| world.get_mut::<VirtueTopology>(entity));
+            let result = virtues.as_mut().map(|virtues| {
+                apply_subject_word(virtues, &subject_word);
+                virtue_snapshot(virtues)
+            });
+            answer(reply, result);
+        }
+        Command::Shutdown { reply } => {
+            roster.leave_all(world);
+            world.write_message(AppExit::Success);
+            answer(reply, Some(()));
+        }
+    }
+}
+
+fn answer<T>(reply: Reply<T>, result: Option<T>) {
+    // The handler may have timed out and gone; nobody's left to tell.
+    let _ = reply.send(result.ok_or(CommandError::NotInWorld));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::player::get_simulated_character;
+    use crate::game::roster::{roster, sync_players_to_shared, PlayerDirectory};
+    use std::collections::HashMap;
+
+    fn world_with(capacity: usize, timeout: Duration) -> (App, CommandBus, PlayerDirectory) {
+        let (bus, inbox) = channel(capacity, timeout);
+        let (directory, mut roster, _) = roster(Duration::from_secs(60));
+        let mut app = App::new();
+        for player_id in ["ana", "ben"] {
+            let state = PlayerState::fresh(get_simulated_character());
+            roster.join(app.world_mut(), player_id.to_string(), state);
+        }
+        app.insert_resource(roster);
+        app.insert_resource(inbox);
+        app.add_systems(Update, (apply_commands, sync_players_to_shared).chain());
+        (app, bus, directory)
+    }
+
+    /// Runs a request against the world, updating it once the command is queued.
+    async fn ask<T>(
+        app: &mut App,
+        bus: &CommandBus,
+        command: impl FnOnce(Reply<T>) -> Command,
+    ) -> Result<T, CommandError> {
+        let (result, _) = tokio::join!(bus.request(command), async {
+            tokio::task::yield_now().await;
+            app.update();
+        });
+        result
+    }
+
+    #[tokio::test]
+    async fn test_choice_replies_with_that_players_virtues() {
+        let (mut app, bus, directory) = world_with(8, REPLY_TIMEOUT);
+        let snapshot = ask(&mut app, &bus, |reply| Command::Choice {
+            player_id: "ana".to_string(),
+            subject_word: "Conflict".to_string(),
+            reply,
+        })
+        .await
+        .unwrap();
+
+        assert!(snapshot.valor > 0.0);
+        assert_eq!(directory.get("ana").unwrap().virtue_topology.valor, snapshot.valor);
+        assert_eq!(directory.get("ben").unwrap().virtue_topology.valor, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_quiz_result_lands_on_the_character() {
+        let (mut app, bus, directory) = world_with(8, REPLY_TIMEOUT);
+        let result = ArchetypeCalculationResult {
+            primary_archetype: common::Archetype {
+                id: 3,
+                name: "The Sage".to_string(),
+                description: String::new(),
+            },
+            stats: HashMap::from([("Wisdom".to_string(), 2)]),
+        };
+        let character = ask(&mut app, &bus, |reply| Command::Quiz {
+            player_id: "ben".to_string(),
+            result,
+            reply,
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(character.primary_archetype_id, Some(3));
+        assert_eq!(character.stats.get("Wisdom"), Some(&2));
+        let ben = directory.get("ben").unwrap();
+        assert_eq!(ben.persona.archetype, crate::game::components::Archetype::Sage);
+        assert_eq!(ben.archetype_stats.primary_archetype_id, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_player_is_not_in_world() {
+        let (mut app, bus, _) = world_with(8, REPLY_TIMEOUT);
+        let result = ask(&mut app, &bus, |reply| Command::Character {
+            player_id: "cy".to_string(),
+            reply,
+        })
+        .await;
+
+        assert_eq!(result.unwrap_err(), CommandError::NotInWorld);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_empties_the_world_and_stops_it() {
+        let (mut app, bus, directory) = world_with(8, REPLY_TIMEOUT);
+        ask(&mut app, &bus, |reply| Command::Shutdown { reply }).await.unwrap();
+
+        assert!(app.world().resource::<Roster>().is_empty());
+        assert!(directory.get("ana").is_none());
+        assert_eq!(app.should_exit(), Some(AppExit::Success));
+    }
+
+    #[tokio::test]
+    async fn test_full_bus_is_busy_and_silent_world_times_out() {
+        let timeout = Duration::from_millis(20);
+        let (mut app, bus, _) = world_with(2, timeout);
+        // Two joins fill the bus until the world runs.
+        for player_id in ["cy", "di"] {
+            let join = Command::Join {
+                player_id: player_id.to_string(),
+                state: Box::default(),
+            };
+            bus.send(join).await.unwrap();
+        }
+        let character = |reply| Command::Character {
+            player_id: "ana".to_string(),
+            reply,
+        };
+        assert_eq!(bus.request(character).await.unwrap_err(), CommandError::Busy);
+
+        app.update();
+        assert_eq!(bus.request(character).await.unwrap_err(), CommandError::TimedOut);
+    }
+}
@@ -1,13 +1,4 @@
-use bevy::prelude::Resource;
 use bevy::prelude::*;
-use std::sync::{Arc, RwLock};
-
-// Define wrapper resources for Bevy
-#[derive(Resource)]
-pub struct SharedResearchLogResource(pub Arc<RwLock<ResearchLog>>);
-
-#[derive(Resource)]
-pub struct SharedVirtuesResource(pub Arc<RwLock<VirtueTopology>>);
 
 use serde::{Deserialize, Serialize};
 
@@ -88,6 +79,14 @@ pub struct StoryProgress {
     pub learned_vocab: std::collections::HashSet<String>,
 }
 
+// ArchetypeStats: The persona quiz's result — the primary archetype and its stat buffs
+#[derive(Component, Reflect, Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct ArchetypeStats {
+    pub primary_archetype_id: Option<i32>,
+    pub stats: std::collections::HashMap<String, i32>,
+}
+
 // --- Legacy / LitRPG Components (Kept for compatibility) ---
 
 #[derive(Component, Reflect, Default, Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -119,6 +118,7 @@ pub struct StudentBundle {
     pub cognitive_load: CognitiveLoad,
     pub story_progress: StoryProgress,
     pub research_log: ResearchLog,
+    pub archetype_stats: ArchetypeStats,
     pub name: Name,
     pub level: Level,
     pub xp: Experience,
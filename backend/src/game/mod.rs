@@ -1,2 +1,4 @@
+pub mod bus;
 pub mod components;
+pub mod roster;
 pub mod systems;
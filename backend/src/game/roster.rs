@@ -0,0 +1,454 @@
+// --- Player Roster ---
+// The backend world holds one student entity per player, keyed by
+// player id. Axum handlers never touch the world directly: they send
+// Commands in over the bus (game::bus) and read a mirror of each
+// player's state out through the PlayerDirectory in AppState.
+//
+// A player is spawned when they log in — restored from storage if
+// they've played before — and despawned once they've been idle for a
+// while; requests for a player who isn't in the world are not found.
+// Their final state is handed back over the departures channel so it
+// can be saved off the Bevy thread, and kept in the directory until
+// the save is written: a player who logs back in between picks up
+// where they left off, not from the older save storage still holds.
+// Players who stay are checkpointed down the same channel every few
+// minutes, and everyone left is sent down it when the server shuts down.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use bevy::ecs::query::QueryData;
+use bevy::prelude::*;
+use common::PlayerCharacter;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+use crate::game::components::*;
+
+/// How long a player may go without a request before being despawned.
+pub const DEFAULT_IDLE_SECS: u64 = 15 * 60;
+
+/// How often players still in the world are saved, if they've changed.
+pub const DEFAULT_CHECKPOINT_SECS: u64 = 5 * 60;
+
+/// The player's id, as the HTTP layer knows them.
+#[derive(Component, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PlayerId(pub String);
+
+/// When a request last named this player.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct LastSeen(pub Instant);
+
+/// Everything the world keeps about one player: what the HTTP layer
+/// reads, and what's written to storage between sessions.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerState {
+    pub name: String,
+    pub persona: Persona,
+    pub virtue_topology: VirtueTopology,
+    pub cognitive_load: CognitiveLoad,
+    pub story_progress: StoryProgress,
+    pub research_log: ResearchLog,
+    pub archetype_stats: ArchetypeStats,
+    pub level: u32,
+    pub xp: u32,
+}
+
+impl PlayerState {
+    /// A new player, starting at the beginning of their character's quest.
+    pub fn fresh(character: PlayerCharacter) -> Self {
+        Self {
+            name: character.name,
+            persona: Persona {
+                archetype: Archetype::Novice,
+                shadow_trait: "None".to_string(),
+                projective_dissonance: 0.0,
+            },
+            story_progress: StoryProgress {
+                current_quest_id: character.current_quest_id,
+                current_step_id: character.current_step_id,
+                current_step_description: character.current_step_description,
+                history: Vec::new(),
+                inventory: character.inventory,
+                quest_flags: character.quest_flags,
+                learned_vocab: character.learned_vocab,
+            },
+            level: 1,
+            ..Default::default()
+        }
+    }
+
+    fn into_bundle(self) -> StudentBundle {
+        StudentBundle {
+            name: Name::new(self.name),
+            persona: self.persona,
+            virtue_topology: self.virtue_topology,
+            cognitive_load: self.cognitive_load,
+            story_progress: self.story_progress,
+            research_log: self.research_log,
+            archetype_stats: self.archetype_stats,
+            level: Level(self.level),
+            xp: Experience(self.xp),
+        }
+    }
+}
+
+/// A player's entity, read whole.
+#[derive(QueryData)]
+pub struct Student {
+    pub id: &'static PlayerId,
+    pub name: &'static Name,
+    pub persona: &'static Persona,
+    pub virtue_topology: &'static VirtueTopology,
+    pub cognitive_load: &'static CognitiveLoad,
+    pub story_progress: &'static StoryProgress,
+    pub research_log: &'static ResearchLog,
+    pub archetype_stats: &'static ArchetypeStats,
+    pub level: &'static Level,
+    pub xp: &'static Experience,
+}
+
+impl StudentItem<'_, '_> {
+    pub fn state(&self) -> PlayerState {
+        PlayerState {
+            name: self.name.as_str().to_string(),
+            persona: self.persona.clone(),
+            virtue_topology: *self.virtue_topology,
+            cognitive_load: *self.cognitive_load,
+            story_progress: self.story_progress.clone(),
+            research_log: self.research_log.clone(),
+            archetype_stats: self.archetype_stats.clone(),
+            level: self.level.0,
+            xp: self.xp.0,
+        }
+    }
+}
+
+/// A player's id and state, for saving: on going idle, at a checkpoint,
+/// or at shutdown.
+pub type Departure = (String, PlayerState);
+
+type Mirror = Arc<RwLock<HashMap<String, PlayerState>>>;
+
+/// The HTTP side of the roster. Cheap to clone into AppState.
+#[derive(Clone)]
+pub struct PlayerDirectory {
+    players: Mirror,
+    /// Players who've left the world and aren't saved yet.
+    saving: Mirror,
+}
+
+impl PlayerDirectory {
+    /// The player's last synced state, if they're in the world.
+    pub fn get(&self, player_id: &str) -> Option<PlayerState> {
+        self.players.read().ok()?.get(player_id).cloned()
+    }
+
+    /// The state a player left the world with, while it's still being
+    /// saved. Newer than what storage holds.
+    pub fn departing(&self, player_id: &str) -> Option<PlayerState> {
+        self.saving.read().ok()?.get(player_id).cloned()
+    }
+
+    /// Storage has caught up with this state. A player who left again
+    /// since, with other state, stays pending.
+    pub fn saved(&self, player_id: &str, state: &PlayerState) {
+        if let Ok(mut saving) = self.saving.write() {
+            if saving.get(player_id) == Some(state) {
+                saving.remove(player_id);
+            }
+        }
+    }
+}
+
+/// The world side of the roster.
+#[derive(Resource)]
+pub struct Roster {
+    index: HashMap<String, Entity>,
+    idle_after: Duration,
+    players: Mirror,
+    saving: Mirror,
+    departures: UnboundedSender<Departure>,
+}
+
+impl Roster {
+    pub fn entity(&self, player_id: &str) -> Option<Entity> {
+        self.index.get(player_id).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// Spawns the player with this state, unless they're already in the world.
+    pub fn join(&mut self, world: &mut World, player_id: String, state: PlayerState) {
+        if self.touch(world, &player_id).is_some() {
+            return;
+        }
+        let entity = world
+            .spawn((
+                state.clone().into_bundle(),
+                PlayerId(player_id.clone()),
+                LastSeen(Instant::now()),
+            ))
+            .id();
+        info!("Player {} joined ({} in world)", player_id, self.len() + 1);
+        if let Ok(mut players) = self.players.write() {
+            players.insert(player_id.clone(), state);
+        }
+        self.index.insert(player_id, entity);
+    }
+
+    /// Despawns every player, handing their state to the departures
+    /// channel. For shutdown: nobody's left unsaved.
+    pub fn leave_all(&mut self, world: &mut World) {
+        let mut query = world.query::<Student>();
+        for student in query.iter(world) {
+            self.depart(student.id.0.clone(), student.state());
+        }
+        for (_, entity) in self.index.drain() {
+            world.despawn(entity);
+        }
+        if let Ok(mut players) = self.players.write() {
+            players.clear();
+        }
+    }
+
+    /// Hands a player's state over to be saved, pending in the directory
+    /// until it is, and takes them out of the mirror.
+    fn depart(&self, player_id: String, state: PlayerState) {
+        // Pending before they leave the mirror, so the directory always has them
+        if let Ok(mut saving) = self.saving.write() {
+            saving.insert(player_id.clone(), state.clone());
+        }
+        if let Ok(mut players) = self.players.write() {
+            players.remove(&player_id);
+        }
+        let _ = self.departures.send((player_id, state));
+    }
+
+    /// The player's entity, marked as just seen.
+    pub fn touch(&self, world: &mut World, player_id: &str) -> Option<Entity> {
+        let entity = self.entity(player_id)?;
+        world.get_mut::<LastSeen>(entity)?.0 = Instant::now();
+        Some(entity)
+    }
+}
+
+/// Builds both halves of the roster, and the receiver of departing
+/// players' state.
+pub fn roster(idle_after: Duration) -> (PlayerDirectory, Roster, UnboundedReceiver<Departure>) {
+    let players = Mirror::default();
+    let saving = Mirror::default();
+    let (departures_tx, departures_rx) = unbounded_channel();
+    let directory = PlayerDirectory {
+        players: players.clone(),
+        saving: saving.clone(),
+    };
+    let roster = Roster {
+        index: HashMap::new(),
+        idle_after,
+        players,
+        saving,
+        departures: departures_tx,
+    };
+    (directory, roster, departures_rx)
+}
+
+// --- Systems ---
+
+/// Any of the state the HTTP layer reads, or storage keeps, has changed.
+type StudentChanged = Or<(
+    Changed<Name>,
+    Changed<Persona>,
+    Changed<VirtueTopology>,
+    Changed<CognitiveLoad>,
+    Changed<StoryProgress>,
+    Changed<ResearchLog>,
+    Changed<ArchetypeStats>,
+    Changed<Level>,
+    Changed<Experience>,
+)>;
+
+/// Mirrors changed players out to the HTTP layer.
+pub fn sync_players_to_shared(query: Query<Student, StudentChanged>, roster: Res<Roster>) {
+    if query.is_empty() {
+        return;
+    }
+    if let Ok(mut players) = roster.players.write() {
+        for student in query.iter() {
+            players.insert(student.id.0.clone(), student.state());
+        }
+    }
+}
+
+/// Hands the state of players who changed since the last checkpoint to
+/// the departures channel, to be saved while they play on. Run it on a
+/// timer: what counts as changed is relative to its last run.
+pub fn checkpoint_players(query: Query<Student, StudentChanged>, roster: Res<Roster>) {
+    for student in query.iter() {
+        let _ = roster.departures.send((student.id.0.clone(), student.state()));
+    }
+}
+
+/// Despawns players nobody has asked about for `idle_after`, handing
+/// their state to the departures channel.
+pub fn despawn_idle_players(
+    mut commands: Commands,
+    mut roster: ResMut<Roster>,
+    query: Query<(Entity, &LastSeen, Student)>,
+) {
+    let idle_after = roster.idle_after;
+    for (entity, last_seen, student) in query.iter() {
+        if last_seen.0.elapsed() < idle_after {
+            continue;
+        }
+        let player_id = student.id.0.clone();
+        roster.index.remove(&player_id);
+        roster.depart(player_id.clone(), student.state());
+        commands.entity(entity).despawn();
+        info!("Player {} went idle ({} in world)", player_id, roster.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::player::get_simulated_character;
+
+    fn world_with(idle_after: Duration) -> (App, PlayerDirectory, UnboundedReceiver<Departure>) {
+        let (directory, roster, departures) = roster(idle_after);
+        let mut app = App::new();
+        app.insert_resource(roster);
+        app.add_systems(Update, (sync_players_to_shared, despawn_idle_players).chain());
+        (app, directory, departures)
+    }
+
+    fn join(app: &mut App, player_id: &str, state: PlayerState) {
+        app.world_mut().resource_scope(|world, mut roster: Mut<Roster>| {
+            roster.join(world, player_id.to_string(), state)
+        });
+    }
+
+    #[test]
+    fn test_one_entity_per_player() {
+        let (mut app, directory, _) = world_with(Duration::from_secs(60));
+        let fresh = PlayerState::fresh(get_simulated_character());
+        join(&mut app, "ana", fresh.clone());
+        join(&mut app, "ben", fresh.clone());
+        join(&mut app, "ana", PlayerState::default());
+        app.update();
+
+        let roster = app.world().resource::<Roster>();
+        assert_eq!(roster.len(), 2);
+        assert_ne!(roster.entity("ana"), roster.entity("ben"));
+        // A second join doesn't reset the player.
+        assert_eq!(directory.get("ana").unwrap().level, 1);
+        assert!(directory.get("cy").is_none());
+    }
+
+    #[test]
+    fn test_restored_state_is_kept() {
+        let (mut app, directory, _) = world_with(Duration::from_secs(60));
+        let mut stored = PlayerState::fresh(get_simulated_character());
+        stored.level = 4;
+        stored.virtue_topology.honesty = 0.7;
+        join(&mut app, "ana", stored.clone());
+        app.update();
+
+        assert_eq!(directory.get("ana"), Some(stored));
+    }
+
+    #[test]
+    fn test_checkpoints_save_only_changed_players() {
+        let (mut app, _, mut departures) = world_with(Duration::from_secs(60));
+        app.add_systems(Update, checkpoint_players.after(sync_players_to_shared));
+        join(&mut app, "ana", PlayerState::default());
+        join(&mut app, "ben", PlayerState::default());
+        app.update();
+        assert_eq!(std::iter::from_fn(|| departures.try_recv().ok()).count(), 2);
+
+        app.update();
+        assert!(departures.try_recv().is_err());
+
+        let ben = app.world().resource::<Roster>().entity("ben").unwrap();
+        app.world_mut().get_mut::<VirtueTopology>(ben).unwrap().valor = 0.2;
+        app.update();
+        let (player_id, state) = departures.try_recv().unwrap();
+        assert_eq!(player_id, "ben");
+        assert_eq!(state.virtue_topology.valor, 0.2);
+        assert!(departures.try_recv().is_err());
+        assert_eq!(app.world().resource::<Roster>().len(), 2);
+
+        // Levelling up counts, though none of the virtues moved
+        app.world_mut().get_mut::<Level>(ben).unwrap().0 = 2;
+        app.update();
+        let (player_id, state) = departures.try_recv().unwrap();
+        assert_eq!(player_id, "ben");
+        assert_eq!(state.level, 2);
+    }
+
+    #[test]
+    fn test_everyone_leaves_at_shutdown() {
+        let (mut app, directory, mut departures) = world_with(Duration::from_secs(60));
+        join(&mut app, "ana", PlayerState::default());
+        join(&mut app, "ben", PlayerState::default());
+        app.update();
+
+        app.world_mut().resource_scope(|world, mut roster: Mut<Roster>| roster.leave_all(world));
+        let mut saved: Vec<String> = std::iter::from_fn(|| departures.try_recv().ok())
+            .map(|(player_id, _)| player_id)
+            .collect();
+        saved.sort();
+        assert_eq!(saved, ["ana", "ben"]);
+        assert!(app.world().resource::<Roster>().is_empty());
+        assert!(directory.get("ana").is_none());
+        assert_eq!(app.world_mut().query::<&PlayerId>().iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn test_idle_players_depart_with_their_state() {
+        let (mut app, directory, mut departures) = world_with(Duration::ZERO);
+        let mut state = PlayerState::default();
+        state.virtue_topology.competence = 0.3;
+        join(&mut app, "ana", state);
+        app.update();
+
+        assert!(app.world().resource::<Roster>().is_empty());
+        assert!(directory.get("ana").is_none());
+        let (player_id, state) = departures.try_recv().unwrap();
+        assert_eq!(player_id, "ana");
+        assert_eq!(state.virtue_topology.competence, 0.3);
+    }
+
+    #[test]
+    fn test_departing_players_are_kept_until_saved() {
+        let (mut app, directory, mut departures) = world_with(Duration::ZERO);
+        let mut state = PlayerState {
+            level: 3,
+            ..Default::default()
+        };
+        join(&mut app, "ana", state.clone());
+        app.update();
+        let (_, first) = departures.try_recv().unwrap();
+
+        // Back before the save is written: the state they left with
+        assert_eq!(directory.departing("ana"), Some(state.clone()));
+        state.level = 4;
+        join(&mut app, "ana", state.clone());
+        app.update();
+        let (_, second) = departures.try_recv().unwrap();
+
+        // The older save landing doesn't drop the newer one
+        directory.saved("ana", &first);
+        assert_eq!(directory.departing("ana"), Some(state));
+        directory.saved("ana", &second);
+        assert!(directory.departing("ana").is_none());
+    }
+}
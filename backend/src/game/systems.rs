@@ -122,19 +122,3 @@ pub fn sync_yarn_to_story_progress(
         }
     }
 }
-
-// System to sync ECS components to Shared Resources (for Axum)
-pub fn sync_ecs_to_shared(
-    query: Query<(&ResearchLog, &VirtueTopology), Changed<ResearchLog>>,
-    shared_log: Res<crate::game::components::SharedResearchLogResource>,
-    shared_virtues: Res<crate::game::components::SharedVirtuesResource>,
-) {
-    for (log, virtues) in query.iter() {
-        if let Ok(mut shared_log_guard) = shared_log.0.write() {
-            *shared_log_guard = log.clone();
-        }
-        if let Ok(mut shared_virtues_guard) = shared_virtues.0.write() {
-            *shared_virtues_guard = virtues.clone();
-        }
-    }
-}
@@ -1,3 +1,5 @@
+use crate::game::bus::Command;
+use crate::handlers::player::PlayerKey;
 use crate::{AppError, AppState, Result};
 use axum::{extract::State, Json};
 use common::expert::StoryGraph;
@@ -100,64 +102,25 @@ pub async fn save_graph(
 /// This handler bridges the frontend's choice events into the Bevy ECS
 /// state engine. Each VAAM subject word maps to specific virtue adjustments,
 /// making the "topological choice mapping" from the Daydream Bible quantitative.
+/// The choice is applied to the player's own entity inside the world, and
+/// the snapshot returned is what the world made of it.
 pub async fn submit_choice(
     State(app_state): State<AppState>,
+    PlayerKey(player_id): PlayerKey,
     Json(payload): Json<common::expert::ChoiceAction>,
 ) -> Result<Json<common::expert::VirtueSnapshot>> {
-    // Map the subject word to virtue adjustments
-    // This is the pedagogical core: different concepts reinforce different virtues
-    let virtues = app_state.shared_virtues.clone();
-
-    {
-        let mut v = virtues.write().map_err(|_| AppError::InternalServerError)?;
-
-        match payload.subject_word.to_lowercase().as_str() {
-            "presence" => {
-                v.spirituality += 0.05;  // Grounding
-                v.self_efficacy += 0.02; // Willingness to engage
-            }
-            "bias" => {
-                v.competence += 0.05;    // Inquiry / curiosity
-                v.self_esteem += 0.03;   // Facing uncomfortable truths
-            }
-            "growth" | "resilience" => {
-                v.honor += 0.05;         // Persistence
-                v.self_efficacy += 0.04; // Agency
-                v.self_esteem += 0.03;   // Self-worth
-            }
-            "self-reflection" | "withdrawal" => {
-                v.compassion += 0.04;    // Self-compassion
-                v.interdependence += 0.03;
-            }
-            "conflict" => {
-                v.valor += 0.04;
-                v.self_efficacy += 0.03;
-            }
-            _ => {
-                // Unknown subject words still reward presence
-                v.self_efficacy += 0.01;
-            }
-        }
-    }
-
-    // Read back the updated snapshot
-    let snapshot = {
-        let v = virtues.read().map_err(|_| AppError::InternalServerError)?;
-        common::expert::VirtueSnapshot {
-            self_efficacy: v.self_efficacy,
-            self_esteem: v.self_esteem,
-            interdependence: v.interdependence,
-            compassion: v.compassion,
-            valor: v.valor,
-            inquiry: v.competence,          // Map competence → inquiry
-            resilience: v.honor,            // Map honor → resilience
-            presence: v.spirituality,       // Map spirituality → presence
-            total_choices: 0,               // TODO: add counter to VirtueTopology
-        }
-    };
+    let snapshot = app_state
+        .bus
+        .request(|reply| Command::Choice {
+            player_id: player_id.clone(),
+            subject_word: payload.subject_word.clone(),
+            reply,
+        })
+        .await?;
 
     tracing::info!(
-        "Choice processed: node={}, word={}, choice={}",
+        "Choice processed: player={}, node={}, word={}, choice={}",
+        player_id,
         payload.node_id,
         payload.subject_word,
         payload.choice_id
@@ -101,14 +101,41 @@ pub async fn get_archetypes(
 }
 
 use crate::domain::persona_logic::calculate_archetype;
-use crate::domain::player::get_simulated_character;
+use crate::game::bus::Command;
+use crate::handlers::player::PlayerKey;
+use crate::AppError;
 use common::{PlayerCharacter, QuizSubmission};
-use tokio::sync::oneshot;
 
+/// Scores the persona quiz and records the archetype on the player's entity.
+/// In simulation mode there's nothing to score against, so the character
+/// comes back as it stands.
 pub async fn submit_quiz(
-    State(_app_state): State<AppState>,
-    Json(_submission): Json<QuizSubmission>,
-) -> Result<Json<PlayerCharacter>, (StatusCode, String)> {
-    // Mock implementation while AsyncWorld/tx is disabled
-    Ok(Json(get_simulated_character()))
+    State(app_state): State<AppState>,
+    PlayerKey(player_id): PlayerKey,
+    Json(submission): Json<QuizSubmission>,
+) -> crate::Result<Json<PlayerCharacter>> {
+    let result = match app_state.pool {
+        Some(ref pool) => Some(calculate_archetype(pool, &submission).await.map_err(|e| {
+            tracing::error!("Quiz scoring failed: {}", e);
+            AppError::InternalServerError
+        })?),
+        None => None,
+    };
+
+    let character = match result {
+        Some(result) => {
+            app_state
+                .bus
+                .request(|reply| Command::Quiz { player_id, result, reply })
+                .await?
+        }
+        None => {
+            app_state
+                .bus
+                .request(|reply| Command::Character { player_id, reply })
+                .await?
+        }
+    };
+
+    Ok(Json(character))
 }
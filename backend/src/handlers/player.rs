@@ -1,7 +1,14 @@
-use crate::domain::game_logic::process_command;
-use crate::domain::player::get_simulated_character;
+use crate::domain::player::{get_simulated_character, SIMULATED_USER_ID};
+use crate::domain::player_store::load_player_state;
+use crate::game::bus::Command;
+use crate::game::roster::PlayerState;
 use crate::{AppError, AppState, Result};
-use axum::{extract::State, Json};
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, State},
+    http::request::Parts,
+    Json,
+};
 use common::{
     CharacterSummary, GameTurn, JournalData, PlayerCharacter, PlayerCommand, PlayerProfile,
     ProfileData, VocabEntry, CHARACTER_TEMPLATES,
@@ -9,15 +16,22 @@ use common::{
 use std::collections::HashMap;
 
 pub async fn handle_submit_command(
-    State(_app_state): State<AppState>,
+    State(app_state): State<AppState>,
+    PlayerKey(player_id): PlayerKey,
     Json(payload): Json<PlayerCommand>,
 ) -> Result<Json<GameTurn>> {
-    // Mock implementation while AsyncWorld is disabled
-    let updated_character = get_simulated_character();
+    let updated_character = app_state
+        .bus
+        .request(|reply| Command::Submit {
+            player_id,
+            command_text: payload.command_text.clone(),
+            reply,
+        })
+        .await?;
 
     let game_turn = GameTurn {
         player_command: payload.command_text,
-        ai_narrative: "Simulation mode: AsyncWorld disabled.".to_string(),
+        ai_narrative: updated_character.current_step_description.clone(),
         system_message: None,
         updated_character,
     };
@@ -26,9 +40,13 @@ pub async fn handle_submit_command(
 }
 
 pub async fn get_player_character(
-    State(_app_state): State<AppState>,
+    State(app_state): State<AppState>,
+    PlayerKey(player_id): PlayerKey,
 ) -> Result<Json<PlayerCharacter>> {
-    let character = get_simulated_character();
+    let character = app_state
+        .bus
+        .request(|reply| Command::Character { player_id, reply })
+        .await?;
     Ok(Json(character))
 }
 
@@ -98,3 +116,78 @@ pub async fn get_player_profile(State(_app_state): State<AppState>) -> Result<Js
         archetype: "The Sage".to_string(),
     }))
 }
+
+// --- Player Roster ---
+
+/// The header naming the player a request acts for.
+pub const PLAYER_ID_HEADER: &str = "x-player-id";
+
+/// The player a request acts for: the `X-Player-Id` header, or the
+/// simulated player when there isn't one. The frontend sends the id it
+/// keeps for its browser, the engine the one given as `--player=ID`.
+pub struct PlayerKey(pub String);
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for PlayerKey {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self> {
+        let Some(value) = parts.headers.get(PLAYER_ID_HEADER) else {
+            return Ok(PlayerKey(SIMULATED_USER_ID.to_string()));
+        };
+        value
+            .to_str()
+            .ok()
+            .filter(|id| is_valid_player_id(id))
+            .map(|id| PlayerKey(id.to_string()))
+            .ok_or(AppError::ValidationError("Invalid player id"))
+    }
+}
+
+fn is_valid_player_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= 64
+        && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// The state of a player in the world. Only logging in brings a player
+/// into it; a player who hasn't, or has since gone idle, isn't found.
+pub fn player_state(app_state: &AppState, player_id: &str) -> Result<PlayerState> {
+    app_state.players.get(player_id).ok_or(AppError::NotFound)
+}
+
+/// The player's state, bringing them into the world first if they aren't
+/// there: restored from storage if they've played before, otherwise fresh.
+async fn join_player(app_state: &AppState, player_id: &str) -> Result<PlayerState> {
+    if let Some(state) = app_state.players.get(player_id) {
+        return Ok(state);
+    }
+
+    // A player who just left may not be saved yet; storage is behind them
+    let stored = match (app_state.players.departing(player_id), &app_state.pool) {
+        (Some(state), _) => Some(state),
+        (None, Some(pool)) => load_player_state(pool, player_id).await?,
+        (None, None) => None,
+    };
+    let state = stored.unwrap_or_else(|| PlayerState::fresh(get_simulated_character()));
+
+    app_state
+        .bus
+        .send(Command::Join {
+            player_id: player_id.to_string(),
+            state: Box::new(state.clone()),
+        })
+        .await?;
+    Ok(state)
+}
+
+/// POST /api/players/login
+/// Spawns the player's entity (or keeps it alive) and returns their state.
+/// Every other per-player request needs the player logged in.
+pub async fn login(
+    State(app_state): State<AppState>,
+    PlayerKey(player_id): PlayerKey,
+) -> Result<Json<PlayerState>> {
+    let state = join_player(&app_state, &player_id).await?;
+    Ok(Json(state))
+}
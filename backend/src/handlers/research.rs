@@ -1,14 +1,29 @@
 use crate::error::Result;
-use crate::game::components::{ResearchLog, VirtueTopology};
+use crate::game::components::{CognitiveLoad, ResearchLog, VirtueTopology};
+use crate::handlers::player::{player_state, PlayerKey};
 use crate::AppState;
 use axum::{extract::State, Json};
 
-pub async fn get_research_log(State(state): State<AppState>) -> Result<Json<ResearchLog>> {
-    let log = state.shared_research_log.read().unwrap().clone();
-    Ok(Json(log))
+pub async fn get_research_log(
+    State(state): State<AppState>,
+    PlayerKey(player_id): PlayerKey,
+) -> Result<Json<ResearchLog>> {
+    let player = player_state(&state, &player_id)?;
+    Ok(Json(player.research_log))
 }
 
-pub async fn get_virtue_topology(State(state): State<AppState>) -> Result<Json<VirtueTopology>> {
-    let virtues = state.shared_virtues.read().unwrap().clone();
-    Ok(Json(virtues))
+pub async fn get_virtue_topology(
+    State(state): State<AppState>,
+    PlayerKey(player_id): PlayerKey,
+) -> Result<Json<VirtueTopology>> {
+    let player = player_state(&state, &player_id)?;
+    Ok(Json(player.virtue_topology))
+}
+
+pub async fn get_cognitive_load(
+    State(state): State<AppState>,
+    PlayerKey(player_id): PlayerKey,
+) -> Result<Json<CognitiveLoad>> {
+    let player = player_state(&state, &player_id)?;
+    Ok(Json(player.cognitive_load))
 }
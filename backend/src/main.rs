@@ -1,5 +1,6 @@
 use axum::{extract::FromRef, response::IntoResponse, Extension, Router};
-use bevy::prelude::{App as BevyApp, MinimalPlugins, Name, Update};
+use bevy::prelude::{App as BevyApp, IntoScheduleConfigs, MinimalPlugins, Update};
+use bevy::time::common_conditions::on_real_timer;
 use common::PlayerCharacter;
 // use frontend::app::App; // Unused in SPA mode
 use leptos::config::get_configuration;
@@ -7,8 +8,8 @@ use leptos::prelude::*;
 // use leptos_axum::{generate_route_list, LeptosRoutes}; // Unused in SPA mode
 use sqlx::{postgres::PgPoolOptions, PgPool};
 use std::env;
-use std::sync::{Arc, RwLock};
 use std::thread;
+use std::time::Duration;
 use tokio::sync::oneshot;
 use tower_http::cors::{Any, CorsLayer};
 
@@ -21,7 +22,7 @@ mod narrative; // [NEW] YouTube video generation pipeline
 mod routes;
 mod static_assets; // [NEW]
 
-use domain::player::get_simulated_character;
+use domain::player_store::save_departures;
 pub use error::{AppError, Result};
 use routes::ai_mirror::ai_mirror_routes;
 use routes::expert::expert_routes;
@@ -30,7 +31,13 @@ use routes::player::player_routes;
 use routes::research::research_routes;
 use static_assets::static_handler;
 
-use crate::game::components::*;
+use crate::game::bus::{
+    self, apply_commands, BusInbox, Command, CommandBus, BUS_CAPACITY, REPLY_TIMEOUT,
+};
+use crate::game::roster::{
+    checkpoint_players, despawn_idle_players, roster, sync_players_to_shared, PlayerDirectory,
+    Roster, DEFAULT_CHECKPOINT_SECS, DEFAULT_IDLE_SECS,
+};
 use crate::game::systems::*;
 
 use bevy_yarnspinner::prelude::*;
@@ -40,8 +47,8 @@ use bevy_yarnspinner::prelude::*;
 pub struct AppState {
     pub leptos_options: LeptosOptions,
     pub pool: Option<PgPool>,
-    pub shared_research_log: Arc<RwLock<ResearchLog>>,
-    pub shared_virtues: Arc<RwLock<VirtueTopology>>,
+    pub players: PlayerDirectory,
+    pub bus: CommandBus,
 }
 
 // Implement FromRef<AppState> for LeptosOptions
@@ -60,69 +67,50 @@ impl FromRef<AppState> for PgPool {
     }
 }
 
-fn run_bevy_app(shared_log: Arc<RwLock<ResearchLog>>, shared_virtues: Arc<RwLock<VirtueTopology>>) {
+fn run_bevy_app(roster: Roster, inbox: BusInbox, checkpoint_every: Duration) {
     let mut app = BevyApp::new();
     app.add_plugins(MinimalPlugins);
     app.add_plugins(bevy::asset::AssetPlugin::default());
     app.add_plugins(YarnSpinnerPlugin::new());
 
-    // Insert Shared Resources
-    app.insert_resource(SharedResearchLogResource(shared_log));
-    app.insert_resource(SharedVirtuesResource(shared_virtues));
+    // One student entity per player, spawned as requests name them,
+    // and the command bus that handlers reach them through
+    app.insert_resource(roster);
+    app.insert_resource(inbox);
 
     // Register Systems
     app.add_systems(
         Update,
         (
-            update_virtue_topology,
-            monitor_cognitive_load,
-            log_research_events,
-            sync_yarn_to_story_progress,
-            sync_ecs_to_shared,
-        ),
+            apply_commands,
+            (
+                update_virtue_topology,
+                monitor_cognitive_load,
+                log_research_events,
+                sync_yarn_to_story_progress,
+            ),
+            sync_players_to_shared,
+            checkpoint_players.run_if(on_real_timer(checkpoint_every)),
+            despawn_idle_players,
+        )
+            .chain(),
     );
 
-    let simulated_player = get_simulated_character();
-
-    // Spawn StudentBundle
-    app.world_mut().spawn(StudentBundle {
-        name: Name::new(simulated_player.name),
-        persona: Persona {
-            archetype: Archetype::Novice,
-            shadow_trait: "None".to_string(),
-            projective_dissonance: 0.0,
-        },
-        virtue_topology: VirtueTopology::default(),
-        cognitive_load: CognitiveLoad::default(),
-        story_progress: StoryProgress {
-            current_quest_id: simulated_player.current_quest_id,
-            current_step_id: simulated_player.current_step_id,
-            current_step_description: simulated_player.current_step_description,
-            history: Vec::new(),
-            inventory: simulated_player.inventory,
-            quest_flags: simulated_player.quest_flags,
-            learned_vocab: simulated_player.learned_vocab,
-        },
-        research_log: ResearchLog::default(),
-        level: Level(1),
-        xp: Experience(0),
-    });
-
     app.run();
 }
 
-
 #[tokio::main]
 async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     println!("Starting Daydream Backend Server...");
 
-    let shared_research_log = Arc::new(RwLock::new(ResearchLog::default()));
-    let shared_virtues = Arc::new(RwLock::new(VirtueTopology::default()));
-
-    let log_clone = shared_research_log.clone();
-    let virtues_clone = shared_virtues.clone();
+    // Players idle this long are saved and despawned
+    let idle_secs = env_secs("PLAYER_IDLE_SECS", DEFAULT_IDLE_SECS);
+    // Players still playing are saved this often, if they've changed
+    let checkpoint_secs = env_secs("PLAYER_CHECKPOINT_SECS", DEFAULT_CHECKPOINT_SECS);
+    let (bus, inbox) = bus::channel(BUS_CAPACITY, REPLY_TIMEOUT);
+    let (players, roster, departures) = roster(Duration::from_secs(idle_secs));
 
-    thread::spawn(move || run_bevy_app(log_clone, virtues_clone));
+    thread::spawn(move || run_bevy_app(roster, inbox, Duration::from_secs(checkpoint_secs)));
 
     let conf = get_configuration(None)
         .map_err(|e| format!("Failed to load Leptos configuration: {}", e))?;
@@ -147,12 +135,14 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         }
     };
 
+    let saver = tokio::spawn(save_departures(pool.clone(), departures, players.clone()));
+
     // Create the application state
     let app_state = AppState {
         leptos_options,
         pool,
-        shared_research_log,
-        shared_virtues,
+        players,
+        bus: bus.clone(),
     };
 
     // SECURITY: Restrict CORS to known origins. Use env var for production override.
@@ -178,8 +168,56 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     println!("Backend listening on http://{}", &addr);
     let listener = tokio::net::TcpListener::bind(&addr).await
         .map_err(|e| format!("Failed to bind to {}: {}", addr, e))?;
-    axum::serve(listener, app).await
-        .map_err(|e| format!("Server error: {}", e))?;
+    let served = axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal())
+        .await;
+
+    // Everyone still in the world departs; wait for their saves. The
+    // world drops the departures channel as it stops, which ends the saver.
+    println!("Saving players still in the world...");
+    match bus.request(|reply| Command::Shutdown { reply }).await {
+        Ok(()) => match tokio::time::timeout(SHUTDOWN_SAVE_TIMEOUT, saver).await {
+            Ok(_) => println!("Players saved."),
+            Err(_) => eprintln!("ERROR: Saving players took too long; some may be lost."),
+        },
+        Err(e) => eprintln!("ERROR: Couldn't save players at shutdown: {}", e),
+    }
 
+    served.map_err(|e| format!("Server error: {}", e))?;
     Ok(())
 }
+
+/// How long shutdown waits for the last players to be saved.
+const SHUTDOWN_SAVE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Seconds from an env var, or the default when it's unset or unreadable.
+fn env_secs(var: &str, default: u64) -> u64 {
+    env::var(var)
+        .ok()
+        .and_then(|secs| secs.parse().ok())
+        .unwrap_or(default)
+}
+
+/// Resolves on Ctrl-C, or SIGTERM where there is one.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(_) => std::future::pending::<()>().await,
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+    println!("Shutting down...");
+}
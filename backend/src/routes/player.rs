@@ -10,6 +10,7 @@ use crate::handlers::player::{
     get_journal_data,
     handle_submit_command,
     get_player_profile,
+    login,
 };
 
 pub fn player_routes(app_state: &AppState) -> Router<AppState> {
@@ -19,5 +20,6 @@ pub fn player_routes(app_state: &AppState) -> Router<AppState> {
         .route("/api/journal_data", get(get_journal_data))
         .route("/api/submit_command", post(handle_submit_command))
         .route("/api/player_profile", get(get_player_profile))
+        .route("/api/players/login", post(login))
         .with_state(app_state.clone())
 }
@@ -1,4 +1,4 @@
-use crate::handlers::research::{get_research_log, get_virtue_topology};
+use crate::handlers::research::{get_cognitive_load, get_research_log, get_virtue_topology};
 use crate::AppState;
 use axum::{routing::get, Router};
 
@@ -6,5 +6,6 @@ pub fn research_routes(state: &AppState) -> Router<AppState> {
     Router::new()
         .route("/api/research/log", get(get_research_log))
         .route("/api/research/virtues", get(get_virtue_topology))
+        .route("/api/research/cognitive_load", get(get_cognitive_load))
         .with_state(state.clone())
 }
@@ -0,0 +1,300 @@
+// Daydream Engine — Archetype Reflection
+// Your story tells you who you are. At the end of a session the
+// analyzer reads how the student played — not which channel scored
+// highest — and names the pattern, with the evidence behind it.
+//
+//   Oracle      → Mind words in the Subject slot
+//   Bard        → many resonance threads woven
+//   Cultivator  → words replayed across sessions (deep mastery)
+//   Templar     → branches taken in many directions
+//   Architect   → casts balanced across all four channels
+//   Hermeticist → digging deeper on nearly every card
+//
+// The archetype is a mirror, not a reward: it never changes play.
+// A pattern only counts once there is enough evidence for it.
+
+use bevy::prelude::*;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use crate::components::*;
+
+/// The patterns a student's play can show.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum Archetype {
+    Oracle,
+    Bard,
+    Cultivator,
+    Templar,
+    Architect,
+    Hermeticist,
+}
+
+impl Archetype {
+    pub const ALL: [Archetype; 6] = [
+        Archetype::Oracle, Archetype::Bard, Archetype::Cultivator,
+        Archetype::Templar, Archetype::Architect, Archetype::Hermeticist,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Archetype::Oracle      => "The Oracle",
+            Archetype::Bard        => "The Bard",
+            Archetype::Cultivator  => "The Cultivator",
+            Archetype::Templar     => "The Templar",
+            Archetype::Architect   => "The Architect",
+            Archetype::Hermeticist => "The Hermeticist",
+        }
+    }
+
+    /// What the game whispers at the trail review.
+    pub fn whisper(&self) -> &'static str {
+        match self {
+            Archetype::Oracle      => "You look for meaning behind meaning.",
+            Archetype::Bard        => "You weave words together like songs.",
+            Archetype::Cultivator  => "You tend your garden patiently.",
+            Archetype::Templar     => "You walk every path to see the whole map.",
+            Archetype::Architect   => "You build from every direction.",
+            Archetype::Hermeticist => "You see the code behind the words.",
+        }
+    }
+}
+
+/// The class shown before any pattern has emerged.
+pub const NEWCOMER: &str = "Newcomer";
+
+/// One archetype's case: how strongly the session shows it (0..1),
+/// and the evidence in the student's own terms — in English, and as
+/// the counts a catalog's `archetype.<title>.evidence` is filled with.
+#[derive(Clone, Debug, Serialize)]
+pub struct ArchetypeSignal {
+    pub archetype: Archetype,
+    pub strength: f32,
+    pub evidence: String,
+    #[serde(skip)]
+    pub facts: Vec<(&'static str, String)>,
+}
+
+/// Resource: the archetype read from the last session, strongest
+/// signal first. Empty until there is enough play to read.
+#[derive(Resource, Clone, Debug, Default, Serialize)]
+pub struct ArchetypeReading {
+    pub signals: Vec<ArchetypeSignal>,
+}
+
+impl ArchetypeReading {
+    pub fn archetype(&self) -> Option<Archetype> {
+        self.signals.first().map(|s| s.archetype)
+    }
+
+    pub fn title(&self) -> &'static str {
+        self.archetype().map_or(NEWCOMER, |a| a.title())
+    }
+
+    /// The whisper and the evidence behind it.
+    pub fn whisper(&self) -> String {
+        match self.signals.first() {
+            Some(signal) => format!("{}\n{}", signal.archetype.whisper(), signal.evidence),
+            None => "Your story is just beginning. Play on, and a pattern will show.".to_string(),
+        }
+    }
+}
+
+// Minimum evidence before a pattern counts
+const MIN_SUBJECTS: usize = 2;
+const MIN_THREADS: usize = 2;
+const MIN_REPLAYS: usize = 2;
+const MIN_BRANCHES: usize = 2;
+const MIN_BALANCED_CASTS: usize = 4;
+const MIN_DEEPER: u32 = 3;
+
+/// Read the session's play patterns. `channel_of` maps a word to its
+/// Channel; the SpellBook carries replays across sessions.
+pub fn read_archetype(
+    trail: &StudentTrail,
+    threads: &ResonanceThreads,
+    spellbook: &SpellBook,
+    channel_of: impl Fn(&str) -> Option<Channel>,
+) -> ArchetypeReading {
+    let mut signals = Vec::new();
+    let mut signal = |archetype, strength: f32, evidence: String, facts: &[(&'static str, usize)]| {
+        let facts = facts.iter().map(|(k, n)| (*k, n.to_string())).collect();
+        signals.push(ArchetypeSignal { archetype, strength: strength.min(1.0), evidence, facts });
+    };
+
+    // Oracle: who the story is about
+    let subjects: Vec<&SlotCard> = trail.moments().filter_map(|m| m.slot(StorySlot::Subject)).collect();
+    let mind_subjects = subjects.iter().filter(|c| channel_of(&c.word) == Some(Channel::Mind)).count();
+    if subjects.len() >= MIN_SUBJECTS && mind_subjects > 0 {
+        signal(
+            Archetype::Oracle,
+            mind_subjects as f32 / subjects.len() as f32,
+            format!("{} of your {} stories were about a Mind word.", mind_subjects, subjects.len()),
+            &[("mind", mind_subjects), ("stories", subjects.len())],
+        );
+    }
+
+    // Bard: what resonated, out of the three pairs a full table holds
+    let moments = trail.moments().count().max(1);
+    let resonances: usize = trail.moments().map(|m| m.resonances.len()).sum();
+    if threads.threads.len() >= MIN_THREADS {
+        signal(
+            Archetype::Bard,
+            resonances as f32 / (3 * moments) as f32,
+            format!("You wove {} resonance threads in {} moments.", threads.threads.len(), moments),
+            &[("threads", threads.threads.len()), ("moments", moments)],
+        );
+    }
+
+    // Cultivator: words cast this session that had been cast before
+    let replayed = trail.cast_words.iter()
+        .filter(|w| spellbook.entries.iter().any(|e| &e.word == *w && e.times_cast >= 2))
+        .count();
+    if replayed >= MIN_REPLAYS {
+        signal(
+            Archetype::Cultivator,
+            replayed as f32 / trail.cast_words.len() as f32,
+            format!("{} words you had cast before came back into your story.", replayed),
+            &[("words", replayed)],
+        );
+    }
+
+    // Templar: how many ways the branches went
+    let branches: Vec<&Branch> = trail.chapters.iter().filter_map(|c| c.branch.as_ref()).collect();
+    let directions: HashSet<BranchEdge> = branches.iter().map(|b| b.edge).collect();
+    if branches.len() >= MIN_BRANCHES && directions.len() >= 2 {
+        signal(
+            Archetype::Templar,
+            directions.len() as f32 / 3.0,
+            format!("You took {} branches, {} different ways.", branches.len(), directions.len()),
+            &[("branches", branches.len()), ("ways", directions.len())],
+        );
+    }
+
+    // Architect: how evenly the casts spread across the channels
+    let mut per_channel: HashMap<Channel, usize> = HashMap::new();
+    for word in &trail.cast_words {
+        if let Some(channel) = channel_of(word) {
+            *per_channel.entry(channel).or_default() += 1;
+        }
+    }
+    let casts: usize = per_channel.values().sum();
+    if casts >= MIN_BALANCED_CASTS && per_channel.len() >= 3 {
+        // Normalised entropy: 1.0 when all four channels are equal
+        let entropy: f32 = per_channel.values()
+            .map(|n| { let p = *n as f32 / casts as f32; -p * p.ln() })
+            .sum();
+        let count = |c: Channel| per_channel.get(&c).copied().unwrap_or(0);
+        let counts: Vec<String> = Channel::ALL.iter()
+            .map(|c| format!("{} {}", count(*c), c.label()))
+            .collect();
+        signal(
+            Archetype::Architect,
+            entropy / 4f32.ln(),
+            format!("Your casts spread across the channels: {}.", counts.join(", ")),
+            &[
+                ("mind", count(Channel::Mind)), ("heart", count(Channel::Heart)),
+                ("body", count(Channel::Body)), ("action", count(Channel::Action)),
+            ],
+        );
+    }
+
+    // Hermeticist: digging beneath the words
+    let deeper = trail.swipe_history.iter().filter(|s| **s == SwipeChoice::Deeper).count() as u32;
+    let seen = trail.visited_words.len().max(1);
+    if deeper >= MIN_DEEPER {
+        signal(
+            Archetype::Hermeticist,
+            deeper as f32 / seen as f32,
+            format!("You dug deeper {} times across {} words.", deeper, seen),
+            &[("deeper", deeper as usize), ("words", seen)],
+        );
+    }
+
+    // Strongest first; ties keep the table's order
+    signals.sort_by(|a, b| b.strength.total_cmp(&a.strength));
+    ArchetypeReading { signals }
+}
+
+/// System: at the trail review, read the session and name the pattern
+/// on the Character Sheet. Runs after the last moment is closed.
+pub fn reflect_archetype(
+    trail: Res<StudentTrail>,
+    threads: Res<ResonanceThreads>,
+    spellbook: Res<SpellBook>,
+    words: Query<(&WordCard, &Channel)>,
+    mut sheet: ResMut<CharacterSheet>,
+    mut reading: ResMut<ArchetypeReading>,
+) {
+    let channels: HashMap<&str, Channel> = words.iter().map(|(w, c)| (w.word.as_str(), *c)).collect();
+    *reading = read_archetype(&trail, &threads, &spellbook, |word| channels.get(word).copied());
+    // A short session keeps the class an earlier one earned
+    if let Some(archetype) = reading.archetype() {
+        sheet.emergent_class = archetype.title().to_string();
+    }
+    info!("Archetype: {} ({} signals)", reading.title(), reading.signals.len());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channel_of(word: &str) -> Option<Channel> {
+        match word {
+            "Bias" | "Clarity" => Some(Channel::Mind),
+            "Patience" => Some(Channel::Heart),
+            "Presence" => Some(Channel::Body),
+            "Resilience" => Some(Channel::Action),
+            _ => None,
+        }
+    }
+
+    fn moment(subject: &str) -> StoryMoment {
+        let mut moment = StoryMoment::default();
+        moment.place(Entity::from_raw_u32(1).unwrap(), subject, Symbol::Star);
+        moment
+    }
+
+    #[test]
+    fn test_mind_subjects_read_as_oracle() {
+        let mut trail = StudentTrail::default();
+        trail.chapter_mut().moments.extend([moment("Bias"), moment("Clarity"), moment("Patience")]);
+        let reading = read_archetype(&trail, &ResonanceThreads::default(), &SpellBook::default(), channel_of);
+
+        assert_eq!(reading.archetype(), Some(Archetype::Oracle));
+        assert!(reading.whisper().contains("2 of your 3 stories"));
+    }
+
+    #[test]
+    fn test_balanced_casts_read_as_architect() {
+        let trail = StudentTrail {
+            cast_words: ["Bias", "Patience", "Presence", "Resilience"].map(String::from).to_vec(),
+            ..default()
+        };
+        let reading = read_archetype(&trail, &ResonanceThreads::default(), &SpellBook::default(), channel_of);
+        assert_eq!(reading.archetype(), Some(Archetype::Architect));
+        assert!((reading.signals[0].strength - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_digging_deeper_outweighs_a_thin_pattern() {
+        let mut trail = StudentTrail {
+            visited_words: ["Bias", "Patience", "Presence"].map(String::from).to_vec(),
+            swipe_history: vec![SwipeChoice::Deeper; 3],
+            ..default()
+        };
+        trail.chapter_mut().moments.extend([moment("Bias"), moment("Patience"), moment("Presence")]);
+        let reading = read_archetype(&trail, &ResonanceThreads::default(), &SpellBook::default(), channel_of);
+
+        assert_eq!(reading.archetype(), Some(Archetype::Hermeticist));
+        // The Oracle case is still there as a weaker signal
+        assert_eq!(reading.signals[1].archetype, Archetype::Oracle);
+    }
+
+    #[test]
+    fn test_too_little_play_reads_as_newcomer() {
+        let reading = read_archetype(
+            &StudentTrail::default(), &ResonanceThreads::default(), &SpellBook::default(), channel_of,
+        );
+        assert_eq!(reading.title(), NEWCOMER);
+    }
+}
@@ -0,0 +1,531 @@
+// Daydream Engine — Backend Sync
+// The engine can play a story authored in the Daydream backend, and
+// report play back to it:
+//
+//   GET  /api/expert/graph    → StoryGraph → a curriculum of word entities
+//   POST /api/quest/action    ← ChoiceAction for every cast, skip and deeper
+//   POST /api/players/login   ← when the backend doesn't know the player
+//
+// Each StoryNode becomes its subject_word: the node's content is the
+// word's story, its depth question the depth prompt, its channel the
+// word's Channel. Choices and connections become DAG edges; an edge
+// that would close a loop becomes a no-target, which may loop by design.
+//
+// Opt in with `--backend=http://host:port`, and name the student the
+// backend knows with `--player=ID` (sent as X-Player-Id; without it the
+// backend counts play against its simulated player). The backend is never
+// required: if it can't be reached the local curriculum is played,
+// and actions that fail to post are dropped — the student's progress
+// is still saved locally. Posting happens off the frame loop.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::components::*;
+use crate::dag::{parse_channel, CurriculumDef, CurriculumError, CurriculumSource, WordDef};
+use crate::mastery::{ProgressKind, SpellProgress};
+
+/// How long a request may take before the backend counts as unreachable.
+#[cfg(not(target_arch = "wasm32"))]
+const TIMEOUT_SECS: u64 = 3;
+
+/// The header naming the player an action is for.
+#[cfg(not(target_arch = "wasm32"))]
+const PLAYER_ID_HEADER: &str = "X-Player-Id";
+
+// ─── WIRE TYPES ─────────────────────────────────────────────
+// Mirrors of common::expert — the engine doesn't pull in the web
+// crates, so these must stay field-compatible with them.
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct StoryChoice {
+    pub id: String,
+    pub leads_to: String,
+    pub virtue: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct StoryNode {
+    pub id: String,
+    pub title: String,
+    pub content: String,
+    pub subject_word: String,
+    pub choices: Vec<StoryChoice>,
+    /// MIND | HEART | BODY | ACTION
+    pub channel: Option<String>,
+    /// Socratic reflection question.
+    pub depth: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct Connection {
+    pub from_node: String,
+    pub to_node: String,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct StoryGraph {
+    pub id: String,
+    pub title: String,
+    pub nodes: Vec<StoryNode>,
+    pub connections: Vec<Connection>,
+}
+
+/// One student action, as the backend's virtue topology reads it.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct ChoiceAction {
+    pub graph_id: String,
+    pub node_id: String,
+    /// "cast", "skip" or "deeper".
+    pub choice_id: String,
+    pub subject_word: String,
+    pub leads_to: String,
+}
+
+// ─── STORY GRAPH → CURRICULUM ───────────────────────────────
+
+impl StoryGraph {
+    /// The node id each word came from (the first node, if several
+    /// share a subject word).
+    pub fn node_ids(&self) -> HashMap<String, String> {
+        let mut ids = HashMap::new();
+        for node in &self.nodes {
+            let word = node.subject_word.trim();
+            if !word.is_empty() {
+                ids.entry(word.to_string()).or_insert_with(|| node.id.clone());
+            }
+        }
+        ids
+    }
+
+    /// Turn the graph into a curriculum: one word per subject word.
+    pub fn to_curriculum(&self) -> CurriculumDef {
+        let word_of: HashMap<&str, &str> = self.nodes.iter()
+            .map(|n| (n.id.as_str(), n.subject_word.trim()))
+            .filter(|(_, w)| !w.is_empty())
+            .collect();
+
+        // Every way out of a node, in authored order: choices, then connections
+        let mut exits: Vec<(&str, Vec<&str>)> = Vec::new();
+        let mut words: Vec<WordDef> = Vec::new();
+        for node in &self.nodes {
+            let Some(&word) = word_of.get(node.id.as_str()) else { continue };
+            let targets = node.choices.iter().map(|c| c.leads_to.as_str())
+                .chain(self.connections.iter().filter(|c| c.from_node == node.id).map(|c| c.to_node.as_str()))
+                .filter_map(|id| word_of.get(id).copied())
+                .filter(|target| *target != word);
+            let virtues = node.choices.iter().filter_map(|c| c.virtue.clone());
+
+            if let Some(def) = words.iter_mut().find(|w| w.word == word) {
+                // A word met again on another node: keep the first telling
+                for virtue in virtues {
+                    if !def.themes.contains(&virtue) {
+                        def.themes.push(virtue);
+                    }
+                }
+                if let Some((_, known)) = exits.iter_mut().find(|(w, _)| *w == word) {
+                    known.extend(targets);
+                }
+                continue;
+            }
+            let mut themes: Vec<String> = Vec::new();
+            for virtue in virtues {
+                if !themes.contains(&virtue) {
+                    themes.push(virtue);
+                }
+            }
+            exits.push((word, targets.collect()));
+            words.push(WordDef {
+                word: word.to_string(),
+                depth_prompt: node.depth.clone().unwrap_or_else(|| node.title.clone()),
+                themes,
+                mood: "calm".to_string(),
+                story_text: node.content.clone(),
+                channel: node.channel.as_deref()
+                    .filter(|c| parse_channel(c).is_some())
+                    .map_or_else(|| "body".to_string(), str::to_lowercase),
+                stage: "hero".to_string(),
+                symbol: "void".to_string(),
+                card_type: "spell".to_string(),
+                yes_targets: Vec::new(),
+                no_targets: Vec::new(),
+                low_focus_targets: Vec::new(),
+                synergy_partners: Vec::new(),
+                translations: HashMap::new(),
+            });
+        }
+
+        // Forward edges stay acyclic: one that would loop back turns aside
+        for (word, targets) in &exits {
+            let mut seen = HashSet::new();
+            for target in targets.iter().filter(|t| seen.insert(**t)) {
+                let loops_back = reaches(&words, target, word);
+                let def = words.iter_mut().find(|w| w.word == *word).expect("every exit has a word");
+                if loops_back {
+                    def.no_targets.push(target.to_string());
+                } else {
+                    def.yes_targets.push(target.to_string());
+                }
+            }
+        }
+
+        // The story starts where no forward edge leads in
+        let entered: HashSet<&str> = words.iter()
+            .flat_map(|w| w.yes_targets.iter().map(String::as_str))
+            .collect();
+        let start_word = words.iter()
+            .find(|w| !entered.contains(w.word.as_str()))
+            .or(words.first())
+            .map(|w| w.word.clone())
+            .unwrap_or_default();
+
+        CurriculumDef { name: self.title.clone(), start_word, words }
+    }
+}
+
+/// Whether `from` already leads to `to` along yes-targets.
+fn reaches(words: &[WordDef], from: &str, to: &str) -> bool {
+    let mut stack = vec![from];
+    let mut visited = HashSet::new();
+    while let Some(word) = stack.pop() {
+        if word == to {
+            return true;
+        }
+        if !visited.insert(word) {
+            continue;
+        }
+        if let Some(def) = words.iter().find(|w| w.word == word) {
+            stack.extend(def.yes_targets.iter().map(String::as_str));
+        }
+    }
+    false
+}
+
+// ─── LINK ───────────────────────────────────────────────────
+
+/// Why the backend's story could not be played.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BackendError {
+    /// No answer, or not a success.
+    Unreachable(String),
+    /// An answer that isn't a StoryGraph.
+    BadResponse(String),
+    /// The graph has no nodes with a subject word.
+    EmptyGraph,
+    /// The graph converts to a curriculum that doesn't validate.
+    Invalid(Vec<CurriculumError>),
+}
+
+impl fmt::Display for BackendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BackendError::Unreachable(msg) => write!(f, "backend unreachable: {}", msg),
+            BackendError::BadResponse(msg) => write!(f, "backend sent an unreadable story graph: {}", msg),
+            BackendError::EmptyGraph => write!(f, "backend story graph has no words"),
+            BackendError::Invalid(errors) => {
+                let listed: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+                write!(f, "backend story graph is not a valid curriculum: {}", listed.join("; "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for BackendError {}
+
+/// Resource: the connection to the Daydream backend.
+#[derive(Resource)]
+pub struct BackendLink {
+    base: String,
+    /// The graph being played, or the local curriculum's name.
+    graph_id: String,
+    /// Word → the StoryNode it came from.
+    node_ids: HashMap<String, String>,
+    online: Arc<AtomicBool>,
+    #[cfg(not(target_arch = "wasm32"))]
+    outbox: std::sync::mpsc::Sender<ChoiceAction>,
+}
+
+impl BackendLink {
+    fn new(base: &str, player: Option<String>) -> Self {
+        let base = base.trim_end_matches('/').to_string();
+        let online = Arc::new(AtomicBool::new(true));
+        #[cfg(target_arch = "wasm32")]
+        let _ = player;
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            outbox: spawn_poster(&base, player, online.clone()),
+            base,
+            graph_id: String::new(),
+            node_ids: HashMap::new(),
+            online,
+        }
+    }
+
+    /// Fetch the backend's story graph as a validated curriculum.
+    pub fn fetch_story(&self) -> Result<(StoryGraph, CurriculumDef), BackendError> {
+        let graph: StoryGraph = get_json(&format!("{}/api/expert/graph", self.base))?;
+        let curriculum = graph.to_curriculum();
+        if curriculum.words.is_empty() {
+            return Err(BackendError::EmptyGraph);
+        }
+        curriculum.validate().map_err(BackendError::Invalid)?;
+        Ok((graph, curriculum))
+    }
+
+    /// Send one action without waiting on the answer.
+    pub fn post(&self, action: ChoiceAction) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.outbox.send(action).is_err() {
+            self.online.store(false, Ordering::Relaxed);
+        }
+        #[cfg(target_arch = "wasm32")]
+        let _ = action;
+    }
+
+    fn node_id(&self, word: &str) -> String {
+        self.node_ids.get(word).cloned().unwrap_or_else(|| word.to_string())
+    }
+}
+
+/// `--backend=URL`: play the backend's story and report play to it, as
+/// `--player=ID` if given. Without the flag, or when the story can't be
+/// fetched, the local source is played.
+pub fn connect(local: CurriculumSource) -> (CurriculumSource, Option<BackendLink>) {
+    let value = |flag: &str| std::env::args().find_map(|a| a.strip_prefix(flag).map(str::to_string));
+    let Some(base) = value("--backend=") else {
+        return (local, None);
+    };
+    let mut link = BackendLink::new(&base, value("--player="));
+    match link.fetch_story() {
+        Ok((graph, curriculum)) => {
+            info!("Playing '{}' from the backend at {} ({} words)", curriculum.name, link.base, curriculum.words.len());
+            link.graph_id = graph.id.clone();
+            link.node_ids = graph.node_ids();
+            (CurriculumSource::Graph(Box::new(curriculum)), Some(link))
+        }
+        Err(e) => {
+            warn!("{}; playing offline", e);
+            link.online.store(false, Ordering::Relaxed);
+            (local, Some(link))
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn get_json<T: serde::de::DeserializeOwned>(url: &str) -> Result<T, BackendError> {
+    let response = ureq::get(url)
+        .timeout(std::time::Duration::from_secs(TIMEOUT_SECS))
+        .call()
+        .map_err(|e| BackendError::Unreachable(e.to_string()))?;
+    response.into_json().map_err(|e| BackendError::BadResponse(e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn get_json<T: serde::de::DeserializeOwned>(_url: &str) -> Result<T, BackendError> {
+    // The browser build has no blocking HTTP; it always plays offline.
+    Err(BackendError::Unreachable("not available in the browser build".to_string()))
+}
+
+/// A worker that posts actions in order, for the player if one is
+/// named, so a slow backend never stalls a frame. The backend only
+/// takes actions for players who've logged in, and forgets them when
+/// they go idle: an action it answers 404 to logs in and goes again.
+/// Going offline and back is logged once each way.
+#[cfg(not(target_arch = "wasm32"))]
+fn spawn_poster(base: &str, player: Option<String>, online: Arc<AtomicBool>) -> std::sync::mpsc::Sender<ChoiceAction> {
+    let (sender, receiver) = std::sync::mpsc::channel::<ChoiceAction>();
+    let action_url = format!("{}/api/quest/action", base);
+    let login_url = format!("{}/api/players/login", base);
+    std::thread::spawn(move || {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(std::time::Duration::from_secs(TIMEOUT_SECS))
+            .build();
+        let post = |url: &str| match &player {
+            Some(player) => agent.post(url).set(PLAYER_ID_HEADER, player),
+            None => agent.post(url),
+        };
+        for action in receiver {
+            let mut sent = post(&action_url).send_json(&action);
+            if matches!(sent, Err(ureq::Error::Status(404, _))) {
+                sent = match post(&login_url).call() {
+                    Ok(_) => post(&action_url).send_json(&action),
+                    Err(e) => Err(e),
+                };
+            }
+            match sent {
+                Ok(_) => {
+                    if !online.swap(true, Ordering::Relaxed) {
+                        info!("Backend reachable again; reporting play");
+                    }
+                }
+                Err(e) => {
+                    if online.swap(false, Ordering::Relaxed) {
+                        warn!("Backend unreachable ({}); playing offline", e);
+                    }
+                }
+            }
+        }
+    });
+    sender
+}
+
+// ─── SYSTEMS ────────────────────────────────────────────────
+
+/// System: report every cast, skip and deeper to the backend.
+/// A cast leads along the word's first yes-edge, a skip along its
+/// first no-edge; a deeper stays on the word.
+pub fn report_choices(
+    mut progress: MessageReader<SpellProgress>,
+    link: Res<BackendLink>,
+    curriculum: Option<Res<crate::dag::Curriculum>>,
+    words: Query<(&WordCard, &WordEdges)>,
+) {
+    for event in progress.read() {
+        let (choice, next): (&str, fn(&WordEdges) -> Option<&String>) = match event.kind {
+            ProgressKind::Cast => ("cast", |e| e.yes_targets.first()),
+            ProgressKind::Skipped => ("skip", |e| e.no_targets.first()),
+            ProgressKind::Deeper => ("deeper", |_| None),
+            _ => continue,
+        };
+        let Ok((card, edges)) = words.get(event.entity) else { continue };
+        let node_id = link.node_id(&card.word);
+        let graph_id = match (&link.graph_id, &curriculum) {
+            (id, _) if !id.is_empty() => id.clone(),
+            (_, Some(curriculum)) => curriculum.name.clone(),
+            _ => String::new(),
+        };
+        link.post(ChoiceAction {
+            graph_id,
+            leads_to: next(edges).map_or_else(|| node_id.clone(), |w| link.node_id(w)),
+            node_id,
+            choice_id: choice.to_string(),
+            subject_word: card.word.clone(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: &str, word: &str, leads_to: &[&str]) -> StoryNode {
+        StoryNode {
+            id: id.to_string(),
+            title: format!("{} title", word),
+            content: format!("The {} scene.", word),
+            subject_word: word.to_string(),
+            choices: leads_to.iter().enumerate().map(|(i, to)| StoryChoice {
+                id: format!("{}-{}", id, i),
+                leads_to: to.to_string(),
+                virtue: Some("valor".to_string()),
+            }).collect(),
+            channel: Some("HEART".to_string()),
+            depth: Some(format!("What is {}?", word)),
+        }
+    }
+
+    #[test]
+    fn test_story_graph_becomes_a_valid_curriculum() {
+        let graph = StoryGraph {
+            id: "demo_graph".to_string(),
+            title: "Bias Quest".to_string(),
+            nodes: vec![
+                node("n1", "Presence", &["n2", "n3"]),
+                node("n2", "Bias", &["n3"]),
+                // Loops back to the start: must not become a forward edge
+                node("n3", "Clarity", &["n1"]),
+                node("n4", "", &["n1"]),
+            ],
+            connections: vec![Connection { from_node: "n2".to_string(), to_node: "n1".to_string() }],
+        };
+        let curriculum = graph.to_curriculum();
+        assert!(curriculum.validate().is_ok());
+        assert_eq!(curriculum.name, "Bias Quest");
+        assert_eq!(curriculum.start_word, "Presence");
+        assert_eq!(curriculum.words.len(), 3);
+
+        let presence = &curriculum.words[0];
+        assert_eq!(presence.yes_targets, vec!["Bias", "Clarity"]);
+        assert_eq!(presence.channel, "heart");
+        assert_eq!(presence.depth_prompt, "What is Presence?");
+        assert_eq!(presence.themes, vec!["valor"]);
+        assert_eq!(curriculum.words[2].no_targets, vec!["Presence"]);
+        assert_eq!(curriculum.words[1].no_targets, vec!["Presence"]);
+        assert_eq!(graph.node_ids()["Clarity"], "n3");
+    }
+
+    #[test]
+    fn test_story_graph_tolerates_missing_fields() {
+        let graph: StoryGraph = serde_json::from_str(r#"{
+            "id": "g", "title": "Sparse",
+            "nodes": [{ "id": "a", "subject_word": "Patience", "channel": "spirit" }]
+        }"#).unwrap();
+        let curriculum = graph.to_curriculum();
+        assert!(curriculum.validate().is_ok());
+        // An unknown channel falls back rather than failing the whole story
+        assert_eq!(curriculum.words[0].channel, "body");
+    }
+
+    #[test]
+    fn test_actions_are_posted_for_the_player() {
+        use std::io::{BufRead, BufReader, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let link = BackendLink::new(&format!("http://{}", listener.local_addr().unwrap()), Some("ana-7".to_string()));
+        link.post(ChoiceAction {
+            graph_id: "g".to_string(),
+            node_id: "n1".to_string(),
+            choice_id: "cast".to_string(),
+            subject_word: "Presence".to_string(),
+            leads_to: "n2".to_string(),
+        });
+
+        let (stream, _) = listener.accept().unwrap();
+        let headers: Vec<String> = BufReader::new(&stream).lines()
+            .map_while(Result::ok)
+            .take_while(|line| !line.is_empty())
+            .collect();
+        assert!(headers[0].starts_with("POST /api/quest/action"), "{:?}", headers);
+        assert!(headers.iter().any(|h| h.eq_ignore_ascii_case("x-player-id: ana-7")), "{:?}", headers);
+        (&stream).write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n").unwrap();
+    }
+
+    #[test]
+    fn test_a_player_the_backend_forgot_logs_back_in() {
+        use std::io::{BufRead, BufReader, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let link = BackendLink::new(&format!("http://{}", listener.local_addr().unwrap()), Some("ana-7".to_string()));
+        link.post(ChoiceAction {
+            graph_id: "g".to_string(),
+            node_id: "n1".to_string(),
+            choice_id: "cast".to_string(),
+            subject_word: "Presence".to_string(),
+            leads_to: "n2".to_string(),
+        });
+
+        // Not found, then logged in, then the action is taken
+        let mut requests = Vec::new();
+        for status in ["404 Not Found", "200 OK", "200 OK"] {
+            let (stream, _) = listener.accept().unwrap();
+            let mut lines = BufReader::new(&stream).lines().map_while(Result::ok);
+            let headers: Vec<String> = lines.by_ref().take_while(|line| !line.is_empty()).collect();
+            assert!(headers.iter().any(|h| h.eq_ignore_ascii_case("x-player-id: ana-7")), "{:?}", headers);
+            requests.push(headers[0].split(' ').take(2).collect::<Vec<_>>().join(" "));
+            write!(&stream, "HTTP/1.1 {}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n", status).unwrap();
+        }
+        assert_eq!(requests, ["POST /api/quest/action", "POST /api/players/login", "POST /api/quest/action"]);
+    }
+
+    #[test]
+    fn test_unreachable_backend_plays_offline() {
+        // Nothing listens on port 9 (discard) of localhost
+        let link = BackendLink::new("http://127.0.0.1:9/", Some("ana".to_string()));
+        assert!(matches!(link.fetch_story(), Err(BackendError::Unreachable(_))));
+    }
+}
@@ -0,0 +1,1477 @@
+// Daydream Engine — Curriculum Builder
+// Turns a teacher's chapters into a curriculum the engine can play:
+//
+//   daydream-engine --build chapter1.md chapter2.pdf [--name="Cells"]
+//       [--out=curriculum.json] [--report=review.md]
+//       [--min-frequency=2] [--max-words=40] [--min-cooccurrence=2]
+//
+// ARCANA's builder pipeline, in Rust:
+//   1. EXTRACT   plain text, Markdown or PDF → candidate words by
+//                frequency, with the sentences they appear in
+//   2. CLASSIFY  Channel from seed words in the word and its sentences,
+//                Stage from complexity, Symbol from the bundled
+//                part-of-speech lexicon (arcana/lexicon.json) and suffixes
+//   3. CONNECT   words that share sentences become synergy partners;
+//                yes_targets lead on to later words they appear with,
+//                no_targets back to gentler earlier ones
+//
+// Nothing here is final. The output is a CurriculumDef to start from and
+// a review report (Markdown) telling the instructor what to check: weak
+// channel guesses, words left without partners, words the path never
+// reaches, and the drafted prompts to rewrite in their own voice.
+//
+// PDF text is read from the page content streams (plain or Flate).
+// Fonts with custom encodings come out as nothing; a text export of the
+// PDF works instead.
+
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use crate::components::{Channel, Stage, Symbol};
+use crate::dag::{CurriculumDef, CurriculumError, WordDef};
+
+/// Seed words, part-of-speech lists and stop words for English.
+const LEXICON_JSON: &str = include_str!("../arcana/lexicon.json");
+
+/// Where the curriculum is written when `--out=` is not given.
+pub const DEFAULT_OUT_PATH: &str = "curriculum_new.json";
+
+/// Mood by channel and stage (rows: Mind, Heart, Body, Action;
+/// columns: Hero, Outlaw, Edge Lord, Best Self).
+const MOOD_MAP: [[&str; 4]; 4] = [
+    ["curious", "mysterious", "intense", "transcendent"],
+    ["warm", "passionate", "raw", "luminous"],
+    ["calm", "tense", "visceral", "oceanic"],
+    ["eager", "fierce", "relentless", "sovereign"],
+];
+
+/// Below this, the channel guess is flagged in the review report.
+const LOW_CONFIDENCE: f32 = 0.5;
+
+// ─── CONFIG & ERRORS ────────────────────────────────────────
+
+/// Knobs for one build.
+#[derive(Clone, Debug)]
+pub struct BuildConfig {
+    pub name: String,
+    /// A word must appear at least this often to become a card.
+    pub min_frequency: u32,
+    /// Keep at most this many words (the most frequent).
+    pub max_words: usize,
+    /// Shared sentences needed before two words become synergy partners.
+    pub min_cooccurrence: u32,
+    /// Shorter words are never cards.
+    pub min_length: usize,
+}
+
+impl Default for BuildConfig {
+    fn default() -> Self {
+        Self {
+            name: "Untitled Curriculum".to_string(),
+            min_frequency: 2,
+            max_words: 40,
+            min_cooccurrence: 2,
+            min_length: 4,
+        }
+    }
+}
+
+/// Why a build did not produce a curriculum.
+#[derive(Debug)]
+pub enum BuildError {
+    BadArgs(String),
+    Io { path: String, message: String },
+    /// The file was read but no text came out of it.
+    NoText { path: String, reason: String },
+    /// Nothing in the sources passed the frequency and length filters.
+    NoWords { min_frequency: u32 },
+    /// The drafted curriculum failed validation (a builder bug).
+    Invalid(Vec<CurriculumError>),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::BadArgs(msg) => write!(f, "{}", msg),
+            BuildError::Io { path, message } => write!(f, "{}: {}", path, message),
+            BuildError::NoText { path, reason } => write!(f, "no text found in {}: {}", path, reason),
+            BuildError::NoWords { min_frequency } => write!(
+                f, "no word appears {} or more times — try a lower --min-frequency", min_frequency
+            ),
+            BuildError::Invalid(errors) => {
+                let list: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+                write!(f, "built curriculum is invalid: {}", list.join("; "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+// ─── LEXICON ────────────────────────────────────────────────
+
+/// Parts of speech the lexicon knows, each mapping to a Symbol.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartOfSpeech {
+    Noun,
+    Verb,
+    Adjective,
+    Adverb,
+}
+
+impl PartOfSpeech {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "noun"      => Some(PartOfSpeech::Noun),
+            "verb"      => Some(PartOfSpeech::Verb),
+            "adjective" => Some(PartOfSpeech::Adjective),
+            "adverb"    => Some(PartOfSpeech::Adverb),
+            _           => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PartOfSpeech::Noun      => "noun",
+            PartOfSpeech::Verb      => "verb",
+            PartOfSpeech::Adjective => "adjective",
+            PartOfSpeech::Adverb    => "adverb",
+        }
+    }
+
+    fn symbol(&self) -> Symbol {
+        match self {
+            PartOfSpeech::Noun => Symbol::Stone,
+            PartOfSpeech::Verb => Symbol::Spark,
+            PartOfSpeech::Adjective | PartOfSpeech::Adverb => Symbol::Prism,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct LexiconFile {
+    channels: HashMap<String, Vec<String>>,
+    pos: HashMap<String, Vec<String>>,
+    suffixes: HashMap<String, Vec<String>>,
+    abstract_prefixes: Vec<String>,
+    stop_words: Vec<String>,
+}
+
+/// The word lists classification runs on.
+#[derive(Clone, Debug)]
+pub struct Lexicon {
+    seeds: Vec<(Channel, HashSet<String>)>,
+    pos: HashMap<String, PartOfSpeech>,
+    /// Checked in order: verb, adjective, noun, adverb.
+    suffixes: Vec<(PartOfSpeech, Vec<String>)>,
+    abstract_prefixes: Vec<String>,
+    stop_words: HashSet<String>,
+}
+
+impl Lexicon {
+    /// The English lexicon shipped with the engine.
+    pub fn bundled() -> Self {
+        Self::from_json(LEXICON_JSON).expect("bundled lexicon is valid")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let file: LexiconFile = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        let lower = |words: &[String]| words.iter().map(|w| w.to_lowercase()).collect::<HashSet<_>>();
+
+        let seeds = Channel::ALL.iter().map(|channel| {
+            let key = channel.label().to_lowercase();
+            (*channel, file.channels.get(&key).map(|w| lower(w)).unwrap_or_default())
+        }).collect();
+
+        // A word listed twice keeps its first part: noun, verb, adjective, adverb
+        if let Some(name) = file.pos.keys().find(|name| PartOfSpeech::parse(name).is_none()) {
+            return Err(format!("unknown part of speech '{}'", name));
+        }
+        let mut pos = HashMap::new();
+        for part in [PartOfSpeech::Noun, PartOfSpeech::Verb, PartOfSpeech::Adjective, PartOfSpeech::Adverb] {
+            for word in file.pos.get(part.label()).into_iter().flatten() {
+                pos.entry(word.to_lowercase()).or_insert(part);
+            }
+        }
+
+        let mut suffixes = Vec::new();
+        for part in [PartOfSpeech::Verb, PartOfSpeech::Adjective, PartOfSpeech::Noun, PartOfSpeech::Adverb] {
+            if let Some(list) = file.suffixes.get(part.label()) {
+                suffixes.push((part, list.clone()));
+            }
+        }
+
+        Ok(Self {
+            seeds,
+            pos,
+            suffixes,
+            abstract_prefixes: file.abstract_prefixes,
+            stop_words: lower(&file.stop_words),
+        })
+    }
+
+    pub fn is_stop_word(&self, word: &str) -> bool {
+        self.stop_words.contains(word)
+    }
+
+    /// The part of speech listed for the word or one of its stems.
+    pub fn part_of_speech(&self, word: &str) -> Option<(PartOfSpeech, String)> {
+        stems(word).into_iter().find_map(|stem| self.pos.get(&stem).map(|part| (*part, stem)))
+    }
+
+    fn abstract_prefix(&self, word: &str) -> Option<&str> {
+        self.abstract_prefixes.iter()
+            .find(|prefix| word.starts_with(prefix.as_str()) && word.len() > prefix.len() + 2)
+            .map(|prefix| prefix.as_str())
+    }
+
+    fn suffix(&self, word: &str) -> Option<(PartOfSpeech, &str)> {
+        self.suffixes.iter().find_map(|(part, list)| {
+            list.iter()
+                .find(|suffix| word.ends_with(suffix.as_str()) && word.len() > suffix.len() + 2)
+                .map(|suffix| (*part, suffix.as_str()))
+        })
+    }
+
+    /// Channels whose seed list contains the word or one of its stems.
+    fn seed_channels(&self, word: &str) -> Vec<Channel> {
+        let forms = stems(word);
+        self.seeds.iter()
+            .filter(|(_, seeds)| forms.iter().any(|form| seeds.contains(form)))
+            .map(|(channel, _)| *channel)
+            .collect()
+    }
+}
+
+/// The word and the forms it may have come from: cells → cell,
+/// explored → explore, studies → study.
+fn stems(word: &str) -> Vec<String> {
+    let mut forms = vec![word.to_string()];
+    let mut push = |stem: String| {
+        if stem.len() >= 3 && !forms.contains(&stem) {
+            forms.push(stem);
+        }
+    };
+    if let Some(base) = word.strip_suffix("ies") { push(format!("{}y", base)); }
+    if let Some(base) = word.strip_suffix("ied") { push(format!("{}y", base)); }
+    for suffix in ["es", "s", "ed", "ing", "ly"] {
+        if let Some(base) = word.strip_suffix(suffix) {
+            if !(suffix == "s" && base.ends_with('s')) {
+                push(base.to_string());
+            }
+            if suffix == "ed" || suffix == "ing" {
+                push(format!("{}e", base));
+            }
+        }
+    }
+    forms
+}
+
+// ─── EXTRACT ────────────────────────────────────────────────
+
+/// One input document, already reduced to plain text.
+#[derive(Clone, Debug)]
+pub struct Source {
+    pub name: String,
+    pub text: String,
+}
+
+impl Source {
+    /// Read a text, Markdown (.md) or PDF (.pdf) file.
+    pub fn read(path: &Path) -> Result<Self, BuildError> {
+        let io = |e: std::io::Error| BuildError::Io { path: path.display().to_string(), message: e.to_string() };
+        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("").to_lowercase();
+        let text = match ext.as_str() {
+            "pdf" => pdf_text(&std::fs::read(path).map_err(io)?)
+                .map_err(|reason| BuildError::NoText { path: path.display().to_string(), reason })?,
+            "md" | "markdown" => strip_markdown(&std::fs::read_to_string(path).map_err(io)?),
+            _ => std::fs::read_to_string(path).map_err(io)?,
+        };
+        if text.trim().is_empty() {
+            return Err(BuildError::NoText {
+                path: path.display().to_string(),
+                reason: "the file is empty".to_string(),
+            });
+        }
+        let name = path.file_name().map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.display().to_string());
+        Ok(Self { name, text })
+    }
+}
+
+/// Markdown → prose: code blocks dropped, headings kept as their own
+/// sentences, links and images reduced to their text, markup removed.
+pub fn strip_markdown(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut in_code = false;
+    for raw in markdown.lines() {
+        let line = raw.trim();
+        if line.starts_with("```") || line.starts_with("~~~") {
+            in_code = !in_code;
+            out.push('\n');
+            continue;
+        }
+        let rule = !line.is_empty() && line.chars().all(|c| "-=*_|: ".contains(c));
+        if in_code || rule || line.starts_with("<!--") {
+            continue;
+        }
+        let heading = line.starts_with('#');
+        let mut body = line.trim_start_matches('#').trim_start_matches('>').trim();
+        for marker in ["- ", "* ", "+ "] {
+            if let Some(rest) = body.strip_prefix(marker) {
+                body = rest;
+            }
+        }
+        if let Some((number, rest)) = body.split_once(". ") {
+            if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) {
+                body = rest;
+            }
+        }
+        let body = strip_inline_markdown(body).replace('|', " ");
+        if heading {
+            out.push_str("\n\n");
+            out.push_str(body.trim());
+            out.push_str("\n\n");
+        } else {
+            out.push_str(&body);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// `[text](url)` and `![alt](src)` → text; emphasis, code ticks and
+/// HTML tags removed.
+fn strip_inline_markdown(line: &str) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let mut out = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '!' if chars.get(i + 1) == Some(&'[') => i += 1,
+            '[' => {
+                // Keep the link text; skip a following (url)
+                if let Some(close) = chars[i..].iter().position(|c| *c == ']').map(|p| p + i) {
+                    out.extend(&chars[i + 1..close]);
+                    i = close + 1;
+                    if chars.get(i) == Some(&'(') {
+                        i = chars[i..].iter().position(|c| *c == ')').map_or(chars.len(), |p| p + i + 1);
+                    }
+                } else {
+                    i += 1;
+                }
+            }
+            '<' => {
+                i = chars[i..].iter().position(|c| *c == '>').map_or(chars.len(), |p| p + i + 1);
+            }
+            '*' | '`' => i += 1,
+            '_' if i == 0 || !chars[i - 1].is_alphanumeric() || !chars.get(i + 1).is_some_and(|c| c.is_alphanumeric()) => i += 1,
+            c => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+/// Sentences of a text: paragraphs split on blank lines, lines joined
+/// (mending words hyphenated across a break), then split after . ! ?
+pub fn split_sentences(text: &str) -> Vec<String> {
+    let mut sentences = Vec::new();
+    for paragraph in text.split("\n\n") {
+        let mut joined = String::new();
+        for line in paragraph.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            if joined.ends_with('-') && line.starts_with(|c: char| c.is_lowercase()) {
+                joined.pop();
+            } else if !joined.is_empty() {
+                joined.push(' ');
+            }
+            joined.push_str(line);
+        }
+        let mut current = String::new();
+        let mut chars = joined.chars().peekable();
+        while let Some(c) = chars.next() {
+            current.push(c);
+            if matches!(c, '.' | '!' | '?') && chars.peek().is_none_or(|n| n.is_whitespace()) {
+                push_sentence(&mut sentences, &mut current);
+            }
+        }
+        push_sentence(&mut sentences, &mut current);
+    }
+    sentences
+}
+
+fn push_sentence(sentences: &mut Vec<String>, current: &mut String) {
+    let sentence = current.trim();
+    if sentence.chars().any(char::is_alphabetic) {
+        sentences.push(sentence.to_string());
+    }
+    current.clear();
+}
+
+/// Words of a sentence: runs of letters, keeping inner hyphens and
+/// apostrophes, with a possessive 's dropped.
+pub fn tokenize(sentence: &str) -> Vec<String> {
+    let chars: Vec<char> = sentence.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        let joins = matches!(c, '-' | '\'' | '’')
+            && !current.is_empty()
+            && chars.get(i + 1).is_some_and(|n| n.is_alphabetic());
+        if c.is_alphabetic() || joins {
+            current.push(*c);
+        } else if !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words.into_iter()
+        .map(|w| w.strip_suffix("'s").or_else(|| w.strip_suffix("’s")).map(str::to_string).unwrap_or(w))
+        .collect()
+}
+
+/// A word that might become a card.
+#[derive(Clone, Debug)]
+pub struct Candidate {
+    /// As it will appear on the card.
+    pub word: String,
+    /// Lowercase form used for matching.
+    pub key: String,
+    pub frequency: u32,
+    /// Order of first appearance across all sources.
+    pub first_seen: usize,
+    /// Sentences it appears in (indices into Extraction::sentences).
+    pub sentences: Vec<usize>,
+    /// Capitalized even mid-sentence: a name or key term.
+    pub proper: bool,
+    /// Up to three sentences showing the word in use.
+    pub contexts: Vec<String>,
+}
+
+/// The candidate words of a set of sources.
+#[derive(Clone, Debug, Default)]
+pub struct Extraction {
+    pub sentences: Vec<String>,
+    /// Filtered lowercase words of each sentence.
+    pub sentence_words: Vec<Vec<String>>,
+    /// In order of first appearance.
+    pub candidates: Vec<Candidate>,
+    /// Distinct words that passed the length and stop-word filters.
+    pub distinct_words: usize,
+    pub total_words: usize,
+}
+
+const MAX_CONTEXTS: usize = 3;
+const MAX_CONTEXT_CHARS: usize = 200;
+const MIN_CONTEXT_WORDS: usize = 5;
+
+/// Count words across the sources and keep the most frequent.
+pub fn extract(sources: &[Source], lexicon: &Lexicon, config: &BuildConfig) -> Extraction {
+    struct Tally {
+        display: String,
+        count: u32,
+        first_seen: usize,
+        sentences: Vec<usize>,
+        lowercase: u32,
+        capitalized_mid: u32,
+    }
+
+    let mut extraction = Extraction::default();
+    let mut tallies: HashMap<String, Tally> = HashMap::new();
+    let mut sentence_lengths = Vec::new();
+    let mut position = 0;
+
+    for source in sources {
+        for sentence in split_sentences(&source.text) {
+            let id = extraction.sentences.len();
+            let mut kept = Vec::new();
+            let tokens = tokenize(&sentence);
+            sentence_lengths.push(tokens.len());
+            for (index, token) in tokens.into_iter().enumerate() {
+                extraction.total_words += 1;
+                let key = token.to_lowercase();
+                if key.chars().count() < config.min_length || lexicon.is_stop_word(&key) {
+                    continue;
+                }
+                position += 1;
+                let tally = tallies.entry(key.clone()).or_insert_with(|| Tally {
+                    display: token.clone(),
+                    count: 0,
+                    first_seen: position,
+                    sentences: Vec::new(),
+                    lowercase: 0,
+                    capitalized_mid: 0,
+                });
+                tally.count += 1;
+                if token.starts_with(char::is_uppercase) {
+                    if index > 0 {
+                        tally.capitalized_mid += 1;
+                    }
+                } else {
+                    tally.lowercase += 1;
+                }
+                if tally.sentences.last() != Some(&id) {
+                    tally.sentences.push(id);
+                }
+                kept.push(key);
+            }
+            extraction.sentences.push(sentence);
+            extraction.sentence_words.push(kept);
+        }
+    }
+
+    extraction.distinct_words = tallies.len();
+    let mut candidates: Vec<Candidate> = tallies.into_iter()
+        .filter(|(_, t)| t.count >= config.min_frequency)
+        .map(|(key, t)| {
+            let proper = t.capitalized_mid > 0 && t.lowercase == 0;
+            // Whole sentences make better context than headings
+            let mut in_use = t.sentences.clone();
+            in_use.sort_by_key(|id| sentence_lengths[*id] < MIN_CONTEXT_WORDS);
+            let contexts = in_use.iter().take(MAX_CONTEXTS)
+                .map(|id| truncate(&extraction.sentences[*id], MAX_CONTEXT_CHARS))
+                .collect();
+            Candidate {
+                word: if proper { t.display } else { capitalize(&key) },
+                key,
+                frequency: t.count,
+                first_seen: t.first_seen,
+                sentences: t.sentences,
+                proper,
+                contexts,
+            }
+        })
+        .collect();
+    candidates.sort_by(|a, b| b.frequency.cmp(&a.frequency).then(a.first_seen.cmp(&b.first_seen)));
+    candidates.truncate(config.max_words);
+    candidates.sort_by_key(|c| c.first_seen);
+    extraction.candidates = candidates;
+    extraction
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(max_chars).collect();
+    let cut = cut.rsplit_once(' ').map_or(cut.as_str(), |(head, _)| head);
+    format!("{}…", cut)
+}
+
+// ─── CLASSIFY ───────────────────────────────────────────────
+
+/// Channel, Stage and Symbol for one word, with the reasons behind them.
+#[derive(Clone, Debug)]
+pub struct Classification {
+    pub channel: Channel,
+    /// Share of the seed evidence the winning channel got (0.25 when
+    /// nothing matched and Body was assumed).
+    pub channel_confidence: f32,
+    pub channel_reason: String,
+    pub stage: Stage,
+    pub stage_reason: String,
+    pub symbol: Symbol,
+    pub symbol_reason: String,
+}
+
+/// Classify a candidate from its spelling and the sentences it is in.
+pub fn classify(candidate: &Candidate, lexicon: &Lexicon) -> Classification {
+    let (channel, channel_confidence, channel_reason) = classify_channel(candidate, lexicon);
+    let (stage, stage_reason) = classify_stage(candidate, lexicon);
+    let (symbol, symbol_reason) = classify_symbol(candidate, lexicon);
+    Classification { channel, channel_confidence, channel_reason, stage, stage_reason, symbol, symbol_reason }
+}
+
+/// A seed match on the word itself counts 5; each seed word in its
+/// sentences counts 1. No evidence at all means Body (grounding).
+fn classify_channel(candidate: &Candidate, lexicon: &Lexicon) -> (Channel, f32, String) {
+    let mut scores = [0u32; 4];
+    let direct = lexicon.seed_channels(&candidate.key);
+    for channel in &direct {
+        scores[channel_index(*channel)] += 5;
+    }
+    for context in &candidate.contexts {
+        for token in tokenize(context) {
+            let token = token.to_lowercase();
+            if token == candidate.key || lexicon.is_stop_word(&token) {
+                continue;
+            }
+            for channel in lexicon.seed_channels(&token) {
+                scores[channel_index(channel)] += 1;
+            }
+        }
+    }
+
+    let total: u32 = scores.iter().sum();
+    if total == 0 {
+        return (Channel::Body, 0.25, "no seed words nearby — Body by default".to_string());
+    }
+    let best = (0..4).max_by_key(|i| (scores[*i], std::cmp::Reverse(*i))).unwrap_or(2);
+    let channel = Channel::ALL[best];
+    let tally: Vec<String> = Channel::ALL.iter().zip(scores)
+        .filter(|(_, score)| *score > 0)
+        .map(|(channel, score)| format!("{} {}", channel.label(), score))
+        .collect();
+    let reason = if direct.contains(&channel) {
+        format!("seed word itself; {}", tally.join(", "))
+    } else {
+        format!("seed words nearby: {}", tally.join(", "))
+    };
+    (channel, scores[best] as f32 / total as f32, reason)
+}
+
+fn channel_index(channel: Channel) -> usize {
+    Channel::ALL.iter().position(|c| *c == channel).unwrap_or(2)
+}
+
+/// Longer, rarer and more abstract words ask for a later stage.
+fn classify_stage(candidate: &Candidate, lexicon: &Lexicon) -> (Stage, String) {
+    let key = &candidate.key;
+    if let Some(prefix) = lexicon.abstract_prefix(key) {
+        return (Stage::EdgeLord, format!("abstract prefix '{}-'", prefix));
+    }
+    if key.contains('-') {
+        return (Stage::EdgeLord, "compound word".to_string());
+    }
+    let count = syllables(key);
+    match count {
+        0..=2 => (Stage::Hero, format!("{} syllables", count)),
+        3 if candidate.frequency >= 3 => (Stage::Hero, "3 syllables, used often".to_string()),
+        3 => (Stage::Outlaw, "3 syllables, used rarely".to_string()),
+        4 => (Stage::Outlaw, "4 syllables".to_string()),
+        _ if key.chars().count() > 12 => (Stage::EdgeLord, format!("{} syllables, long technical term", count)),
+        _ => (Stage::BestSelf, format!("{} syllables", count)),
+    }
+}
+
+/// Vowel groups, less a silent final e.
+pub fn syllables(word: &str) -> usize {
+    let mut count = 0;
+    let mut previous_vowel = false;
+    for c in word.chars() {
+        let vowel = "aeiouy".contains(c.to_ascii_lowercase());
+        if vowel && !previous_vowel {
+            count += 1;
+        }
+        previous_vowel = vowel;
+    }
+    if count > 1 && word.ends_with('e') && !word.ends_with("le") && !word.ends_with("ee") {
+        count -= 1;
+    }
+    count.max(1)
+}
+
+fn classify_symbol(candidate: &Candidate, lexicon: &Lexicon) -> (Symbol, String) {
+    let key = &candidate.key;
+    if let Some(prefix) = lexicon.abstract_prefix(key) {
+        return (Symbol::Void, format!("abstract prefix '{}-'", prefix));
+    }
+    if let Some((part, stem)) = lexicon.part_of_speech(key) {
+        return (part.symbol(), format!("lexicon: '{}' is a {}", stem, part.label()));
+    }
+    if candidate.proper {
+        return (Symbol::Star, "capitalized mid-sentence — a name or key term".to_string());
+    }
+    if let Some((part, suffix)) = lexicon.suffix(key) {
+        return (part.symbol(), format!("suffix '-{}' ({})", suffix, part.label()));
+    }
+    (Symbol::Stone, "no evidence — Stone by default".to_string())
+}
+
+// ─── CONNECT ────────────────────────────────────────────────
+
+/// Edges and themes for each candidate, by index.
+#[derive(Clone, Debug, Default)]
+pub struct Connections {
+    pub yes_targets: Vec<Vec<usize>>,
+    pub no_targets: Vec<Vec<usize>>,
+    pub synergy_partners: Vec<Vec<usize>>,
+    pub themes: Vec<Vec<String>>,
+    /// Shared sentences between each pair.
+    pub cooccurrence: Vec<Vec<u32>>,
+}
+
+/// Link words that share sentences. yes_targets only point to words that
+/// appear later, so the forward path can never loop.
+pub fn connect(extraction: &Extraction, classes: &[Classification], config: &BuildConfig) -> Connections {
+    let candidates = &extraction.candidates;
+    let n = candidates.len();
+    let mut cooccurrence = vec![vec![0u32; n]; n];
+    for i in 0..n {
+        for j in i + 1..n {
+            let shared = shared_count(&candidates[i].sentences, &candidates[j].sentences);
+            cooccurrence[i][j] = shared;
+            cooccurrence[j][i] = shared;
+        }
+    }
+
+    let ranked = |i: usize, others: &mut Vec<usize>, nearest_last: bool| {
+        others.sort_by(|a, b| {
+            let by_count = cooccurrence[i][*b].cmp(&cooccurrence[i][*a]);
+            if nearest_last { by_count.then(b.cmp(a)) } else { by_count.then(a.cmp(b)) }
+        });
+    };
+
+    let mut connections = Connections::default();
+    for i in 0..n {
+        let stage = classes[i].stage;
+
+        let mut yes: Vec<usize> = (i + 1..n)
+            .filter(|j| cooccurrence[i][*j] > 0 && classes[*j].stage >= stage)
+            .collect();
+        ranked(i, &mut yes, false);
+        yes.truncate(2);
+        if yes.is_empty() && i + 1 < n {
+            yes.push(i + 1);
+        }
+
+        let mut no: Vec<usize> = (0..i)
+            .filter(|j| cooccurrence[i][*j] > 0 && classes[*j].stage <= stage)
+            .collect();
+        ranked(i, &mut no, true);
+        no.truncate(2);
+
+        let mut partners: Vec<usize> = (0..n)
+            .filter(|j| *j != i && cooccurrence[i][*j] >= config.min_cooccurrence)
+            .collect();
+        ranked(i, &mut partners, false);
+        partners.truncate(3);
+
+        connections.yes_targets.push(yes);
+        connections.no_targets.push(no);
+        connections.synergy_partners.push(partners);
+        connections.themes.push(themes_for(extraction, i));
+    }
+    connections.cooccurrence = cooccurrence;
+    connections
+}
+
+fn shared_count(a: &[usize], b: &[usize]) -> u32 {
+    let (mut i, mut j, mut shared) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                shared += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    shared
+}
+
+/// The two words (not themselves cards) seen most often around a word.
+fn themes_for(extraction: &Extraction, index: usize) -> Vec<String> {
+    let candidate_keys: HashSet<&str> = extraction.candidates.iter().map(|c| c.key.as_str()).collect();
+    let mut counts: HashMap<&str, u32> = HashMap::new();
+    for sentence in &extraction.candidates[index].sentences {
+        for word in &extraction.sentence_words[*sentence] {
+            if !candidate_keys.contains(word.as_str()) {
+                *counts.entry(word.as_str()).or_default() += 1;
+            }
+        }
+    }
+    let mut themes: Vec<(&str, u32)> = counts.into_iter().collect();
+    themes.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    themes.into_iter().take(2).map(|(word, _)| word.to_string()).collect()
+}
+
+// ─── BUILD ──────────────────────────────────────────────────
+
+/// A drafted curriculum and the report to review it with.
+#[derive(Clone, Debug)]
+pub struct Build {
+    pub curriculum: CurriculumDef,
+    pub report: String,
+}
+
+/// Extract, classify and connect — the whole pipeline over some sources.
+pub fn build(sources: &[Source], lexicon: &Lexicon, config: &BuildConfig) -> Result<Build, BuildError> {
+    let extraction = extract(sources, lexicon, config);
+    if extraction.candidates.is_empty() {
+        return Err(BuildError::NoWords { min_frequency: config.min_frequency });
+    }
+    let classes: Vec<Classification> = extraction.candidates.iter()
+        .map(|candidate| classify(candidate, lexicon))
+        .collect();
+    let connections = connect(&extraction, &classes, config);
+
+    let candidates = &extraction.candidates;
+    let names = |indices: &[usize]| indices.iter().map(|i| candidates[*i].word.clone()).collect::<Vec<_>>();
+    let words = candidates.iter().enumerate().map(|(i, candidate)| {
+        let class = &classes[i];
+        let context = candidate.contexts.first().cloned().unwrap_or_default();
+        WordDef {
+            word: candidate.word.clone(),
+            depth_prompt: format!("\"{}\" — {}", context, class.channel.question()),
+            themes: connections.themes[i].clone(),
+            mood: mood(class.channel, class.stage).to_string(),
+            story_text: context,
+            channel: class.channel.label().to_lowercase(),
+            stage: stage_key(class.stage).to_string(),
+            symbol: class.symbol.label().to_lowercase(),
+            card_type: "spell".to_string(),
+            yes_targets: names(&connections.yes_targets[i]),
+            no_targets: names(&connections.no_targets[i]),
+            low_focus_targets: Vec::new(),
+            synergy_partners: names(&connections.synergy_partners[i]),
+            translations: HashMap::new(),
+        }
+    }).collect();
+
+    let start = classes.iter().position(|c| c.stage == Stage::Hero).unwrap_or(0);
+    let curriculum = CurriculumDef {
+        name: config.name.clone(),
+        start_word: candidates[start].word.clone(),
+        words,
+    };
+    curriculum.validate().map_err(BuildError::Invalid)?;
+
+    let report = review_report(sources, &extraction, &classes, &connections, &curriculum, config);
+    Ok(Build { curriculum, report })
+}
+
+pub fn mood(channel: Channel, stage: Stage) -> &'static str {
+    MOOD_MAP[channel_index(channel)][stage_key_index(stage)]
+}
+
+fn stage_key_index(stage: Stage) -> usize {
+    match stage {
+        Stage::Hero     => 0,
+        Stage::Outlaw   => 1,
+        Stage::EdgeLord => 2,
+        Stage::BestSelf => 3,
+    }
+}
+
+/// The curriculum file's spelling of a stage.
+fn stage_key(stage: Stage) -> &'static str {
+    ["hero", "outlaw", "edge_lord", "best_self"][stage_key_index(stage)]
+}
+
+// ─── REVIEW REPORT ──────────────────────────────────────────
+
+/// Markdown for the instructor: what was found, what to check first,
+/// then every word with the reasons for its classification.
+fn review_report(
+    sources: &[Source],
+    extraction: &Extraction,
+    classes: &[Classification],
+    connections: &Connections,
+    curriculum: &CurriculumDef,
+    config: &BuildConfig,
+) -> String {
+    let candidates = &extraction.candidates;
+    let mut out = String::new();
+    let mut line = |text: String| {
+        out.push_str(&text);
+        out.push('\n');
+    };
+
+    line(format!("# Review: {}", curriculum.name));
+    line(String::new());
+    line("Drafted by the curriculum builder. Every field is a guess — check the".to_string());
+    line("flagged words first, then rewrite the prompts and story text in your own voice.".to_string());
+    line(String::new());
+    line("## Sources".to_string());
+    line(String::new());
+    for source in sources {
+        line(format!("- {} ({} sentences)", source.name, split_sentences(&source.text).len()));
+    }
+    line(String::new());
+    line(format!(
+        "{} words read, {} distinct after filtering; {} kept (seen {}+ times, at most {}). Start word: **{}**.",
+        extraction.total_words, extraction.distinct_words, candidates.len(),
+        config.min_frequency, config.max_words, curriculum.start_word,
+    ));
+    line(String::new());
+
+    line("## Check first".to_string());
+    line(String::new());
+    let weak: Vec<String> = candidates.iter().zip(classes)
+        .filter(|(_, class)| class.channel_confidence < LOW_CONFIDENCE)
+        .map(|(candidate, class)| format!("{} ({}, {:.0}%)", candidate.word, class.channel.label(), class.channel_confidence * 100.0))
+        .collect();
+    let lonely: Vec<String> = candidates.iter().enumerate()
+        .filter(|(i, _)| connections.synergy_partners[*i].is_empty())
+        .map(|(_, candidate)| candidate.word.clone())
+        .collect();
+    let unreachable: Vec<String> = unreachable_words(curriculum);
+    for (title, words) in [
+        ("Low-confidence channel", weak),
+        ("No synergy partners", lonely),
+        ("Not reachable from the start word", unreachable),
+    ] {
+        if words.is_empty() {
+            line(format!("- {}: none", title));
+        } else {
+            line(format!("- {} ({}): {}", title, words.len(), words.join(", ")));
+        }
+    }
+    line(String::new());
+
+    line("## Words".to_string());
+    line(String::new());
+    line("| Word | Seen | Channel | Stage | Symbol | Yes → | Synergy |".to_string());
+    line("|---|---|---|---|---|---|---|".to_string());
+    for (def, (candidate, class)) in curriculum.words.iter().zip(candidates.iter().zip(classes)) {
+        line(format!(
+            "| {} | {} | {} ({:.0}%) | {} | {} | {} | {} |",
+            def.word, candidate.frequency, class.channel.label(), class.channel_confidence * 100.0,
+            class.stage.label(), class.symbol.label(),
+            def.yes_targets.join(", "), def.synergy_partners.join(", "),
+        ));
+    }
+    line(String::new());
+
+    for (i, (def, class)) in curriculum.words.iter().zip(classes).enumerate() {
+        line(format!("### {}", def.word));
+        line(String::new());
+        line(format!("- Channel: {} — {}", class.channel.label(), class.channel_reason));
+        line(format!("- Stage: {} — {}", class.stage.label(), class.stage_reason));
+        line(format!("- Symbol: {} — {}", class.symbol.label(), class.symbol_reason));
+        let shared: Vec<String> = connections.synergy_partners[i].iter()
+            .map(|j| format!("{} ({} sentences)", candidates[*j].word, connections.cooccurrence[i][*j]))
+            .collect();
+        if !shared.is_empty() {
+            line(format!("- Appears with: {}", shared.join(", ")));
+        }
+        line(format!("- Themes: {}", if def.themes.is_empty() { "none found".to_string() } else { def.themes.join(", ") }));
+        line("- In context:".to_string());
+        for context in &candidates[i].contexts {
+            line(format!("  > {}", context));
+        }
+        line(String::new());
+    }
+    out
+}
+
+/// Words no chain of yes/no targets leads to from the start word.
+fn unreachable_words(curriculum: &CurriculumDef) -> Vec<String> {
+    let by_name: HashMap<&str, &WordDef> = curriculum.words.iter().map(|w| (w.word.as_str(), w)).collect();
+    let mut seen: HashSet<&str> = HashSet::new();
+    let mut queue = VecDeque::from([curriculum.start_word.as_str()]);
+    while let Some(word) = queue.pop_front() {
+        if !seen.insert(word) {
+            continue;
+        }
+        if let Some(def) = by_name.get(word) {
+            queue.extend(def.yes_targets.iter().chain(&def.no_targets).map(String::as_str));
+        }
+    }
+    curriculum.words.iter()
+        .filter(|w| !seen.contains(w.word.as_str()))
+        .map(|w| w.word.clone())
+        .collect()
+}
+
+// ─── PDF TEXT ───────────────────────────────────────────────
+
+/// A page's text inflates to a few hundred KB at most. A stream that
+/// would grow past this is skipped rather than filling memory.
+const MAX_INFLATED_STREAM: usize = 16 * 1024 * 1024;
+
+/// Text shown by the page content streams of a PDF. Streams are read
+/// raw or inflated (FlateDecode); images, fonts, other encodings and
+/// streams inflating past MAX_INFLATED_STREAM are skipped.
+pub fn pdf_text(bytes: &[u8]) -> Result<String, String> {
+    if !bytes.starts_with(b"%PDF") {
+        return Err("not a PDF file".to_string());
+    }
+    let mut text = String::new();
+    let mut at = 0;
+    while let Some(start) = find(bytes, b"stream", at) {
+        at = start + b"stream".len();
+        // "endstream" also contains "stream"
+        if start >= 3 && &bytes[start - 3..start] == b"end" {
+            continue;
+        }
+        let mut data_start = at;
+        if bytes.get(data_start) == Some(&b'\r') {
+            data_start += 1;
+        }
+        if bytes.get(data_start) != Some(&b'\n') {
+            continue;
+        }
+        data_start += 1;
+        let Some(end) = find(bytes, b"endstream", data_start) else { break };
+        at = end + b"endstream".len();
+
+        let dict_start = rfind(&bytes[..start], b"obj").unwrap_or(0);
+        let dict = String::from_utf8_lossy(&bytes[dict_start..start]);
+        if dict.contains("/Image") || dict.contains("/Length1") || dict.contains("/XRef") || dict.contains("/ObjStm") {
+            continue;
+        }
+        let raw = trim_eol(&bytes[data_start..end]);
+        let content = if dict.contains("/FlateDecode") {
+            match inflate(raw, MAX_INFLATED_STREAM) {
+                Some(inflated) => inflated,
+                None => continue,
+            }
+        } else if dict.contains("/Filter") {
+            continue;
+        } else {
+            raw.to_vec()
+        };
+        let shown = content_text(&content);
+        if !shown.trim().is_empty() {
+            text.push_str(&shown);
+            text.push_str("\n\n");
+        }
+    }
+    if text.trim().is_empty() {
+        return Err("no readable text (scanned pages or custom font encodings?) — try a text export".to_string());
+    }
+    Ok(text)
+}
+
+/// A FlateDecode stream's data, unless it is corrupt or larger than `limit`.
+fn inflate(raw: &[u8], limit: usize) -> Option<Vec<u8>> {
+    miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(raw, limit).ok()
+}
+
+fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    haystack.get(from..)?.windows(needle.len()).position(|w| w == needle).map(|p| p + from)
+}
+
+fn rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).rposition(|w| w == needle)
+}
+
+/// Stream data ends with one end-of-line before `endstream`.
+fn trim_eol(data: &[u8]) -> &[u8] {
+    data.strip_suffix(b"\r\n")
+        .or_else(|| data.strip_suffix(b"\n"))
+        .or_else(|| data.strip_suffix(b"\r"))
+        .unwrap_or(data)
+}
+
+/// One operand on the content stream's stack.
+enum Operand {
+    Text(String),
+    /// A TJ array: its strings, with wide kerning gaps read as spaces.
+    Array(String),
+    Other,
+}
+
+/// Walk a content stream and collect what Tj, TJ, ' and " show.
+/// Text moves (Td, TD, T*) and text-object ends start a new line.
+fn content_text(content: &[u8]) -> String {
+    let mut out = String::new();
+    let mut operands: Vec<Operand> = Vec::new();
+    let mut i = 0;
+    while i < content.len() {
+        let c = content[i];
+        match c {
+            b'%' => {
+                while i < content.len() && content[i] != b'\n' && content[i] != b'\r' {
+                    i += 1;
+                }
+            }
+            b'(' => {
+                let (text, next) = literal_string(content, i);
+                operands.push(Operand::Text(text));
+                i = next;
+                continue;
+            }
+            b'<' if content.get(i + 1) == Some(&b'<') => {
+                operands.push(Operand::Other);
+                i += 2;
+                continue;
+            }
+            b'<' => {
+                let (text, next) = hex_string(content, i);
+                operands.push(Operand::Text(text));
+                i = next;
+                continue;
+            }
+            b'[' => {
+                let (text, next) = text_array(content, i);
+                operands.push(Operand::Array(text));
+                i = next;
+                continue;
+            }
+            b'/' | b'+' | b'-' | b'.' | b'0'..=b'9' | b'>' | b']' | b'{' | b'}' => {
+                let start = i;
+                i += 1;
+                while i < content.len() && !is_delimiter(content[i]) && !content[i].is_ascii_whitespace() {
+                    i += 1;
+                }
+                if i > start + 1 || !matches!(c, b'>' | b']') {
+                    operands.push(Operand::Other);
+                }
+                continue;
+            }
+            _ if c.is_ascii_whitespace() => {}
+            _ => {
+                let start = i;
+                while i < content.len() && !is_delimiter(content[i]) && !content[i].is_ascii_whitespace() {
+                    i += 1;
+                }
+                if i == start {
+                    i += 1;
+                }
+                apply_operator(&content[start..i], &mut operands, &mut out);
+                continue;
+            }
+        }
+        i += 1;
+    }
+    out
+}
+
+fn is_delimiter(c: u8) -> bool {
+    matches!(c, b'(' | b')' | b'<' | b'>' | b'[' | b']' | b'{' | b'}' | b'/' | b'%')
+}
+
+fn apply_operator(operator: &[u8], operands: &mut Vec<Operand>, out: &mut String) {
+    let last_text = |operands: &Vec<Operand>| operands.iter().rev().find_map(|o| match o {
+        Operand::Text(text) | Operand::Array(text) => Some(text.clone()),
+        Operand::Other => None,
+    });
+    match operator {
+        b"Tj" | b"TJ" => out.push_str(&last_text(operands).unwrap_or_default()),
+        b"'" | b"\"" => {
+            out.push('\n');
+            out.push_str(&last_text(operands).unwrap_or_default());
+        }
+        b"Td" | b"TD" | b"T*" | b"ET" if !out.is_empty() && !out.ends_with('\n') => out.push('\n'),
+        _ => {}
+    }
+    operands.clear();
+}
+
+/// A `( ... )` string: nested parentheses, escapes and octal codes,
+/// bytes read as Latin-1. Returns the text and the index after it.
+fn literal_string(content: &[u8], open: usize) -> (String, usize) {
+    let mut text = String::new();
+    let mut depth = 1;
+    let mut i = open + 1;
+    while i < content.len() {
+        let c = content[i];
+        match c {
+            b'\\' => {
+                i += 1;
+                let Some(&escaped) = content.get(i) else { break };
+                match escaped {
+                    b'n' => text.push('\n'),
+                    b'r' => text.push('\r'),
+                    b't' => text.push('\t'),
+                    b'b' | b'f' => {}
+                    b'\r' | b'\n' => {
+                        if escaped == b'\r' && content.get(i + 1) == Some(&b'\n') {
+                            i += 1;
+                        }
+                    }
+                    b'0'..=b'7' => {
+                        let mut code = 0u32;
+                        let mut digits = 0;
+                        while digits < 3 && i < content.len() && (b'0'..=b'7').contains(&content[i]) {
+                            code = code * 8 + (content[i] - b'0') as u32;
+                            i += 1;
+                            digits += 1;
+                        }
+                        text.push(char::from_u32(code & 0xff).unwrap_or(' '));
+                        continue;
+                    }
+                    other => text.push(other as char),
+                }
+            }
+            b'(' => {
+                depth += 1;
+                text.push('(');
+            }
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return (text, i + 1);
+                }
+                text.push(')');
+            }
+            other => text.push(other as char),
+        }
+        i += 1;
+    }
+    (text, i)
+}
+
+/// A `< ... >` hex string, bytes read as Latin-1.
+fn hex_string(content: &[u8], open: usize) -> (String, usize) {
+    let close = find(content, b">", open).unwrap_or(content.len());
+    let digits: Vec<u8> = content[open + 1..close].iter()
+        .filter_map(|c| (*c as char).to_digit(16).map(|d| d as u8))
+        .collect();
+    let text = digits.chunks(2)
+        .map(|pair| (pair[0] << 4 | pair.get(1).copied().unwrap_or(0)) as char)
+        .collect();
+    (text, close + 1)
+}
+
+/// A TJ array. Kerning of a thousandth of an em is routine; a gap wider
+/// than a fifth of an em is how many PDFs write a space.
+fn text_array(content: &[u8], open: usize) -> (String, usize) {
+    let mut text = String::new();
+    let mut i = open + 1;
+    while i < content.len() && content[i] != b']' {
+        match content[i] {
+            b'(' => {
+                let (part, next) = literal_string(content, i);
+                text.push_str(&part);
+                i = next;
+            }
+            b'<' => {
+                let (part, next) = hex_string(content, i);
+                text.push_str(&part);
+                i = next;
+            }
+            b'-' | b'.' | b'0'..=b'9' => {
+                let start = i;
+                while i < content.len() && matches!(content[i], b'-' | b'.' | b'0'..=b'9') {
+                    i += 1;
+                }
+                let gap: f32 = std::str::from_utf8(&content[start..i]).ok()
+                    .and_then(|n| n.parse().ok())
+                    .unwrap_or(0.0);
+                if gap < -200.0 && !text.ends_with(' ') {
+                    text.push(' ');
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    (text, i + 1)
+}
+
+// ─── CLI ────────────────────────────────────────────────────
+
+fn config_from_args(args: &[String]) -> Result<(BuildConfig, Vec<PathBuf>, PathBuf, PathBuf), BuildError> {
+    let value = |flag: &str| args.iter().find_map(|a| a.strip_prefix(flag));
+    let number = |flag: &str, what: &str| -> Result<Option<u32>, BuildError> {
+        value(flag).map(|n| n.parse().ok().filter(|n| *n > 0)
+            .ok_or_else(|| BuildError::BadArgs(format!("bad {} '{}'", what, n))))
+            .transpose()
+    };
+
+    let inputs: Vec<PathBuf> = args.iter().skip(1)
+        .filter(|a| !a.starts_with("--"))
+        .map(PathBuf::from)
+        .collect();
+    if inputs.is_empty() {
+        return Err(BuildError::BadArgs(
+            "usage: daydream-engine --build FILE... [--name=NAME] [--out=FILE] [--report=FILE] \
+             [--min-frequency=N] [--max-words=N] [--min-cooccurrence=N]".to_string(),
+        ));
+    }
+
+    let defaults = BuildConfig::default();
+    let name = match value("--name=") {
+        Some(name) => name.to_string(),
+        None => inputs[0].file_stem()
+            .map(|stem| capitalize(&stem.to_string_lossy().replace(['_', '-'], " ")))
+            .unwrap_or(defaults.name),
+    };
+    let config = BuildConfig {
+        name,
+        min_frequency: number("--min-frequency=", "minimum frequency")?.unwrap_or(defaults.min_frequency),
+        max_words: number("--max-words=", "word count")?.map_or(defaults.max_words, |n| n as usize),
+        min_cooccurrence: number("--min-cooccurrence=", "co-occurrence")?.unwrap_or(defaults.min_cooccurrence),
+        min_length: defaults.min_length,
+    };
+    let out = PathBuf::from(value("--out=").unwrap_or(DEFAULT_OUT_PATH));
+    let report = value("--report=").map(PathBuf::from)
+        .unwrap_or_else(|| out.with_extension("review.md"));
+    Ok((config, inputs, out, report))
+}
+
+/// `--build`: read the sources, write the curriculum and review report.
+/// Returns the process exit code.
+pub fn run_from_args() -> i32 {
+    let args: Vec<String> = std::env::args().collect();
+    let result = config_from_args(&args).and_then(|(config, inputs, out, report_path)| {
+        let sources = inputs.iter().map(|path| Source::read(path)).collect::<Result<Vec<_>, _>>()?;
+        let built = build(&sources, &Lexicon::bundled(), &config)?;
+        let json = serde_json::to_string_pretty(&built.curriculum)
+            .map_err(|e| BuildError::BadArgs(e.to_string()))?;
+        let write = |path: &Path, text: &str| std::fs::write(path, text)
+            .map_err(|e| BuildError::Io { path: path.display().to_string(), message: e.to_string() });
+        write(&out, &json)?;
+        write(&report_path, &built.report)?;
+        println!(
+            "Built '{}': {} words from {} source(s) → {} (review: {})",
+            built.curriculum.name, built.curriculum.words.len(), sources.len(),
+            out.display(), report_path.display(),
+        );
+        Ok(())
+    });
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("daydream --build: {}", e);
+            1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHAPTER: &str = "\
+# Cells and Energy
+
+Every living organism is built from cells. A cell takes in energy from food.
+Plants capture light energy in a process called photosynthesis.
+Photosynthesis turns light and water into energy the cell can store.
+
+Inside the cell, the Mitochondria release energy. Scientists observe cells
+under a microscope. They observe how each cell divides to grow.
+";
+
+    fn source(text: &str) -> Source {
+        Source { name: "chapter.md".to_string(), text: strip_markdown(text) }
+    }
+
+    #[test]
+    fn test_markdown_is_reduced_to_prose() {
+        let text = strip_markdown(
+            "# Title\n\nSome **bold** and _soft_ text with a [link](http://x.y) and `code`.\n\
+             ```\nlet hidden = 1;\n```\n- a listed item\n![diagram](img.png) <b>tag</b>\n",
+        );
+        assert!(text.contains("Title"));
+        assert!(text.contains("Some bold and soft text with a link and code."));
+        assert!(text.contains("a listed item"));
+        assert!(text.contains("diagram tag"));
+        assert!(!text.contains("hidden"));
+        assert!(!text.contains("http"));
+        assert!(!text.contains('*'));
+        // The heading stays its own sentence
+        assert_eq!(split_sentences(&text)[0], "Title");
+    }
+
+    #[test]
+    fn test_extraction_counts_words_with_their_sentences() {
+        let lexicon = Lexicon::bundled();
+        let extraction = extract(&[source(CHAPTER)], &lexicon, &BuildConfig::default());
+        let keys: Vec<&str> = extraction.candidates.iter().map(|c| c.key.as_str()).collect();
+
+        // Frequent content words, in order of first appearance; stop words
+        // and one-off words are left out
+        assert_eq!(keys[0], "cells");
+        assert!(keys.contains(&"energy"));
+        assert!(keys.contains(&"photosynthesis"));
+        assert!(!keys.contains(&"every"));
+        assert!(!keys.contains(&"microscope"));
+
+        let energy = extraction.candidates.iter().find(|c| c.key == "energy").unwrap();
+        assert_eq!(energy.frequency, 5);
+        assert_eq!(energy.contexts.len(), MAX_CONTEXTS);
+        assert!(energy.contexts[0].starts_with("A cell takes in energy"));
+        // A heading only serves as context when nothing better is left
+        assert!(!energy.contexts.iter().any(|c| c == "Cells and Energy"));
+        // Sentence-initial capitals don't make a proper noun
+        let photo = extraction.candidates.iter().find(|c| c.key == "photosynthesis").unwrap();
+        assert!(!photo.proper);
+        assert_eq!(photo.word, "Photosynthesis");
+    }
+
+    #[test]
+    fn test_words_are_classified_from_lexicon_and_context() {
+        let lexicon = Lexicon::bundled();
+        let extraction = extract(&[source(CHAPTER)], &lexicon, &BuildConfig::default());
+        let class = |key: &str| classify(extraction.candidates.iter().find(|c| c.key == key).unwrap(), &lexicon);
+
+        let cell = class("cell");
+        assert_eq!(cell.channel, Channel::Body);
+        assert_eq!(cell.stage, Stage::Hero);
+        assert_eq!(cell.symbol, Symbol::Stone);
+
+        let observe = class("observe");
+        assert_eq!(observe.channel, Channel::Mind);
+        assert_eq!(observe.symbol, Symbol::Spark);
+
+        let photo = class("photosynthesis");
+        assert_eq!(photo.stage, Stage::EdgeLord);
+
+        assert_eq!(classify(&Candidate {
+            word: "Metacognition".to_string(),
+            key: "metacognition".to_string(),
+            frequency: 2,
+            first_seen: 0,
+            sentences: vec![],
+            proper: false,
+            contexts: vec![],
+        }, &lexicon).symbol, Symbol::Void);
+        assert_eq!(syllables("patience"), 2);
+        assert_eq!(syllables("energy"), 3);
+    }
+
+    #[test]
+    fn test_built_curriculum_validates_and_round_trips() {
+        let lexicon = Lexicon::bundled();
+        let built = build(&[source(CHAPTER)], &lexicon, &BuildConfig {
+            name: "Cells".to_string(),
+            ..Default::default()
+        }).unwrap();
+        let curriculum = &built.curriculum;
+        assert_eq!(curriculum.name, "Cells");
+        assert!(curriculum.words.len() >= 4);
+        assert!(curriculum.words.iter().any(|w| w.word == curriculum.start_word));
+
+        // Cell and Energy share three sentences: partners both ways
+        let cell = curriculum.words.iter().find(|w| w.word == "Cell").unwrap();
+        let energy = curriculum.words.iter().find(|w| w.word == "Energy").unwrap();
+        assert!(cell.synergy_partners.contains(&"Energy".to_string()));
+        assert!(energy.synergy_partners.contains(&"Cell".to_string()));
+        assert!(cell.story_text.contains("cell"));
+        assert_eq!(cell.mood, mood(Channel::Body, Stage::Hero));
+
+        // What is written can be played
+        let json = serde_json::to_string_pretty(curriculum).unwrap();
+        let reloaded = CurriculumDef::from_json(&json).unwrap();
+        assert_eq!(reloaded.words.len(), curriculum.words.len());
+
+        assert!(built.report.contains("# Review: Cells"));
+        assert!(built.report.contains("### Photosynthesis"));
+        assert!(built.report.contains("Not reachable from the start word"));
+    }
+
+    #[test]
+    fn test_text_is_read_from_pdf_content_streams() {
+        let page = b"BT /F1 12 Tf 72 720 Td (Light feeds the \\(green\\) plant.) Tj T* \
+                     [(Roots) -250 (drink) 12 (water.)] TJ ET";
+        let compressed = miniz_oxide::deflate::compress_to_vec_zlib(page, 6);
+        let mut pdf = b"%PDF-1.4\n1 0 obj\n<< /Length 5 /Filter /FlateDecode >>\nstream\n".to_vec();
+        pdf.extend(&compressed);
+        pdf.extend(b"\nendstream\nendobj\n2 0 obj\n<< /Subtype /Image /Length 3 >>\nstream\n(X) Tj\nendstream\nendobj\n%%EOF\n");
+
+        let text = pdf_text(&pdf).unwrap();
+        assert!(text.contains("Light feeds the (green) plant."));
+        assert!(text.contains("Roots drinkwater."));
+        assert!(!text.contains('X'));
+        assert!(pdf_text(b"not a pdf").is_err());
+    }
+
+    #[test]
+    fn test_oversized_streams_are_not_inflated() {
+        let page = b"BT (Light feeds the plant.) Tj ET ".repeat(1_000);
+        let compressed = miniz_oxide::deflate::compress_to_vec_zlib(&page, 6);
+        assert_eq!(inflate(&compressed, page.len()).as_deref(), Some(page.as_slice()));
+        assert!(inflate(&compressed, page.len() - 1).is_none());
+        assert!(compressed.len() * 10 < page.len(), "small on disk, large inflated");
+    }
+}
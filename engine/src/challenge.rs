@@ -0,0 +1,453 @@
+// Daydream Engine — Daily Challenge
+// One session a day that a whole class plays the same way: the date
+// (and an optional class code) fixes a seed, and the seed fixes the
+// deck, the hand size and the genre over the chosen curriculum. The
+// session RNG starts from that seed too, so students who make the same
+// choices see the same draws — and results line up side by side.
+//
+//   daydream-engine --challenge[=2026-10-18] [curriculum.json] [--class=CODE]
+//       [--student=ID] [--challenge-out=PATH]
+//   daydream-engine --export-challenge=PATH [--challenge=DATE] [--class=CODE]
+//
+// What a student brings along doesn't change the deal: the Recycler
+// sits the challenge out (its schedule is personal) and a constructed
+// deck is ignored. SpellBook growth is still saved as usual.
+//
+// At the trail review the result — trail, resonance threads, archetype
+// and a few counts — is written as JSON together with the challenge
+// definition, for the backend to collect.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use crate::archetype::ArchetypeReading;
+use crate::components::*;
+use crate::dag::{CurriculumDef, CurriculumSource};
+use crate::genre::{ActiveGenre, Genre};
+use crate::recycler::ReviewClock;
+use crate::rng::SessionRng;
+use crate::save::TrailRecord;
+
+/// Most words in a challenge deck (the start word always among them).
+pub const DAILY_DECK_SIZE: usize = 12;
+/// The hand size is drawn from this range.
+const MIN_HAND: usize = 3;
+const MAX_HAND: usize = 5;
+
+/// Where results go when `--challenge-out=` is not given.
+const DEFAULT_OUT_DIR: &str = "challenges";
+
+// ─── DATES ──────────────────────────────────────────────────
+
+/// A calendar day (UTC), written 2026-10-18.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ChallengeDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl ChallengeDate {
+    /// Parse `YYYY-MM-DD`, rejecting days the month doesn't have.
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut parts = s.trim().splitn(3, '-');
+        let year: i32 = parts.next()?.parse().ok()?;
+        let month: u32 = parts.next()?.parse().ok()?;
+        let day: u32 = parts.next()?.parse().ok()?;
+        let date = Self { year, month, day };
+        ((1..=12).contains(&month) && day >= 1 && day <= date.days_in_month()).then_some(date)
+    }
+
+    /// The UTC day of a Unix time.
+    pub fn from_unix(secs: u64) -> Self {
+        // Days to civil date (proleptic Gregorian), after H. Hinnant
+        let z = (secs / 86_400) as i64 + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+        Self { year, month, day }
+    }
+
+    /// Today, by the wall clock (UTC).
+    pub fn today() -> Self {
+        Self::from_unix(ReviewClock::Wall.now())
+    }
+
+    fn days_in_month(&self) -> u32 {
+        match self.month {
+            2 if self.year % 4 == 0 && (self.year % 100 != 0 || self.year % 400 == 0) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+}
+
+impl fmt::Display for ChallengeDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// The seed for a day's challenge: a stable hash (FNV-1a) of the date
+/// and class code, mixed once through the session RNG.
+pub fn challenge_seed(date: ChallengeDate, class: &str) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in format!("daydream-daily:{}:{}", date, class).bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    SessionRng::from_seed(hash).next_u64()
+}
+
+// ─── CHALLENGE ──────────────────────────────────────────────
+
+/// Everything that makes one day's challenge — exported as JSON so a
+/// backend can check results against it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DailyChallenge {
+    /// `date[:class]:curriculum` — the same for every student who plays it.
+    pub id: String,
+    pub date: String,
+    #[serde(default)]
+    pub class: String,
+    pub curriculum: String,
+    pub seed: u64,
+    pub genre: String,
+    pub hand_size: usize,
+    /// The deck's words in draw order.
+    pub deck: Vec<String>,
+}
+
+impl DailyChallenge {
+    /// The challenge for a day over a curriculum.
+    pub fn new(date: ChallengeDate, class: &str, curriculum: &CurriculumDef) -> Self {
+        let seed = challenge_seed(date, class);
+        let mut rng = SessionRng::from_seed(seed);
+        let genre = Genre::ALL[rng.below(Genre::ALL.len())];
+        let hand_size = MIN_HAND + rng.below(MAX_HAND - MIN_HAND + 1);
+
+        // The start word opens every story; the rest is the day's pick
+        let mut others: Vec<String> = curriculum.words.iter()
+            .map(|w| w.word.clone())
+            .filter(|w| *w != curriculum.start_word)
+            .collect();
+        rng.shuffle(&mut others);
+        others.truncate(DAILY_DECK_SIZE - 1);
+        let mut deck = vec![curriculum.start_word.clone()];
+        deck.extend(others);
+        rng.shuffle(&mut deck);
+
+        let id = if class.is_empty() {
+            format!("{}:{}", date, curriculum.name)
+        } else {
+            format!("{}:{}:{}", date, class, curriculum.name)
+        };
+        Self {
+            id,
+            date: date.to_string(),
+            class: class.to_string(),
+            curriculum: curriculum.name.clone(),
+            seed,
+            genre: genre.id().to_string(),
+            hand_size,
+            deck,
+        }
+    }
+
+    pub fn genre(&self) -> Genre {
+        Genre::parse(&self.genre).unwrap_or_default()
+    }
+}
+
+/// Resource: the challenge being played, and its result once the
+/// session reaches the trail review.
+#[derive(Resource, Clone, Debug)]
+pub struct ActiveChallenge {
+    pub challenge: DailyChallenge,
+    /// Who played it, as the class knows them (`--student=ID`).
+    pub student: Option<String>,
+    /// Where the result is written; None keeps it in memory only.
+    pub out: Option<PathBuf>,
+    pub result: Option<ChallengeResult>,
+}
+
+impl ActiveChallenge {
+    pub fn new(challenge: DailyChallenge) -> Self {
+        Self { challenge, student: None, out: None, result: None }
+    }
+
+    /// `--challenge` (today) or `--challenge=YYYY-MM-DD` over the
+    /// curriculum being played; None when no challenge was asked for.
+    pub fn from_args(source: &CurriculumSource) -> Option<Self> {
+        let args: Vec<String> = std::env::args().collect();
+        if !args.iter().any(|a| a == "--challenge" || a.starts_with("--challenge=")) {
+            return None;
+        }
+        let challenge = challenge_from_args(&args, source);
+        let value = |flag: &str| args.iter().find_map(|a| a.strip_prefix(flag).map(str::to_string));
+        let out = value("--challenge-out=").map(PathBuf::from)
+            .unwrap_or_else(|| default_out_path(&challenge));
+        Some(Self {
+            student: value("--student="),
+            out: Some(out),
+            result: None,
+            challenge,
+        })
+    }
+}
+
+fn challenge_from_args(args: &[String], source: &CurriculumSource) -> DailyChallenge {
+    let value = |flag: &str| args.iter().find_map(|a| a.strip_prefix(flag));
+    let date = match value("--challenge=") {
+        Some(text) => ChallengeDate::parse(text).unwrap_or_else(|| {
+            warn!("'{}' is not a date (YYYY-MM-DD); playing today's challenge", text);
+            ChallengeDate::today()
+        }),
+        None => ChallengeDate::today(),
+    };
+    DailyChallenge::new(date, value("--class=").unwrap_or_default(), &source.resolve())
+}
+
+fn default_out_path(challenge: &DailyChallenge) -> PathBuf {
+    let name = if challenge.class.is_empty() {
+        format!("{}.json", challenge.date)
+    } else {
+        format!("{}-{}.json", challenge.date, challenge.class)
+    };
+    Path::new(DEFAULT_OUT_DIR).join(name)
+}
+
+/// Play `challenge` in an app that already has the DaydreamPlugin:
+/// its seed, genre and deal replace whatever was set before.
+pub fn add_challenge(app: &mut App, active: ActiveChallenge) {
+    let source = app.world().resource::<CurriculumSource>().clone();
+    app.insert_resource(SessionRng::from_seed(active.challenge.seed))
+        .insert_resource(ActiveGenre::load(active.challenge.genre(), &source))
+        .insert_resource(active)
+        .add_systems(Update, deal_challenge
+            .before(crate::constructed::deal_constructed_deck)
+            .run_if(in_state(GameState::Loading)))
+        .add_systems(OnEnter(GameState::TrailReview), record_challenge_result
+            .after(crate::archetype::reflect_archetype));
+}
+
+/// Run condition: no challenge is being played.
+pub fn no_challenge(challenge: Option<Res<ActiveChallenge>>) -> bool {
+    challenge.is_none()
+}
+
+// ─── SYSTEMS ────────────────────────────────────────────────
+
+/// System: deal the challenge's deck in its order, at its hand size.
+/// Runs while Loading, ahead of every other deal.
+pub fn deal_challenge(
+    challenge: Res<ActiveChallenge>,
+    mut deck: ResMut<Deck>,
+    mut hand: ResMut<Hand>,
+    words: Query<(Entity, &WordCard)>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if !deck.cards.is_empty() || words.is_empty() {
+        return; // Already dealt, or the curriculum has not spawned yet
+    }
+    let by_name: HashMap<&str, Entity> = words.iter().map(|(e, w)| (w.word.as_str(), e)).collect();
+    let challenge = &challenge.challenge;
+    deck.cards = challenge.deck.iter().filter_map(|w| by_name.get(w.as_str()).copied()).collect();
+    if deck.cards.len() < challenge.deck.len() {
+        warn!(
+            "Challenge {}: {} word(s) are missing from this curriculum",
+            challenge.id, challenge.deck.len() - deck.cards.len(),
+        );
+    }
+    if deck.cards.is_empty() {
+        return; // Wrong curriculum entirely; the usual deal takes over
+    }
+    hand.max_size = challenge.hand_size;
+    info!(
+        "Daily challenge {}: {} cards, hand of {}, {} (seed {})",
+        challenge.id, deck.remaining(), challenge.hand_size, challenge.genre, challenge.seed,
+    );
+    next_state.set(GameState::Drawing);
+}
+
+/// How a challenge went, in numbers that compare across students.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+pub struct ChallengeSummary {
+    pub cast: usize,
+    pub skipped: usize,
+    pub deeper: usize,
+    pub moments: usize,
+    pub chapters: usize,
+    pub resonance_threads: usize,
+}
+
+/// One student's result, with the challenge it answers.
+#[derive(Clone, Debug, Serialize)]
+pub struct ChallengeResult {
+    pub challenge: DailyChallenge,
+    pub student: Option<String>,
+    /// Unix seconds.
+    pub completed_at: u64,
+    pub summary: ChallengeSummary,
+    pub trail: TrailRecord,
+    pub resonance_threads: Vec<ResonanceThread>,
+    pub archetype: ArchetypeReading,
+}
+
+/// System: at the trail review, keep the result and write it out.
+pub fn record_challenge_result(
+    mut active: ResMut<ActiveChallenge>,
+    trail: Res<StudentTrail>,
+    threads: Res<ResonanceThreads>,
+    reading: Res<ArchetypeReading>,
+    clock: Res<ReviewClock>,
+) {
+    let record = TrailRecord::from_trail(&trail, &active.challenge.curriculum, true);
+    let count = |choice: SwipeChoice| record.swipe_history.iter().filter(|c| **c == choice).count();
+    let summary = ChallengeSummary {
+        cast: count(SwipeChoice::Yes),
+        skipped: count(SwipeChoice::No),
+        deeper: count(SwipeChoice::Deeper),
+        moments: record.moments.len(),
+        chapters: record.chapters.len(),
+        resonance_threads: threads.threads.len(),
+    };
+    let result = ChallengeResult {
+        challenge: active.challenge.clone(),
+        student: active.student.clone(),
+        completed_at: clock.now(),
+        summary,
+        trail: record,
+        resonance_threads: threads.threads.clone(),
+        archetype: reading.clone(),
+    };
+
+    if let Some(path) = &active.out {
+        match write_json(path, &result) {
+            Ok(()) => info!("Challenge result written to {}", path.display()),
+            Err(e) => warn!("Could not write the challenge result: {}", e),
+        }
+    }
+    active.result = Some(result);
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(value).map_err(|e| e.to_string())?;
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    }
+    std::fs::write(path, json).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// `--export-challenge=PATH`: write the challenge definition without
+/// playing it. Returns the process exit code.
+pub fn run_from_args() -> i32 {
+    let args: Vec<String> = std::env::args().collect();
+    let Some(path) = args.iter().find_map(|a| a.strip_prefix("--export-challenge=")) else {
+        return 1;
+    };
+    let challenge = challenge_from_args(&args, &CurriculumSource::from_args());
+    match write_json(Path::new(path), &challenge) {
+        Ok(()) => {
+            println!("Challenge {} written to {}", challenge.id, path);
+            0
+        }
+        Err(e) => {
+            eprintln!("daydream --export-challenge: {}", e);
+            1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::focus::FocusRules;
+    use crate::journey::JourneyRules;
+    use crate::save::SaveStore;
+    use crate::sim::{self, SimConfig, SimPolicy};
+
+    fn date(s: &str) -> ChallengeDate {
+        ChallengeDate::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_challenge_dates() {
+        assert_eq!(ChallengeDate::from_unix(1_735_689_600).to_string(), "2025-01-01");
+        assert_eq!(ChallengeDate::from_unix(1_709_208_000).to_string(), "2024-02-29");
+        assert_eq!(date("2026-10-18").to_string(), "2026-10-18");
+        assert!(ChallengeDate::parse("2025-02-29").is_none());
+        assert!(ChallengeDate::parse("2026-13-01").is_none());
+        assert!(ChallengeDate::parse("today").is_none());
+    }
+
+    #[test]
+    fn test_a_date_fixes_the_challenge() {
+        let curriculum = CurriculumSource::Demo.resolve();
+        let today = DailyChallenge::new(date("2026-10-18"), "", &curriculum);
+        assert_eq!(today, DailyChallenge::new(date("2026-10-18"), "", &curriculum));
+        assert_eq!(today.id, format!("2026-10-18:{}", curriculum.name));
+        assert!(today.deck.contains(&curriculum.start_word));
+        assert_eq!(today.deck.len(), curriculum.words.len().min(DAILY_DECK_SIZE));
+        assert!((MIN_HAND..=MAX_HAND).contains(&today.hand_size));
+        assert!(Genre::parse(&today.genre).is_some());
+
+        // Other days and other classes get their own seed
+        assert_ne!(today.seed, DailyChallenge::new(date("2026-10-19"), "", &curriculum).seed);
+        assert_ne!(today.seed, DailyChallenge::new(date("2026-10-18"), "7B", &curriculum).seed);
+
+        let json = serde_json::to_string(&today).unwrap();
+        assert_eq!(serde_json::from_str::<DailyChallenge>(&json).unwrap(), today);
+    }
+
+    fn student(seed: u64, challenge: &DailyChallenge) -> SimConfig {
+        SimConfig {
+            curriculum: CurriculumSource::Demo,
+            genre: Genre::Fantasy,
+            seed,
+            policy: SimPolicy::Cast,
+            script: Vec::new(),
+            sessions: 1,
+            store: SaveStore::Disabled,
+            max_focus: FocusRules::default().max_focus,
+            moments_per_chapter: JourneyRules::default().moments_per_chapter,
+            days_between: 1,
+            challenge: Some(challenge.clone()),
+        }
+    }
+
+    #[test]
+    fn test_students_making_the_same_choices_get_the_same_session() {
+        let challenge = DailyChallenge::new(date("2026-10-18"), "", &CurriculumSource::Demo.resolve());
+        // Each student's own seed would deal differently; the challenge's wins
+        let first = sim::run(&student(1, &challenge)).unwrap();
+        let second = sim::run(&student(99, &challenge)).unwrap();
+        assert_eq!(first.genre, challenge.genre);
+        assert_eq!(first.sessions[0].trail.visited_words, second.sessions[0].trail.visited_words);
+        assert_eq!(first.sessions[0].trail.moments, second.sessions[0].trail.moments);
+        assert_eq!(first.sessions[0].trail.cast_words.len(), challenge.deck.len());
+    }
+
+    #[test]
+    fn test_the_result_is_kept_at_the_trail_review() {
+        let challenge = DailyChallenge::new(date("2026-10-18"), "", &CurriculumSource::Demo.resolve());
+        let mut app = sim::build_app(&student(5, &challenge), 0, Vec::new(), None);
+        sim::harness::play_to_review(&mut app, 2_000);
+        assert_eq!(app.world().resource::<Hand>().max_size, challenge.hand_size);
+        let active = app.world().resource::<ActiveChallenge>();
+        let result = active.result.as_ref().expect("result recorded at the trail review");
+        assert_eq!(result.challenge, challenge);
+        assert_eq!(result.summary.cast, challenge.deck.len());
+        assert_eq!(result.summary.resonance_threads, result.resonance_threads.len());
+        let json = serde_json::to_value(result).unwrap();
+        assert_eq!(json["challenge"]["id"], challenge.id.as_str());
+    }
+}
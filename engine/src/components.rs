@@ -7,13 +7,14 @@
 //   learning to cast that spell — changing your relationship with reality.
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 // ─── THE FOUR CHANNELS (Card Element Types) ─────────────────────
 
 /// The Four Channels of Consciousness from The Great Game.
 /// Each word belongs to a Channel, which determines its card color,
 /// the type of engagement it demands, and its synergy relationships.
-#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Channel {
     /// 🟢 The Sage — "What does this mean?"
     /// Analytical, pattern-seeing, metacognitive words.
@@ -30,6 +31,8 @@ pub enum Channel {
 }
 
 impl Channel {
+    pub const ALL: [Channel; 4] = [Channel::Mind, Channel::Heart, Channel::Body, Channel::Action];
+
     /// The signature color for this channel's card frame.
     pub fn color(&self) -> Color {
         match self {
@@ -111,6 +114,38 @@ impl Stage {
             Stage::BestSelf => "Best Self",
         }
     }
+
+    /// Card rarity derived from the stage (see ARCANA: Rarity).
+    pub fn rarity(&self) -> &'static str {
+        match self {
+            Stage::Hero     => "Common",
+            Stage::Outlaw   => "Uncommon",
+            Stage::EdgeLord => "Rare",
+            Stage::BestSelf => "Legendary",
+        }
+    }
+
+    /// Relative draw frequency: common words appear often,
+    /// legendary words very rarely.
+    pub fn draw_weight(&self) -> f32 {
+        match self {
+            Stage::Hero     => 8.0,
+            Stage::Outlaw   => 4.0,
+            Stage::EdgeLord => 2.0,
+            Stage::BestSelf => 1.0,
+        }
+    }
+
+    /// Focus the Hero spends to carry this word into the story.
+    /// Common words are light; abstract words weigh more.
+    pub fn focus_cost(&self) -> u32 {
+        match self {
+            Stage::Hero     => 1,
+            Stage::Outlaw   => 2,
+            Stage::EdgeLord => 3,
+            Stage::BestSelf => 4,
+        }
+    }
 }
 
 // ─── SPELL POWER (Word Mastery Tracking) ────────────────────────
@@ -118,9 +153,10 @@ impl Stage {
 /// How deeply a student has internalized a word-spell.
 /// The Great Recycler IS the progression:
 ///   Junk (unknown) → Process (experience) → Gear (owned spell)
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum MasteryLevel {
     /// Saw the card — the word is known to exist.
+    #[default]
     Encountered,
     /// Engaged with the story — felt the word in context.
     Experienced,
@@ -131,6 +167,20 @@ pub enum MasteryLevel {
 }
 
 impl MasteryLevel {
+    pub const ALL: [MasteryLevel; 4] = [
+        MasteryLevel::Encountered, MasteryLevel::Experienced, MasteryLevel::Owned, MasteryLevel::Mastered,
+    ];
+
+    /// Display name.
+    pub fn label(&self) -> &'static str {
+        match self {
+            MasteryLevel::Encountered => "Encountered",
+            MasteryLevel::Experienced => "Experienced",
+            MasteryLevel::Owned       => "Owned",
+            MasteryLevel::Mastered    => "Mastered",
+        }
+    }
+
     /// Emoji indicator for UI.
     pub fn icon(&self) -> &'static str {
         match self {
@@ -143,16 +193,23 @@ impl MasteryLevel {
 }
 
 /// Tracks the student's mastery of a specific word entity.
-#[derive(Component, Clone, Debug)]
+#[derive(Component, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SpellPower {
     /// Current mastery level.
     pub mastery: MasteryLevel,
     /// How many times the student has seen this word.
     pub times_encountered: u32,
+    /// How many times the student cast this word into a story.
+    pub times_cast: u32,
+    /// How many times an Ally card stood beside this word in a story.
+    pub ally_boosts: u32,
     /// How many times the student swiped "Deeper" on this word.
     pub times_explored_deeper: u32,
     /// How many synergy partners of this word have been visited.
     pub synergies_discovered: u32,
+    /// Which partners those were (so each counts once).
+    pub discovered_partners: Vec<String>,
 }
 
 impl Default for SpellPower {
@@ -160,8 +217,11 @@ impl Default for SpellPower {
         Self {
             mastery: MasteryLevel::Encountered,
             times_encountered: 0,
+            times_cast: 0,
+            ally_boosts: 0,
             times_explored_deeper: 0,
             synergies_discovered: 0,
+            discovered_partners: Vec::new(),
         }
     }
 }
@@ -170,7 +230,7 @@ impl Default for SpellPower {
 
 /// The type of synergy between two words, inspired by the Five Phases
 /// (Wǔ Xíng) Generation Cycle from The Great Game.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
 pub enum SynergyType {
     /// Wood feeds Fire: understanding deepens feeling.
     /// Mind words nourish Heart words.
@@ -186,9 +246,56 @@ pub enum SynergyType {
     Resonant,
 }
 
+impl SynergyType {
+    /// Infer the synergy between two words from their Channels.
+    /// The channels form a cycle — Mind → Heart → Action → Body → Mind:
+    /// same channel resonates, neighbours feed each other, and
+    /// opposite poles (Mind/Action, Heart/Body) complement.
+    pub fn between(a: Channel, b: Channel) -> SynergyType {
+        use Channel::*;
+        match (a, b) {
+            _ if a == b => SynergyType::Resonant,
+            (Mind, Heart) | (Heart, Mind)
+            | (Body, Mind) | (Mind, Body) => SynergyType::Nourishing,
+            (Heart, Action) | (Action, Heart)
+            | (Action, Body) | (Body, Action) => SynergyType::Generating,
+            _ => SynergyType::Complementary,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SynergyType::Nourishing    => "Nourishing",
+            SynergyType::Generating    => "Generating",
+            SynergyType::Complementary => "Complementary",
+            SynergyType::Resonant      => "Resonant",
+        }
+    }
+
+    /// SpellPower preview shown when both cards could be played together.
+    pub fn bonus(&self) -> i32 {
+        match self {
+            SynergyType::Resonant      => 1,
+            SynergyType::Nourishing    => 2,
+            SynergyType::Generating    => 2,
+            SynergyType::Complementary => 3,
+        }
+    }
+
+    /// How the pairing reads in the story.
+    pub fn flavor(&self, a: &str, b: &str) -> String {
+        match self {
+            SynergyType::Nourishing    => format!("{} deepens {}", a, b),
+            SynergyType::Generating    => format!("{} fuels {}", a, b),
+            SynergyType::Complementary => format!("{} balances {}", a, b),
+            SynergyType::Resonant      => format!("{} + {} resonate together", a, b),
+        }
+    }
+}
+
 /// A synergy link from this word to another.
 /// When both are mastered, a synergy bonus activates.
-#[derive(Component, Clone, Debug)]
+#[derive(Component, Clone, Debug, Default)]
 pub struct SynergyLinks {
     pub links: Vec<SynergyEntry>,
 }
@@ -204,27 +311,29 @@ pub struct SynergyEntry {
     pub bonus: String,
 }
 
-impl Default for SynergyLinks {
-    fn default() -> Self {
-        Self { links: Vec::new() }
-    }
-}
-
 // ─── WORD CARD (the TCG card entity) ─────────────────────────────
 
 /// The core vocabulary entity. Every word in the curriculum
 /// becomes one of these. VAAM: Vocabulary Acquisition Autonomous Meaning.
 /// A word isn't defined by other words — it's defined by experience.
 /// Letters SPELL words. Words are spells. This card IS a spell.
-#[derive(Component, Clone, Debug)]
+#[derive(Component, Clone, Debug, Default)]
 pub struct WordCard {
-    /// The vocabulary word itself: "Resilience", "Bias", "Patience"
+    /// The vocabulary word itself: "Resilience", "Bias", "Patience".
+    /// Also its id — edges, saves and the SpellBook refer to it by this.
     pub word: String,
+    /// The word as the student reads it, in their language.
+    pub name: String,
     /// Socratic question for the "dig deeper" swipe-down action.
     /// This is experiential, not a dictionary definition.
     pub depth_prompt: String,
     /// Thematic tags for DAG relationships: ["courage", "growth"]
     pub themes: Vec<String>,
+    /// The curriculum's own story beat — a genre may tell it differently.
+    pub story_text: String,
+    /// The story beat is a translation, so it outranks the genre's
+    /// untranslated telling (see genre.rs).
+    pub localized_story: bool,
 }
 
 /// DAG edges: which words this word connects to.
@@ -235,6 +344,8 @@ pub struct WordEdges {
     pub yes_targets: Vec<String>,
     /// Words reachable via "no" (swipe left) — may loop to gentler concepts
     pub no_targets: Vec<String>,
+    /// Special branches that only open while the Hero's Focus is low
+    pub low_focus_targets: Vec<String>,
 }
 
 /// Visual properties for the card frame.
@@ -261,11 +372,16 @@ impl Default for CardStyle {
 /// The emotional/visual context layer. Changes based on the
 /// current word's mood + Channel background color.
 /// BOTTOM of the Triple Sandwich = the Body's experience of the word.
+/// Skinned by the active genre (see genre.rs).
 #[derive(Component, Clone, Debug)]
 pub struct Setting {
     pub mood: String,
     pub genre: String,
     pub background_color: Color,
+    /// What happens in the story when this word is cast.
+    pub story_text: String,
+    /// Backdrop art for this word in the genre, if any.
+    pub setting_image: Option<String>,
 }
 
 impl Default for Setting {
@@ -274,6 +390,8 @@ impl Default for Setting {
             mood: "calm".to_string(),
             genre: "fantasy".to_string(),
             background_color: Color::srgba(0.04, 0.04, 0.06, 1.0),
+            story_text: String::new(),
+            setting_image: None,
         }
     }
 }
@@ -283,7 +401,8 @@ impl Default for Setting {
 /// The student's living Character Sheet from The Great Game.
 /// Tracks channel attunement, emergent class, and active buffs/debuffs.
 /// This is the "AI Mirror" — reflecting the student's patterns.
-#[derive(Resource, Debug)]
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct CharacterSheet {
     /// Channel attunement scores (0.0 = disconnected, 1.0 = integrated)
     pub mind_attunement: f32,
@@ -291,8 +410,8 @@ pub struct CharacterSheet {
     pub body_attunement: f32,
     pub action_attunement: f32,
 
-    /// Emergent class title — derived from dominant channel pattern.
-    /// e.g., "The Oracle" (Mind-heavy), "The Bard" (Heart-heavy)
+    /// Emergent class title — the archetype read from how the student
+    /// plays (see archetype.rs), e.g. "The Oracle", "The Bard".
     pub emergent_class: String,
 
     /// Total words encountered across all sessions.
@@ -316,6 +435,16 @@ impl Default for CharacterSheet {
 }
 
 impl CharacterSheet {
+    /// Attunement to one channel.
+    pub fn attunement(&self, channel: Channel) -> f32 {
+        match channel {
+            Channel::Mind   => self.mind_attunement,
+            Channel::Heart  => self.heart_attunement,
+            Channel::Body   => self.body_attunement,
+            Channel::Action => self.action_attunement,
+        }
+    }
+
     /// Update attunement based on engaging with a word of this Channel.
     /// Attunement rises slowly (0.05 per engagement), capped at 1.0.
     pub fn engage_channel(&mut self, channel: &Channel) {
@@ -326,80 +455,75 @@ impl CharacterSheet {
             Channel::Body   => self.body_attunement = (self.body_attunement + bump).min(1.0),
             Channel::Action => self.action_attunement = (self.action_attunement + bump).min(1.0),
         }
-        self.update_class();
-    }
-
-    /// Derive the emergent class from the dominant channel pattern.
-    fn update_class(&mut self) {
-        let scores = [
-            (self.mind_attunement,   "Mind"),
-            (self.heart_attunement,  "Heart"),
-            (self.body_attunement,   "Body"),
-            (self.action_attunement, "Action"),
-        ];
-
-        let dominant = scores.iter()
-            .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal))
-            .map(|s| s.1)
-            .unwrap_or("Mind");
-
-        self.emergent_class = match dominant {
-            "Mind"   => "The Oracle".to_string(),
-            "Heart"  => "The Bard".to_string(),
-            "Body"   => "The Cultivator".to_string(),
-            "Action" => "The Templar".to_string(),
-            _        => "The Architect".to_string(),
-        };
-    }
-
-    /// Render a text-based channel attunement bar for trail review.
-    pub fn attunement_display(&self) -> String {
-        fn bar(val: f32) -> String {
-            let filled = (val * 8.0).round() as usize;
-            let empty = 8 - filled;
-            format!("{}{}", "█".repeat(filled), "░".repeat(empty))
-        }
-        format!(
-            "Mind:   {} {:.0}%\nHeart:  {} {:.0}%\nBody:   {} {:.0}%\nAction: {} {:.0}%",
-            bar(self.mind_attunement), self.mind_attunement * 100.0,
-            bar(self.heart_attunement), self.heart_attunement * 100.0,
-            bar(self.body_attunement), self.body_attunement * 100.0,
-            bar(self.action_attunement), self.action_attunement * 100.0,
-        )
     }
 }
 
 // ─── SPELL BOOK (Word Collection) ────────────────────────────────
 
 /// A record of a single word in the student's SpellBook.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SpellBookEntry {
     pub word: String,
     pub channel: Channel,
+    #[serde(default)]
     pub mastery: MasteryLevel,
+    #[serde(default)]
     pub times_encountered: u32,
+    #[serde(default)]
+    pub times_cast: u32,
+    #[serde(default)]
+    pub times_explored_deeper: u32,
+    #[serde(default)]
+    pub synergies_discovered: u32,
+    /// The student's answers to the word's depth question, oldest first.
+    #[serde(default)]
+    pub journal: Vec<JournalEntry>,
+}
+
+/// A typed answer to a word's depth question.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub text: String,
+    /// Unix seconds.
+    pub written_at: u64,
+    /// How well the student knew the word when they wrote it.
+    pub mastery: MasteryLevel,
 }
 
 /// The student's SpellBook — their collection of word-spells.
 /// In TCG terms: your card collection. Words you OWN, experientially.
-#[derive(Resource, Debug, Default)]
+#[derive(Resource, Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SpellBook {
     pub entries: Vec<SpellBookEntry>,
 }
 
 impl SpellBook {
-    /// Add or update a word in the SpellBook.
-    pub fn record_encounter(&mut self, word: &str, channel: Channel) {
-        if let Some(entry) = self.entries.iter_mut().find(|e| e.word == word) {
-            entry.times_encountered += 1;
-        } else {
-            self.entries.push(SpellBookEntry {
-                word: word.to_string(),
-                channel,
-                mastery: MasteryLevel::Encountered,
-                times_encountered: 1,
-            });
-        }
+    /// Add or update a word in the SpellBook from its SpellPower,
+    /// so the book always mirrors the word entity.
+    pub fn sync_power(&mut self, word: &str, channel: Channel, power: &SpellPower) {
+        let idx = match self.entries.iter().position(|e| e.word == word) {
+            Some(idx) => idx,
+            None => {
+                self.entries.push(SpellBookEntry {
+                    word: word.to_string(),
+                    channel,
+                    mastery: MasteryLevel::Encountered,
+                    times_encountered: 0,
+                    times_cast: 0,
+                    times_explored_deeper: 0,
+                    synergies_discovered: 0,
+                    journal: Vec::new(),
+                });
+                self.entries.len() - 1
+            }
+        };
+        let entry = &mut self.entries[idx];
+        entry.times_encountered = power.times_encountered;
+        entry.times_cast = power.times_cast;
+        entry.times_explored_deeper = power.times_explored_deeper;
+        entry.synergies_discovered = power.synergies_discovered;
+        self.upgrade_mastery(word, power.mastery);
     }
 
     /// Upgrade mastery for a word (if it exists).
@@ -411,17 +535,16 @@ impl SpellBook {
         }
     }
 
-    /// Count words at each mastery level.
-    pub fn summary(&self) -> String {
-        let encountered = self.entries.iter().filter(|e| e.mastery == MasteryLevel::Encountered).count();
-        let experienced = self.entries.iter().filter(|e| e.mastery == MasteryLevel::Experienced).count();
-        let owned = self.entries.iter().filter(|e| e.mastery == MasteryLevel::Owned).count();
-        let mastered = self.entries.iter().filter(|e| e.mastery == MasteryLevel::Mastered).count();
+    /// Keep an answer to a word's depth question (if the word exists).
+    pub fn write_journal(&mut self, word: &str, entry: JournalEntry) {
+        if let Some(book_entry) = self.entries.iter_mut().find(|e| e.word == word) {
+            book_entry.journal.push(entry);
+        }
+    }
 
-        format!(
-            "SpellBook: {} words\n🔮 Encountered: {}\n⚡ Experienced: {}\n🌟 Owned: {}\n👑 Mastered: {}",
-            self.entries.len(), encountered, experienced, owned, mastered
-        )
+    /// Everything the student has written about a word, oldest first.
+    pub fn journal(&self, word: &str) -> &[JournalEntry] {
+        self.entries.iter().find(|e| e.word == word).map_or(&[], |e| &e.journal)
     }
 }
 
@@ -435,12 +558,39 @@ pub struct StudentTrail {
     pub visited_words: Vec<String>,
     /// How the student responded at each step
     pub swipe_history: Vec<SwipeChoice>,
+    /// Words cast into the story (swipe right), in order
+    pub cast_words: Vec<String>,
+    /// Chapters of the journey so far; the last one is being written
+    pub chapters: Vec<Chapter>,
+    /// Words that reached a new mastery level this session, in order
+    pub mastery_gains: Vec<(String, MasteryLevel)>,
     /// The currently active word entity
     pub current_word: Option<Entity>,
 }
 
+impl StudentTrail {
+    /// Every story moment completed on the crafting table, in order.
+    pub fn moments(&self) -> impl Iterator<Item = &StoryMoment> {
+        self.chapters.iter().flat_map(|c| c.moments.iter())
+    }
+
+    /// The chapter being written, if the story has begun.
+    pub fn chapter(&self) -> Option<&Chapter> {
+        self.chapters.last()
+    }
+
+    /// The chapter being written, opening the first one if needed.
+    pub fn chapter_mut(&mut self) -> &mut Chapter {
+        if self.chapters.is_empty() {
+            self.chapters.push(Chapter::new(JourneyStage::OrdinaryWorld));
+        }
+        let last = self.chapters.len() - 1;
+        &mut self.chapters[last]
+    }
+}
+
 /// How a student responded to a word
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum SwipeChoice {
     /// Swipe right: "Yes, I accept this / continue"
     Yes,
@@ -504,20 +654,466 @@ impl Symbol {
     }
 }
 
+// ─── CARD TYPES ─────────────────────────────────────────────
+
+/// What a card does when cast, besides teaching its word.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CardType {
+    /// Core vocabulary — the word to learn
+    #[default]
+    Spell,
+    /// Changes the atmosphere: its mood and backdrop carry over
+    /// to the moments that follow
+    Setting,
+    /// A synergy partner: the words it resonates with grow faster
+    Ally,
+}
+
+impl CardType {
+    pub const ALL: [CardType; 3] = [CardType::Spell, CardType::Setting, CardType::Ally];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CardType::Spell   => "Spell",
+            CardType::Setting => "Setting",
+            CardType::Ally    => "Ally",
+        }
+    }
+}
+
+// ─── STORY MOMENT (the Crafting Table) ─────────────────────
+
+/// The three grammar slots of a story moment:
+///   "In the [SETTING], a [SUBJECT] begins to [ACTION]."
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StorySlot {
+    /// ◆ noun — where the moment happens
+    Setting,
+    /// ☆ key term — who or what the moment is about
+    Subject,
+    /// ◇ verb — what happens
+    Action,
+}
+
+impl StorySlot {
+    /// Slots in the order they are filled.
+    pub const ALL: [StorySlot; 3] = [StorySlot::Setting, StorySlot::Subject, StorySlot::Action];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            StorySlot::Setting => "Setting",
+            StorySlot::Subject => "Subject",
+            StorySlot::Action  => "Action",
+        }
+    }
+
+    /// The symbol this slot is typed for.
+    pub fn symbol(&self) -> Symbol {
+        match self {
+            StorySlot::Setting => Symbol::Stone,
+            StorySlot::Subject => Symbol::Star,
+            StorySlot::Action  => Symbol::Spark,
+        }
+    }
+
+    /// Whether a card of this symbol naturally fits the slot.
+    /// ○ Void cards are wild and fit anywhere.
+    pub fn accepts(&self, symbol: Symbol) -> bool {
+        symbol == Symbol::Void || symbol == self.symbol()
+    }
+}
+
+/// A word card sitting on the crafting table.
+#[derive(Clone, Debug)]
+pub struct SlotCard {
+    pub entity: Entity,
+    pub word: String,
+    pub symbol: Symbol,
+    /// False when the card was placed in a slot of another type —
+    /// always allowed, but the words don't quite fit.
+    pub fits: bool,
+}
+
+/// A △ Prism card attached to a placed card instead of taking a slot.
+#[derive(Clone, Debug)]
+pub struct SlotModifier {
+    pub card: SlotCard,
+    pub target: StorySlot,
+}
+
+/// Where a card ended up when placed on the crafting table.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Placement {
+    /// Took a slot (`fits` = the symbol matched the slot type).
+    Slot { slot: StorySlot, fits: bool },
+    /// Attached as a modifier to the card in this slot.
+    Modifier { target: StorySlot },
+}
+
+/// Resource: the story moment currently being built.
+/// Each moment has Setting/Subject/Action slots; the story text
+/// is assembled from whatever has been placed in them.
+#[derive(Resource, Default, Debug, Clone)]
+pub struct StoryMoment {
+    pub setting: Option<SlotCard>,
+    pub subject: Option<SlotCard>,
+    pub action: Option<SlotCard>,
+    /// △ Prism attachments
+    pub modifiers: Vec<SlotModifier>,
+    /// Story text assembled from the slots
+    pub generated_text: String,
+    /// The slot that received the most recent card
+    pub last_placed: Option<StorySlot>,
+    /// Word pairs that resonated in this moment
+    pub resonances: Vec<(String, String, SynergyType)>,
+    /// How the Hero was holding up when the last card landed
+    pub focus: FocusTier,
+}
+
+impl StoryMoment {
+    pub fn slot(&self, slot: StorySlot) -> Option<&SlotCard> {
+        match slot {
+            StorySlot::Setting => self.setting.as_ref(),
+            StorySlot::Subject => self.subject.as_ref(),
+            StorySlot::Action  => self.action.as_ref(),
+        }
+    }
+
+    fn slot_mut(&mut self, slot: StorySlot) -> &mut Option<SlotCard> {
+        match slot {
+            StorySlot::Setting => &mut self.setting,
+            StorySlot::Subject => &mut self.subject,
+            StorySlot::Action  => &mut self.action,
+        }
+    }
+
+    /// All three slots are filled.
+    pub fn is_complete(&self) -> bool {
+        StorySlot::ALL.iter().all(|s| self.slot(*s).is_some())
+    }
+
+    /// Nothing has been placed yet.
+    pub fn is_empty(&self) -> bool {
+        StorySlot::ALL.iter().all(|s| self.slot(*s).is_none())
+    }
+
+    /// Where a card with this symbol would go if placed now.
+    /// Prisms attach to the most recently placed card; everything else
+    /// takes the first empty slot that accepts it, or failing that the
+    /// first empty slot (placing is always allowed).
+    pub fn placement_for(&self, symbol: Symbol) -> Option<Placement> {
+        if symbol == Symbol::Prism {
+            if let Some(target) = self.last_placed {
+                return Some(Placement::Modifier { target });
+            }
+        }
+        let empty: Vec<StorySlot> = StorySlot::ALL.iter().copied()
+            .filter(|s| self.slot(*s).is_none())
+            .collect();
+        if let Some(slot) = empty.iter().find(|s| s.accepts(symbol)) {
+            return Some(Placement::Slot { slot: *slot, fits: true });
+        }
+        empty.first().map(|slot| Placement::Slot { slot: *slot, fits: false })
+    }
+
+    /// Place a card on the table and reassemble the story text.
+    pub fn place(&mut self, entity: Entity, word: &str, symbol: Symbol) -> Option<Placement> {
+        let placement = self.placement_for(symbol)?;
+        match placement {
+            Placement::Slot { slot, fits } => {
+                *self.slot_mut(slot) = Some(SlotCard {
+                    entity, word: word.to_string(), symbol, fits,
+                });
+                self.last_placed = Some(slot);
+            }
+            Placement::Modifier { target } => {
+                self.modifiers.push(SlotModifier {
+                    card: SlotCard { entity, word: word.to_string(), symbol, fits: true },
+                    target,
+                });
+            }
+        }
+        self.generated_text = self.assemble_text();
+        Some(placement)
+    }
+
+    /// Every card on the table (slots + modifiers).
+    pub fn placed_cards(&self) -> Vec<&SlotCard> {
+        StorySlot::ALL.iter()
+            .filter_map(|s| self.slot(*s))
+            .chain(self.modifiers.iter().map(|m| &m.card))
+            .collect()
+    }
+
+    /// Whether this word is part of a resonance in the moment.
+    pub fn is_resonating(&self, word: &str) -> bool {
+        self.resonances.iter().any(|(a, b, _)| a == word || b == word)
+    }
+
+    /// Every card entity on the table (slots + modifiers).
+    pub fn placed_entities(&self) -> Vec<Entity> {
+        self.placed_cards().iter().map(|c| c.entity).collect()
+    }
+
+    /// Some card sits in a slot it doesn't naturally fit.
+    pub fn is_dissonant(&self) -> bool {
+        StorySlot::ALL.iter().any(|s| self.slot(*s).is_some_and(|c| !c.fits))
+    }
+
+    /// The slot's words as shown in the story: "[RESILIENCE PRESENCE]" or "_____".
+    pub fn slot_phrase(&self, slot: StorySlot) -> String {
+        self.slot_phrase_in(slot, |word| word.to_string())
+    }
+
+    /// The slot's phrase, with each word as `name` gives it.
+    pub fn slot_phrase_in(&self, slot: StorySlot, name: impl Fn(&str) -> String) -> String {
+        match self.slot(slot) {
+            Some(card) => {
+                let mut words: Vec<String> = self.modifiers.iter()
+                    .filter(|m| m.target == slot)
+                    .map(|m| name(&m.card.word).to_uppercase())
+                    .collect();
+                words.push(name(&card.word).to_uppercase());
+                format!("[{}]", words.join(" "))
+            }
+            None => "_____".to_string(),
+        }
+    }
+
+    /// Assemble the moment's story text from what is in the slots.
+    pub fn assemble_text(&self) -> String {
+        let mut text = format!(
+            "In the {}, a {} begins to {}.",
+            self.slot_phrase(StorySlot::Setting),
+            self.slot_phrase(StorySlot::Subject),
+            self.slot_phrase(StorySlot::Action),
+        );
+        if self.is_dissonant() {
+            text.push_str("\nThe words don't quite fit — something is off.");
+        }
+        if !self.resonances.is_empty() {
+            text.push_str("\nThese words echo each other...");
+        }
+        if let Some(line) = self.focus.story_line() {
+            text.push('\n');
+            text.push_str(line);
+        }
+        text
+    }
+}
+
+// ─── HERO'S JOURNEY (Chapters & Branch Points) ──────────────
+
+/// The stages of the Hero's Journey. A session is told as one
+/// chapter per stage; the last chapter runs until the deck is spent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum JourneyStage {
+    #[default]
+    OrdinaryWorld,
+    CallToAdventure,
+    CrossingThreshold,
+    TestsAndAllies,
+    TheOrdeal,
+    Transformation,
+    TheReturn,
+}
+
+impl JourneyStage {
+    pub const ALL: [JourneyStage; 7] = [
+        JourneyStage::OrdinaryWorld, JourneyStage::CallToAdventure,
+        JourneyStage::CrossingThreshold, JourneyStage::TestsAndAllies,
+        JourneyStage::TheOrdeal, JourneyStage::Transformation, JourneyStage::TheReturn,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            JourneyStage::OrdinaryWorld     => "The Ordinary World",
+            JourneyStage::CallToAdventure   => "The Call to Adventure",
+            JourneyStage::CrossingThreshold => "Crossing the Threshold",
+            JourneyStage::TestsAndAllies    => "Tests and Allies",
+            JourneyStage::TheOrdeal         => "The Ordeal",
+            JourneyStage::Transformation    => "The Transformation",
+            JourneyStage::TheReturn         => "The Return",
+        }
+    }
+
+    /// Chapter number as a roman numeral: "I" … "VII".
+    pub fn numeral(&self) -> &'static str {
+        const NUMERALS: [&str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+        NUMERALS[*self as usize]
+    }
+
+    /// The stage after this one; None after The Return.
+    pub fn next(&self) -> Option<JourneyStage> {
+        JourneyStage::ALL.get(*self as usize + 1).copied()
+    }
+}
+
+/// Which DAG edge a branch grew from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BranchEdge {
+    /// A yes-target of a word just cast: press on
+    Onward,
+    /// A no-target: turn aside
+    Aside,
+    /// A low-Focus special branch, only offered to a strained Hero
+    Special,
+    /// Any undrawn word, when the edges run out
+    Wander,
+}
+
+impl BranchEdge {
+    /// The choice as the student reads it.
+    pub fn prompt(&self, word: &str) -> String {
+        match self {
+            BranchEdge::Onward  => format!("Press on toward {}.", word),
+            BranchEdge::Aside   => format!("Turn aside to {}.", word),
+            BranchEdge::Special => format!("Risk it all on {}.", word),
+            BranchEdge::Wander  => format!("Wander off toward {}.", word),
+        }
+    }
+}
+
+/// One way forward from a branch point: an undrawn word the next
+/// chapter opens with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Branch {
+    pub entity: Entity,
+    pub word: String,
+    pub edge: BranchEdge,
+}
+
+impl Branch {
+    pub fn prompt(&self) -> String {
+        self.edge.prompt(&self.word)
+    }
+}
+
+/// A chapter of the session: the moments built in it, and the branch
+/// the student took at its end.
+#[derive(Clone, Debug)]
+pub struct Chapter {
+    pub stage: JourneyStage,
+    pub moments: Vec<StoryMoment>,
+    pub branch: Option<Branch>,
+}
+
+impl Chapter {
+    pub fn new(stage: JourneyStage) -> Self {
+        Self { stage, moments: Vec::new(), branch: None }
+    }
+
+    /// "II · The Call to Adventure"
+    pub fn heading(&self) -> String {
+        format!("{} · {}", self.stage.numeral(), self.stage.title())
+    }
+}
+
+/// Resource: the branch point the student is facing.
+/// Options are ordered right, left, down — the swipe that picks them.
+#[derive(Resource, Default, Debug)]
+pub struct BranchPoint {
+    pub options: Vec<Branch>,
+}
+
+impl BranchPoint {
+    pub const SWIPES: [SwipeChoice; 3] = [SwipeChoice::Yes, SwipeChoice::No, SwipeChoice::Deeper];
+
+    /// Index of the option a swipe picks, if there is one.
+    pub fn index_for(&self, choice: SwipeChoice) -> Option<usize> {
+        BranchPoint::SWIPES.iter().position(|s| *s == choice).filter(|i| *i < self.options.len())
+    }
+}
+
+// ─── CHARACTER STATE (Hero Focus) ───────────────────────────
+
+/// The Hero's strength inside the story — cognitive energy,
+/// willpower, stamina. Governs drama, not player choice: the student
+/// may always play any card, but the Hero feels the cost.
+#[derive(Resource, Debug, Clone)]
+pub struct CharacterState {
+    pub focus: u32,
+    pub max_focus: u32,
+    /// Focus ran out; the Hero must rest before the story moves on.
+    pub exhausted: bool,
+}
+
+impl Default for CharacterState {
+    fn default() -> Self {
+        Self::new(10)
+    }
+}
+
+impl CharacterState {
+    pub fn new(max_focus: u32) -> Self {
+        Self { focus: max_focus, max_focus, exhausted: false }
+    }
+
+    /// Spend Focus on a cast. Reaching zero exhausts the Hero.
+    pub fn spend(&mut self, cost: u32) {
+        self.focus = self.focus.saturating_sub(cost);
+        if self.focus == 0 {
+            self.exhausted = true;
+        }
+    }
+
+    /// A rest point restores the Hero completely.
+    pub fn rest(&mut self) {
+        self.focus = self.max_focus;
+        self.exhausted = false;
+    }
+
+    pub fn tier(&self) -> FocusTier {
+        if self.exhausted || self.focus == 0 {
+            FocusTier::Exhausted
+        } else if self.focus * 3 <= self.max_focus {
+            FocusTier::Strained
+        } else {
+            FocusTier::Steady
+        }
+    }
+
+    /// Low Focus opens the special branches.
+    pub fn is_low(&self) -> bool {
+        self.tier() != FocusTier::Steady
+    }
+}
+
+/// How the Hero is holding up.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FocusTier {
+    #[default]
+    Steady,
+    /// A third of Focus or less — the story shows the strain.
+    Strained,
+    /// No Focus left — the branch locks until the Hero rests.
+    Exhausted,
+}
+
+impl FocusTier {
+    /// The line the story gains at this tier.
+    pub fn story_line(&self) -> Option<&'static str> {
+        match self {
+            FocusTier::Steady    => None,
+            FocusTier::Strained  => Some("The hero's breath comes heavier. Every word costs more now."),
+            FocusTier::Exhausted => Some("The hero's steps falter. There is nothing left to give — not without rest."),
+        }
+    }
+}
+
 // ─── DECK / HAND / DISCARD (TCG Resources) ──────────────────
 
 /// The student's deck — all unplayed word cards for this session.
 /// Cards are drawn from the deck into the hand.
-#[derive(Resource, Debug)]
+#[derive(Resource, Debug, Default)]
 pub struct Deck {
     /// Word entities in draw order (top of deck = last element)
     pub cards: Vec<Entity>,
-}
-
-impl Default for Deck {
-    fn default() -> Self {
-        Self { cards: Vec::new() }
-    }
+    /// A card promised to the next draw (a branch the student chose)
+    pub promised: Option<Entity>,
+    /// Every card may be drawn, whatever the DAG has unlocked
+    /// (a constructed deck of words earned in earlier sessions)
+    pub open: bool,
 }
 
 impl Deck {
@@ -530,9 +1126,25 @@ impl Deck {
         self.cards.pop()
     }
 
+    /// Draw a specific card by position (removes from deck)
+    pub fn take(&mut self, index: usize) -> Option<Entity> {
+        if index < self.cards.len() {
+            Some(self.cards.remove(index))
+        } else {
+            None
+        }
+    }
+
     pub fn is_empty(&self) -> bool {
         self.cards.is_empty()
     }
+
+    /// Clear the promise; the promised card's position, if it is
+    /// still in the deck.
+    pub fn take_promise(&mut self) -> Option<usize> {
+        let promised = self.promised.take()?;
+        self.cards.iter().position(|e| *e == promised)
+    }
 }
 
 /// The student's hand — cards available to play right now.
@@ -609,6 +1221,7 @@ pub struct Selected;
 pub struct ActiveSynergy {
     pub source_word: String,
     pub target_word: String,
+    pub synergy_type: SynergyType,
     pub bonus: i32,
     pub flavor_text: String,
 }
@@ -619,8 +1232,57 @@ pub struct ActiveSynergies {
     pub synergies: Vec<ActiveSynergy>,
 }
 
+// ─── RESONANCE THREADS ──────────────────────────────────────
+
+/// Two words that resonated in the student's stories.
+/// Not a score — a record of what the student connected.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResonanceThread {
+    pub word_a: String,
+    pub word_b: String,
+    pub synergy_type: SynergyType,
+    pub times_resonated: u32,
+    /// The stories where they appeared together
+    pub story_moments: Vec<String>,
+}
+
+/// Resource: every resonance thread woven this session.
+#[derive(Resource, Default, Debug)]
+pub struct ResonanceThreads {
+    pub threads: Vec<ResonanceThread>,
+}
+
+impl ResonanceThreads {
+    /// Record that two words resonated in a story moment.
+    /// The pair is unordered: Patience+Resilience == Resilience+Patience.
+    pub fn record(&mut self, a: &str, b: &str, synergy_type: SynergyType, story: &str) {
+        let (word_a, word_b) = if a <= b { (a, b) } else { (b, a) };
+        if let Some(thread) = self.threads.iter_mut()
+            .find(|t| t.word_a == word_a && t.word_b == word_b)
+        {
+            thread.times_resonated += 1;
+            thread.story_moments.push(story.to_string());
+        } else {
+            self.threads.push(ResonanceThread {
+                word_a: word_a.to_string(),
+                word_b: word_b.to_string(),
+                synergy_type,
+                times_resonated: 1,
+                story_moments: vec![story.to_string()],
+            });
+        }
+    }
+
+    /// Threads ordered from most to least woven.
+    pub fn strongest(&self) -> Vec<&ResonanceThread> {
+        let mut sorted: Vec<&ResonanceThread> = self.threads.iter().collect();
+        sorted.sort_by_key(|t| std::cmp::Reverse(t.times_resonated));
+        sorted
+    }
+}
+
 /// Application states
-#[derive(States, Default, Clone, Eq, PartialEq, Debug, Hash)]
+#[derive(States, Default, Clone, Eq, PartialEq, Debug, Hash, Serialize, Deserialize)]
 pub enum GameState {
     #[default]
     Loading,
@@ -632,6 +1294,64 @@ pub enum GameState {
     CardSelected,
     /// Depth view overlay (dig deeper on selected card)
     DepthView,
+    /// The Hero ran out of Focus — a rest point before drawing again
+    Resting,
+    /// A chapter ended — choosing the branch the next one follows
+    BranchPoint,
     /// Session complete, reviewing the trail
     TrailReview,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(n: u32) -> Entity {
+        Entity::from_raw_u32(n).unwrap()
+    }
+
+    #[test]
+    fn test_cards_take_their_typed_slot() {
+        let mut moment = StoryMoment::default();
+        assert_eq!(
+            moment.place(card(1), "Bias", Symbol::Star),
+            Some(Placement::Slot { slot: StorySlot::Subject, fits: true }),
+        );
+        assert_eq!(
+            moment.place(card(2), "Patience", Symbol::Stone),
+            Some(Placement::Slot { slot: StorySlot::Setting, fits: true }),
+        );
+        // Void is wild: fills the remaining Action slot
+        assert_eq!(
+            moment.place(card(3), "Clarity", Symbol::Void),
+            Some(Placement::Slot { slot: StorySlot::Action, fits: true }),
+        );
+        assert!(moment.is_complete());
+        assert_eq!(moment.generated_text, "In the [PATIENCE], a [BIAS] begins to [CLARITY].");
+    }
+
+    #[test]
+    fn test_mismatched_card_is_placed_but_dissonant() {
+        let mut moment = StoryMoment::default();
+        moment.place(card(1), "Patience", Symbol::Stone);
+        assert_eq!(
+            moment.place(card(2), "Presence", Symbol::Stone),
+            Some(Placement::Slot { slot: StorySlot::Subject, fits: false }),
+        );
+        assert!(moment.is_dissonant());
+        assert!(moment.generated_text.contains("something is off"));
+    }
+
+    #[test]
+    fn test_prism_attaches_to_last_placed_card() {
+        let mut moment = StoryMoment::default();
+        moment.place(card(1), "Presence", Symbol::Stone);
+        assert_eq!(
+            moment.place(card(2), "Resilient", Symbol::Prism),
+            Some(Placement::Modifier { target: StorySlot::Setting }),
+        );
+        assert_eq!(moment.slot_phrase(StorySlot::Setting), "[RESILIENT PRESENCE]");
+        assert!(!moment.is_complete());
+        assert_eq!(moment.placed_entities().len(), 2);
+    }
+}
@@ -0,0 +1,448 @@
+// Daydream Engine — Constructed Decks
+// Once a student owns words, they can build their own session deck:
+// a named hand-picked set of SpellBook words, played against the story
+// (the curriculum) instead of the whole word set.
+//
+// The rules (DeckRules) decide what is legal, and the engine enforces
+// them wherever a deck is saved or dealt — a UI only proposes:
+//   - only words at Owned or Mastered in the SpellBook
+//   - each word once
+//   - between min_size and max_size cards
+//   - no channel above max_channel_share of the deck
+//   - when dealt, every word must be in the story being played
+//
+// Decks live in the save (DeckLibrary). `--deck=NAME` plays one; an
+// unknown or illegal deck falls back to the whole curriculum.
+//
+//   daydream-engine --decks                       what can go in a deck
+//   daydream-engine --make-deck=Still --words=Presence,Patience,Bias,...
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use crate::components::*;
+use crate::rng::SessionRng;
+use crate::save::{SaveData, SaveStore};
+
+// ─── RULES ──────────────────────────────────────────────────
+
+/// Resource: what makes a constructed deck legal.
+#[derive(Resource, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeckRules {
+    pub min_size: usize,
+    pub max_size: usize,
+    /// The least a word must be known to go in a deck.
+    pub min_mastery: MasteryLevel,
+    /// The largest share of the deck one channel may take.
+    pub max_channel_share: f32,
+}
+
+impl Default for DeckRules {
+    fn default() -> Self {
+        Self {
+            min_size: 6,
+            max_size: 20,
+            min_mastery: MasteryLevel::Owned,
+            max_channel_share: 0.5,
+        }
+    }
+}
+
+/// A named deck of SpellBook words.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConstructedDeck {
+    pub name: String,
+    pub words: Vec<String>,
+}
+
+/// Why a deck is not legal. Checks collect all of them, so a student
+/// can fix a deck in one pass.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DeckError {
+    NoName,
+    UnknownDeck(String),
+    TooSmall { size: usize, min: usize },
+    TooLarge { size: usize, max: usize },
+    Duplicate(String),
+    /// In the SpellBook below the rules' mastery, or not in it at all.
+    NotEarned { word: String, mastery: Option<MasteryLevel> },
+    Unbalanced { channel: Channel, count: usize, max: usize },
+    /// The story being played has no such word.
+    NotInStory(String),
+}
+
+impl fmt::Display for DeckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeckError::NoName => write!(f, "a deck needs a name"),
+            DeckError::UnknownDeck(name) => write!(f, "no saved deck is named '{}'", name),
+            DeckError::TooSmall { size, min } =>
+                write!(f, "the deck has {} cards; it needs at least {}", size, min),
+            DeckError::TooLarge { size, max } =>
+                write!(f, "the deck has {} cards; it may hold at most {}", size, max),
+            DeckError::Duplicate(word) => write!(f, "'{}' is in the deck more than once", word),
+            DeckError::NotEarned { word, mastery: Some(level) } =>
+                write!(f, "'{}' is only {} — keep casting it to add it to a deck", word, level.label()),
+            DeckError::NotEarned { word, mastery: None } =>
+                write!(f, "'{}' is not in the SpellBook", word),
+            DeckError::Unbalanced { channel, count, max } =>
+                write!(f, "{} {} cards; a deck this size holds at most {} of one channel", count, channel.label(), max),
+            DeckError::NotInStory(word) => write!(f, "'{}' is not in this story", word),
+        }
+    }
+}
+
+impl std::error::Error for DeckError {}
+
+impl DeckRules {
+    /// SpellBook words that may go in a deck.
+    pub fn eligible<'a>(&self, spellbook: &'a SpellBook) -> impl Iterator<Item = &'a SpellBookEntry> + 'a {
+        let min = self.min_mastery;
+        spellbook.entries.iter().filter(move |e| e.mastery >= min)
+    }
+
+    /// Most cards of one channel a deck of this size may hold.
+    pub fn max_per_channel(&self, size: usize) -> usize {
+        ((size as f32 * self.max_channel_share).floor() as usize).max(1)
+    }
+
+    /// Check a deck against the student's SpellBook.
+    pub fn check(&self, deck: &ConstructedDeck, spellbook: &SpellBook) -> Result<(), Vec<DeckError>> {
+        let mut errors = Vec::new();
+        if deck.name.trim().is_empty() {
+            errors.push(DeckError::NoName);
+        }
+        let size = deck.words.len();
+        if size < self.min_size {
+            errors.push(DeckError::TooSmall { size, min: self.min_size });
+        }
+        if size > self.max_size {
+            errors.push(DeckError::TooLarge { size, max: self.max_size });
+        }
+
+        let mut seen = HashSet::new();
+        let mut per_channel: HashMap<Channel, usize> = HashMap::new();
+        for word in &deck.words {
+            if !seen.insert(word.as_str()) {
+                errors.push(DeckError::Duplicate(word.clone()));
+                continue;
+            }
+            match spellbook.entries.iter().find(|e| &e.word == word) {
+                Some(entry) if entry.mastery >= self.min_mastery => {
+                    *per_channel.entry(entry.channel).or_default() += 1;
+                }
+                entry => errors.push(DeckError::NotEarned {
+                    word: word.clone(),
+                    mastery: entry.map(|e| e.mastery),
+                }),
+            }
+        }
+
+        let max = self.max_per_channel(size);
+        for channel in Channel::ALL {
+            let count = per_channel.get(&channel).copied().unwrap_or(0);
+            if count > max {
+                errors.push(DeckError::Unbalanced { channel, count, max });
+            }
+        }
+
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+}
+
+// ─── LIBRARY ────────────────────────────────────────────────
+
+/// Resource: the student's saved decks. Persists in the save.
+#[derive(Resource, Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeckLibrary {
+    pub decks: Vec<ConstructedDeck>,
+}
+
+impl DeckLibrary {
+    pub fn get(&self, name: &str) -> Option<&ConstructedDeck> {
+        self.decks.iter().find(|d| d.name == name)
+    }
+
+    /// Keep a legal deck, replacing any deck of the same name.
+    pub fn save(&mut self, deck: ConstructedDeck, rules: &DeckRules, spellbook: &SpellBook) -> Result<(), Vec<DeckError>> {
+        rules.check(&deck, spellbook)?;
+        match self.decks.iter_mut().find(|d| d.name == deck.name) {
+            Some(existing) => *existing = deck,
+            None => self.decks.push(deck),
+        }
+        Ok(())
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<ConstructedDeck> {
+        let index = self.decks.iter().position(|d| d.name == name)?;
+        Some(self.decks.remove(index))
+    }
+}
+
+/// Resource: the deck chosen for this session, if any (`--deck=NAME`).
+#[derive(Resource, Clone, Debug, Default)]
+pub struct DeckChoice(pub Option<String>);
+
+impl DeckChoice {
+    pub fn from_args() -> Self {
+        Self(std::env::args().find_map(|a| a.strip_prefix("--deck=").map(str::to_string)))
+    }
+}
+
+// ─── DEALING ────────────────────────────────────────────────
+
+/// What a chosen deck is checked against when it's dealt.
+#[derive(SystemParam)]
+pub struct DeckCheck<'w> {
+    library: Res<'w, DeckLibrary>,
+    rules: Res<'w, DeckRules>,
+    spellbook: Res<'w, SpellBook>,
+}
+
+/// System: deal the chosen deck instead of the whole curriculum.
+/// Runs while Loading, before deck::init_deck. The deck is checked
+/// again against today's SpellBook and story; if it no longer holds
+/// up, the choice is dropped and init_deck deals the curriculum.
+/// Every card of a constructed deck can be drawn from the start — the
+/// student earned those words in earlier sessions.
+pub fn deal_constructed_deck(
+    mut choice: ResMut<DeckChoice>,
+    check: DeckCheck,
+    mut deck: ResMut<Deck>,
+    mut rng: ResMut<SessionRng>,
+    words: Query<(Entity, &WordCard)>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let Some(name) = choice.0.clone() else { return };
+    if !deck.cards.is_empty() || words.is_empty() {
+        return; // Already dealt, or the curriculum has not spawned yet
+    }
+    let DeckCheck { library, rules, spellbook } = check;
+
+    let in_story: HashMap<&str, Entity> = words.iter().map(|(e, w)| (w.word.as_str(), e)).collect();
+    let errors = match library.get(&name) {
+        None => vec![DeckError::UnknownDeck(name.clone())],
+        Some(constructed) => {
+            let mut errors = rules.check(constructed, &spellbook).err().unwrap_or_default();
+            errors.extend(constructed.words.iter()
+                .filter(|w| !in_story.contains_key(w.as_str()))
+                .map(|w| DeckError::NotInStory(w.clone())));
+            errors
+        }
+    };
+    if !errors.is_empty() {
+        for error in &errors {
+            warn!("Deck '{}': {}", name, error);
+        }
+        warn!("Deck '{}' can't be played; dealing the whole curriculum", name);
+        choice.0 = None;
+        return;
+    }
+
+    let mut dealt: Vec<Entity> = library.get(&name).into_iter()
+        .flat_map(|d| d.words.iter())
+        .filter_map(|w| in_story.get(w.as_str()).copied())
+        .collect();
+    rng.shuffle(&mut dealt);
+    deck.cards = dealt;
+    deck.open = true;
+    info!("Dealt deck '{}' ({} cards, seed {})", name, deck.remaining(), rng.seed());
+    next_state.set(GameState::Drawing);
+}
+
+// ─── CLI ────────────────────────────────────────────────────
+
+/// `--decks` lists what may go in a deck and the saved decks;
+/// `--make-deck=NAME --words=A,B,...` saves a deck (legal ones only).
+/// Both work on the save chosen with `--save=`. Returns the exit code.
+pub fn run_from_args() -> i32 {
+    let args: Vec<String> = std::env::args().collect();
+    let value = |flag: &str| args.iter().find_map(|a| a.strip_prefix(flag));
+    let store = SaveStore::from_args();
+    let rules = DeckRules::default();
+
+    let loaded = store.read().and_then(|raw| raw.map(|json| SaveData::from_json(&json)).transpose());
+    let mut data = match loaded {
+        Ok(data) => data.unwrap_or_default(),
+        Err(e) => {
+            eprintln!("daydream --decks: {}", e);
+            return 1;
+        }
+    };
+
+    if let Some(name) = value("--make-deck=") {
+        let words = value("--words=").unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|w| !w.is_empty())
+            .map(str::to_string)
+            .collect();
+        let deck = ConstructedDeck { name: name.to_string(), words };
+        if let Err(errors) = data.deck_library.save(deck, &rules, &data.spellbook) {
+            for error in errors {
+                eprintln!("daydream --make-deck: {}", error);
+            }
+            return 1;
+        }
+        if let Err(e) = data.to_json().and_then(|json| store.write(&json)) {
+            eprintln!("daydream --make-deck: {}", e);
+            return 1;
+        }
+        println!("Saved deck '{}'", name);
+        return 0;
+    }
+
+    println!("Words you can build with ({} or better):", rules.min_mastery.label());
+    for channel in Channel::ALL {
+        let words: Vec<&str> = rules.eligible(&data.spellbook)
+            .filter(|e| e.channel == channel)
+            .map(|e| e.word.as_str())
+            .collect();
+        println!("  {:<7} {}", channel.label(), if words.is_empty() { "—".to_string() } else { words.join(", ") });
+    }
+    println!(
+        "Decks hold {}–{} cards, at most {:.0}% from one channel.",
+        rules.min_size, rules.max_size, rules.max_channel_share * 100.0,
+    );
+    println!("Saved decks:");
+    if data.deck_library.decks.is_empty() {
+        println!("  none");
+    }
+    for deck in &data.deck_library.decks {
+        let legal = match rules.check(deck, &data.spellbook) {
+            Ok(()) => "ready".to_string(),
+            Err(errors) => format!("not playable: {}", errors[0]),
+        };
+        println!("  {} ({} cards, {}): {}", deck.name, deck.words.len(), legal, deck.words.join(", "));
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dag::CurriculumSource;
+    use crate::sim::harness::{deal, state};
+    use crate::sim::{carry_progress, headless_app, CarriedProgress};
+
+    fn book(words: &[(&str, Channel, MasteryLevel)]) -> SpellBook {
+        let mut book = SpellBook::default();
+        for (word, channel, mastery) in words {
+            book.sync_power(word, *channel, &SpellPower { mastery: *mastery, ..default() });
+        }
+        book
+    }
+
+    fn deck(name: &str, words: &[&str]) -> ConstructedDeck {
+        ConstructedDeck { name: name.to_string(), words: words.iter().map(|w| w.to_string()).collect() }
+    }
+
+    fn small_rules() -> DeckRules {
+        DeckRules { min_size: 4, max_size: 6, ..default() }
+    }
+
+    /// The demo words, all earned except Resilience.
+    fn demo_book() -> SpellBook {
+        book(&[
+            ("Presence", Channel::Body, MasteryLevel::Owned),
+            ("Bias", Channel::Mind, MasteryLevel::Mastered),
+            ("Patience", Channel::Body, MasteryLevel::Owned),
+            ("Clarity", Channel::Mind, MasteryLevel::Owned),
+            ("Resilience", Channel::Action, MasteryLevel::Experienced),
+        ])
+    }
+
+    #[test]
+    fn test_decks_take_earned_words_in_balance() {
+        let rules = small_rules();
+        let spellbook = demo_book();
+
+        assert_eq!(rules.check(&deck("Still", &["Presence", "Bias", "Patience", "Clarity"]), &spellbook), Ok(()));
+
+        let errors = rules.check(&deck("", &["Presence", "Presence", "Resilience", "Ghost"]), &spellbook).unwrap_err();
+        assert!(errors.contains(&DeckError::NoName));
+        assert!(errors.contains(&DeckError::Duplicate("Presence".to_string())));
+        assert!(errors.contains(&DeckError::NotEarned {
+            word: "Resilience".to_string(), mastery: Some(MasteryLevel::Experienced),
+        }));
+        assert!(errors.contains(&DeckError::NotEarned { word: "Ghost".to_string(), mastery: None }));
+
+        // Three Body cards in five is more than half
+        let heavy = book(&[
+            ("A", Channel::Body, MasteryLevel::Owned), ("B", Channel::Body, MasteryLevel::Owned),
+            ("C", Channel::Body, MasteryLevel::Owned), ("D", Channel::Mind, MasteryLevel::Owned),
+            ("E", Channel::Heart, MasteryLevel::Owned),
+        ]);
+        assert_eq!(
+            rules.check(&deck("Heavy", &["A", "B", "C", "D", "E"]), &heavy),
+            Err(vec![DeckError::Unbalanced { channel: Channel::Body, count: 3, max: 2 }]),
+        );
+        assert!(matches!(
+            rules.check(&deck("Tiny", &["D", "E"]), &heavy).unwrap_err()[0],
+            DeckError::TooSmall { size: 2, min: 4 },
+        ));
+        assert_eq!(rules.eligible(&spellbook).count(), 4);
+    }
+
+    #[test]
+    fn test_only_legal_decks_are_saved() {
+        let rules = small_rules();
+        let spellbook = demo_book();
+        let mut library = DeckLibrary::default();
+
+        library.save(deck("Still", &["Presence", "Bias", "Patience", "Clarity"]), &rules, &spellbook).unwrap();
+        assert!(library.save(deck("Still", &["Presence", "Resilience"]), &rules, &spellbook).is_err());
+        assert_eq!(library.get("Still").unwrap().words.len(), 4);
+
+        // Saving under the same name replaces the deck
+        library.save(deck("Still", &["Clarity", "Patience", "Bias", "Presence"]), &rules, &spellbook).unwrap();
+        assert_eq!(library.decks.len(), 1);
+        assert_eq!(library.get("Still").unwrap().words[0], "Clarity");
+        assert!(library.remove("Still").is_some());
+        assert!(library.get("Still").is_none());
+    }
+
+    fn session(choice: &str, words: &[&str]) -> App {
+        let mut data = SaveData { spellbook: demo_book(), ..default() };
+        data.deck_library.decks.push(deck(choice, words));
+        let mut app = headless_app(7, &CurriculumSource::Demo);
+        app.insert_resource(small_rules())
+            .insert_resource(DeckChoice(Some(choice.to_string())))
+            .insert_resource(CarriedProgress(data))
+            .add_systems(Startup, carry_progress.after(crate::save::load_progress));
+        deal(&mut app);
+        app
+    }
+
+    fn dealt_words(app: &App) -> Vec<String> {
+        let world = app.world();
+        world.resource::<Deck>().cards.iter().chain(&world.resource::<Hand>().cards)
+            .map(|e| world.get::<WordCard>(*e).unwrap().word.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_a_chosen_deck_is_dealt_instead_of_the_curriculum() {
+        let app = session("Still", &["Presence", "Bias", "Patience", "Clarity"]);
+        assert_eq!(state(&app), GameState::Playing);
+        assert!(app.world().resource::<Deck>().open);
+        // A full hand even though nothing has been cast to unlock these words
+        assert_eq!(app.world().resource::<Hand>().card_count(), 3);
+        let mut dealt = dealt_words(&app);
+        dealt.sort();
+        assert_eq!(dealt, vec!["Bias", "Clarity", "Patience", "Presence"]);
+    }
+
+    #[test]
+    fn test_an_illegal_deck_falls_back_to_the_curriculum() {
+        // Resilience is only Experienced
+        let app = session("Bold", &["Presence", "Bias", "Patience", "Resilience"]);
+        assert!(app.world().resource::<DeckChoice>().0.is_none());
+        assert!(!app.world().resource::<Deck>().open);
+        assert!(dealt_words(&app).len() + app.world().resource::<DiscardPile>().cards.len() >= 5);
+    }
+}
@@ -7,11 +7,18 @@
 //   The word's meaning is EARNED through the journey, not read from a definition.
 
 use bevy::prelude::*;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 use crate::components::*;
+use crate::genre::{ActiveGenre, Atmosphere};
+use crate::locale::ActiveLocale;
+use crate::recycler::RecyclerSession;
 
 /// JSON schema for a word in the curriculum DAG
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct WordDef {
     pub word: String,
     pub depth_prompt: String,
@@ -24,20 +31,45 @@ pub struct WordDef {
     /// Stage: "hero", "outlaw", "edge_lord", "best_self"
     #[serde(default = "default_stage")]
     pub stage: String,
+    /// Symbol: "stone", "spark", "prism", "void", "star"
+    #[serde(default = "default_symbol")]
+    pub symbol: String,
+    /// Card type: "spell", "setting", "ally"
+    #[serde(default = "default_card_type")]
+    pub card_type: String,
     #[serde(default)]
     pub yes_targets: Vec<String>,
     #[serde(default)]
     pub no_targets: Vec<String>,
+    /// Special branches that open only while the Hero's Focus is low
+    #[serde(default)]
+    pub low_focus_targets: Vec<String>,
     /// Synergy partners: ["Patience", "Clarity"]
     #[serde(default)]
     pub synergy_partners: Vec<String>,
+    /// The word's text in other languages, by locale ("es", "es-MX")
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub translations: HashMap<String, WordTranslation>,
+}
+
+/// A word told in another language. Anything left out stays in the
+/// curriculum's own language.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct WordTranslation {
+    pub word: Option<String>,
+    pub depth_prompt: Option<String>,
+    pub themes: Option<Vec<String>>,
+    pub story_text: Option<String>,
 }
 
 fn default_channel() -> String { "body".to_string() }
 fn default_stage() -> String { "hero".to_string() }
+fn default_symbol() -> String { "void".to_string() }
+fn default_card_type() -> String { "spell".to_string() }
 
 /// JSON schema for a complete curriculum
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CurriculumDef {
     pub name: String,
     pub start_word: String,
@@ -51,92 +83,497 @@ pub struct Curriculum {
     pub start_word: String,
 }
 
+/// Where the curriculum comes from. Chosen once at startup from the
+/// command line (`daydream-engine path/to/curriculum.json`), falling back
+/// to the asset folder, and finally to the baked-in demo set.
+/// `--backend=URL` plays the backend's story graph instead (see backend.rs).
+#[derive(Resource, Clone, Debug)]
+pub enum CurriculumSource {
+    Demo,
+    File(PathBuf),
+    /// Already fetched and validated.
+    Graph(Box<CurriculumDef>),
+}
+
+/// Curriculum file picked up automatically when no path is given.
+pub const DEFAULT_CURRICULUM_PATH: &str = "assets/curriculum.json";
+
+impl CurriculumSource {
+    /// The curriculum this source plays — the demo set if a file is
+    /// missing or invalid.
+    pub fn resolve(&self) -> CurriculumDef {
+        resolve_curriculum(self)
+    }
+
+    /// Resolve the source from CLI args, then the asset folder.
+    pub fn from_args() -> Self {
+        let arg = std::env::args().skip(1).find(|a| !a.starts_with("--"));
+        if let Some(path) = arg {
+            return CurriculumSource::File(PathBuf::from(path));
+        }
+        let default = PathBuf::from(DEFAULT_CURRICULUM_PATH);
+        if default.is_file() {
+            CurriculumSource::File(default)
+        } else {
+            CurriculumSource::Demo
+        }
+    }
+}
+
+// ─── VALIDATION ─────────────────────────────────────────────
+
+/// Everything that can be wrong with an authored curriculum file.
+/// Validation collects all of them so authors can fix a file in one pass.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CurriculumError {
+    /// The file could not be read.
+    Io { path: String, message: String },
+    /// The file is not valid curriculum JSON.
+    Parse(String),
+    /// `start_word` does not name a word in the set.
+    MissingStartWord(String),
+    /// The same word is defined twice.
+    DuplicateWord(String),
+    /// An edge or synergy partner names a word that does not exist.
+    UnknownReference { word: String, field: &'static str, target: String },
+    /// A channel string that is not mind/heart/body/action.
+    BadChannel { word: String, value: String },
+    /// A stage string that is not hero/outlaw/edge_lord/best_self.
+    BadStage { word: String, value: String },
+    /// A symbol string that is not stone/spark/prism/void/star.
+    BadSymbol { word: String, value: String },
+    /// A card type string that is not spell/setting/ally.
+    BadCardType { word: String, value: String },
+    /// The forward (yes) edges loop back on themselves.
+    Cycle(Vec<String>),
+}
+
+impl fmt::Display for CurriculumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CurriculumError::Io { path, message } =>
+                write!(f, "could not read {}: {}", path, message),
+            CurriculumError::Parse(message) =>
+                write!(f, "invalid curriculum JSON: {}", message),
+            CurriculumError::MissingStartWord(word) =>
+                write!(f, "start_word '{}' is not defined in words", word),
+            CurriculumError::DuplicateWord(word) =>
+                write!(f, "word '{}' is defined more than once", word),
+            CurriculumError::UnknownReference { word, field, target } =>
+                write!(f, "'{}' lists unknown word '{}' in {}", word, target, field),
+            CurriculumError::BadChannel { word, value } =>
+                write!(f, "'{}' has unknown channel '{}' (expected mind, heart, body or action)", word, value),
+            CurriculumError::BadStage { word, value } =>
+                write!(f, "'{}' has unknown stage '{}' (expected hero, outlaw, edge_lord or best_self)", word, value),
+            CurriculumError::BadSymbol { word, value } =>
+                write!(f, "'{}' has unknown symbol '{}' (expected stone, spark, prism, void or star)", word, value),
+            CurriculumError::BadCardType { word, value } =>
+                write!(f, "'{}' has unknown card type '{}' (expected spell, setting or ally)", word, value),
+            CurriculumError::Cycle(path) =>
+                write!(f, "yes_targets form a cycle: {}", path.join(" → ")),
+        }
+    }
+}
+
+impl std::error::Error for CurriculumError {}
+
+impl CurriculumDef {
+    /// Parse and validate a curriculum from JSON text.
+    pub fn from_json(json: &str) -> Result<Self, Vec<CurriculumError>> {
+        let def: CurriculumDef = serde_json::from_str(json)
+            .map_err(|e| vec![CurriculumError::Parse(e.to_string())])?;
+        def.validate()?;
+        Ok(def)
+    }
+
+    /// Read, parse and validate a curriculum file.
+    pub fn load(path: &Path) -> Result<Self, Vec<CurriculumError>> {
+        let json = std::fs::read_to_string(path).map_err(|e| vec![CurriculumError::Io {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        }])?;
+        Self::from_json(&json)
+    }
+
+    /// Check references, enum strings and DAG shape.
+    /// Only `yes_targets` must be acyclic — `no_targets` may loop back
+    /// to gentler concepts by design.
+    pub fn validate(&self) -> Result<(), Vec<CurriculumError>> {
+        let mut errors = Vec::new();
+        let mut known: HashSet<&str> = HashSet::new();
+
+        for word_def in &self.words {
+            if !known.insert(word_def.word.as_str()) {
+                errors.push(CurriculumError::DuplicateWord(word_def.word.clone()));
+            }
+        }
+
+        if !known.contains(self.start_word.as_str()) {
+            errors.push(CurriculumError::MissingStartWord(self.start_word.clone()));
+        }
+
+        for word_def in &self.words {
+            if parse_channel(&word_def.channel).is_none() {
+                errors.push(CurriculumError::BadChannel {
+                    word: word_def.word.clone(),
+                    value: word_def.channel.clone(),
+                });
+            }
+            if parse_stage(&word_def.stage).is_none() {
+                errors.push(CurriculumError::BadStage {
+                    word: word_def.word.clone(),
+                    value: word_def.stage.clone(),
+                });
+            }
+            if parse_symbol(&word_def.symbol).is_none() {
+                errors.push(CurriculumError::BadSymbol {
+                    word: word_def.word.clone(),
+                    value: word_def.symbol.clone(),
+                });
+            }
+            if parse_card_type(&word_def.card_type).is_none() {
+                errors.push(CurriculumError::BadCardType {
+                    word: word_def.word.clone(),
+                    value: word_def.card_type.clone(),
+                });
+            }
+
+            let references = [
+                ("yes_targets", &word_def.yes_targets),
+                ("no_targets", &word_def.no_targets),
+                ("low_focus_targets", &word_def.low_focus_targets),
+                ("synergy_partners", &word_def.synergy_partners),
+            ];
+            for (field, targets) in references {
+                for target in targets {
+                    if !known.contains(target.as_str()) {
+                        errors.push(CurriculumError::UnknownReference {
+                            word: word_def.word.clone(),
+                            field,
+                            target: target.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        if let Some(cycle) = self.find_yes_cycle() {
+            errors.push(CurriculumError::Cycle(cycle));
+        }
+
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+
+    /// Depth-first search over `yes_targets`, returning the first cycle found.
+    fn find_yes_cycle(&self) -> Option<Vec<String>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Mark { Unvisited, InProgress, Done }
+
+        let index: HashMap<&str, usize> = self.words.iter().enumerate()
+            .map(|(i, w)| (w.word.as_str(), i))
+            .collect();
+        let mut marks = vec![Mark::Unvisited; self.words.len()];
+        let mut path: Vec<usize> = Vec::new();
+
+        fn visit(
+            node: usize,
+            def: &CurriculumDef,
+            index: &HashMap<&str, usize>,
+            marks: &mut [Mark],
+            path: &mut Vec<usize>,
+        ) -> Option<Vec<String>> {
+            marks[node] = Mark::InProgress;
+            path.push(node);
+            for target in &def.words[node].yes_targets {
+                let Some(&next) = index.get(target.as_str()) else { continue };
+                match marks[next] {
+                    Mark::InProgress => {
+                        let start = path.iter().position(|&n| n == next).unwrap_or(0);
+                        let mut cycle: Vec<String> = path[start..].iter()
+                            .map(|&n| def.words[n].word.clone())
+                            .collect();
+                        cycle.push(def.words[next].word.clone());
+                        return Some(cycle);
+                    }
+                    Mark::Unvisited => {
+                        if let Some(cycle) = visit(next, def, index, marks, path) {
+                            return Some(cycle);
+                        }
+                    }
+                    Mark::Done => {}
+                }
+            }
+            path.pop();
+            marks[node] = Mark::Done;
+            None
+        }
+
+        for node in 0..self.words.len() {
+            if marks[node] == Mark::Unvisited {
+                if let Some(cycle) = visit(node, self, &index, &mut marks, &mut path) {
+                    return Some(cycle);
+                }
+            }
+        }
+        None
+    }
+}
+
 /// Parse a channel string to the Channel enum.
-fn parse_channel(s: &str) -> Channel {
+pub(crate) fn parse_channel(s: &str) -> Option<Channel> {
     match s.to_lowercase().as_str() {
-        "mind"   => Channel::Mind,
-        "heart"  => Channel::Heart,
-        "action" => Channel::Action,
-        _        => Channel::Body,
+        "mind"   => Some(Channel::Mind),
+        "heart"  => Some(Channel::Heart),
+        "body"   => Some(Channel::Body),
+        "action" => Some(Channel::Action),
+        _        => None,
     }
 }
 
 /// Parse a stage string to the Stage enum.
-fn parse_stage(s: &str) -> Stage {
+fn parse_stage(s: &str) -> Option<Stage> {
+    match s.to_lowercase().as_str() {
+        "hero"      => Some(Stage::Hero),
+        "outlaw"    => Some(Stage::Outlaw),
+        "edge_lord" => Some(Stage::EdgeLord),
+        "best_self" => Some(Stage::BestSelf),
+        _           => None,
+    }
+}
+
+/// Parse a symbol string to the Symbol enum.
+fn parse_symbol(s: &str) -> Option<Symbol> {
+    match s.to_lowercase().as_str() {
+        "stone" => Some(Symbol::Stone),
+        "spark" => Some(Symbol::Spark),
+        "prism" => Some(Symbol::Prism),
+        "void"  => Some(Symbol::Void),
+        "star"  => Some(Symbol::Star),
+        _       => None,
+    }
+}
+
+/// Parse a card type string to the CardType enum.
+fn parse_card_type(s: &str) -> Option<CardType> {
     match s.to_lowercase().as_str() {
-        "outlaw"    => Stage::Outlaw,
-        "edge_lord" => Stage::EdgeLord,
-        "best_self" => Stage::BestSelf,
-        _           => Stage::Hero,
+        "spell"   => Some(CardType::Spell),
+        "setting" => Some(CardType::Setting),
+        "ally"    => Some(CardType::Ally),
+        _         => None,
     }
 }
 
-/// Spawn all word entities from the hardcoded demo curriculum.
-/// In the future, this loads from JSON files authored by parents.
-pub fn spawn_demo_curriculum(mut commands: Commands) {
-    let curriculum = demo_curriculum();
+// ─── SPAWNING ───────────────────────────────────────────────
+
+/// Resolve the configured source into a validated curriculum.
+/// Invalid files are reported and the demo set is used instead,
+/// so a typo never leaves a classroom with a blank screen.
+fn resolve_curriculum(source: &CurriculumSource) -> CurriculumDef {
+    match source {
+        CurriculumSource::Demo => demo_curriculum(),
+        CurriculumSource::Graph(def) => (**def).clone(),
+        CurriculumSource::File(path) => match CurriculumDef::load(path) {
+            Ok(def) => {
+                info!("Loaded curriculum '{}' from {}", def.name, path.display());
+                def
+            }
+            Err(errors) => {
+                report_errors(path, &errors);
+                warn!("Falling back to the demo curriculum");
+                demo_curriculum()
+            }
+        },
+    }
+}
+
+fn report_errors(path: &Path, errors: &[CurriculumError]) {
+    error!("Curriculum {} has {} problem(s):", path.display(), errors.len());
+    for e in errors {
+        error!("  - {}", e);
+    }
+}
 
+/// System: spawn all word entities from the configured curriculum source.
+pub fn spawn_curriculum(
+    mut commands: Commands,
+    source: Res<CurriculumSource>,
+    genre: Res<ActiveGenre>,
+    locale: Res<ActiveLocale>,
+) {
+    let curriculum = resolve_curriculum(&source);
+    spawn_words(&mut commands, &curriculum, &genre, &locale);
+
+    // Initialize game resources — the story opens on the genre's frame
+    commands.insert_resource(StudentTrail::default());
+    commands.insert_resource(RecyclerSession::default());
+    commands.insert_resource(CurrentSlide {
+        story_text: genre.template.frame(&locale),
+        ..default()
+    });
+    commands.insert_resource(CharacterSheet::default());
+    commands.insert_resource(SpellBook::default());
+}
+
+/// Spawn each word of a validated curriculum as an ECS entity, in the
+/// student's language where the curriculum has it, its Setting skinned
+/// by the active genre.
+fn spawn_words(commands: &mut Commands, curriculum: &CurriculumDef, genre: &ActiveGenre, locale: &ActiveLocale) {
     // Store curriculum metadata
     commands.insert_resource(Curriculum {
         name: curriculum.name.clone(),
         start_word: curriculum.start_word.clone(),
     });
 
+    // Channels by word, so synergy types can be inferred from both ends
+    let channels: HashMap<&str, Channel> = curriculum.words.iter()
+        .map(|w| (w.word.as_str(), parse_channel(&w.channel).unwrap_or(Channel::Body)))
+        .collect();
+
     // Spawn each word as an ECS entity — a spell card in the set
     for word_def in &curriculum.words {
-        let channel = parse_channel(&word_def.channel);
-        let stage = parse_stage(&word_def.stage);
+        let channel = parse_channel(&word_def.channel).unwrap_or(Channel::Body);
+        let stage = parse_stage(&word_def.stage).unwrap_or(Stage::Hero);
+        let symbol = parse_symbol(&word_def.symbol).unwrap_or(Symbol::Void);
+        let card_type = parse_card_type(&word_def.card_type).unwrap_or_default();
 
         // Build synergy links
         let synergy_links = SynergyLinks {
             links: word_def.synergy_partners.iter().map(|partner| {
+                let partner_channel = channels.get(partner.as_str()).copied().unwrap_or(channel);
+                let synergy_type = SynergyType::between(channel, partner_channel);
                 SynergyEntry {
                     partner: partner.clone(),
-                    synergy_type: SynergyType::Resonant,
-                    bonus: format!("{} + {} amplify each other", word_def.word, partner),
+                    synergy_type,
+                    bonus: synergy_type.flavor(&word_def.word, partner),
                 }
             }).collect(),
         };
 
+        let local = locale.translation(&word_def.translations).cloned().unwrap_or_default();
+        let card = WordCard {
+            word: word_def.word.clone(),
+            name: local.word.unwrap_or_else(|| word_def.word.clone()),
+            depth_prompt: local.depth_prompt.unwrap_or_else(|| word_def.depth_prompt.clone()),
+            themes: local.themes.unwrap_or_else(|| word_def.themes.clone()),
+            localized_story: local.story_text.is_some(),
+            story_text: local.story_text.unwrap_or_else(|| word_def.story_text.clone()),
+        };
+        // Visual style — color derived from Channel
+        let style = CardStyle {
+            color: channel.color(),
+            mood: word_def.mood.clone(),
+        };
+        // Setting backdrop — the genre's telling of this word
+        let setting = genre.setting_for(&card, &style, channel, locale);
+
         commands.spawn((
             // The spell itself
-            WordCard {
-                word: word_def.word.clone(),
-                depth_prompt: word_def.depth_prompt.clone(),
-                themes: word_def.themes.clone(),
-            },
+            card,
             // Which channel this spell belongs to
             channel,
             // What mastery tier this word requires
             stage,
+            // How the word functions in a sentence — which slot it fits
+            symbol,
+            // Spell, Setting or Ally — what casting it does to the story
+            card_type,
             // DAG edges
             WordEdges {
                 yes_targets: word_def.yes_targets.clone(),
                 no_targets: word_def.no_targets.clone(),
+                low_focus_targets: word_def.low_focus_targets.clone(),
             },
-            // Visual style — color derived from Channel
-            CardStyle {
-                color: channel.color(),
-                mood: word_def.mood.clone(),
-            },
-            // Setting backdrop — tinted by channel
-            Setting {
-                mood: word_def.mood.clone(),
-                genre: "fantasy".to_string(),
-                background_color: channel.background_color(),
-            },
+            style,
+            setting,
             // Mastery tracking — starts at zero
             SpellPower::default(),
             // Synergy relationships
             synergy_links,
         ));
     }
+}
+
+// ─── HOT RELOAD ─────────────────────────────────────────────
+
+/// Polls the curriculum file so authors can iterate without restarting.
+#[derive(Resource)]
+pub struct CurriculumWatcher {
+    path: PathBuf,
+    last_modified: Option<SystemTime>,
+    timer: Timer,
+}
+
+impl CurriculumWatcher {
+    /// Watch the source file, if there is one.
+    pub fn for_source(source: &CurriculumSource) -> Option<Self> {
+        match source {
+            CurriculumSource::Demo | CurriculumSource::Graph(_) => None,
+            CurriculumSource::File(path) => Some(Self {
+                path: path.clone(),
+                last_modified: modified_time(path),
+                timer: Timer::from_seconds(1.0, TimerMode::Repeating),
+            }),
+        }
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// System: reload the curriculum when its file changes on disk.
+/// A valid edit replaces every word entity and restarts the session;
+/// an invalid edit is reported and the current words stay in play.
+pub fn hot_reload_curriculum(
+    mut commands: Commands,
+    time: Res<Time>,
+    watcher: Option<ResMut<CurriculumWatcher>>,
+    words: Query<Entity, With<WordCard>>,
+    genre: Res<ActiveGenre>,
+    locale: Res<ActiveLocale>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let Some(mut watcher) = watcher else { return };
+    if !watcher.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let modified = modified_time(&watcher.path);
+    if modified.is_none() || modified == watcher.last_modified {
+        return;
+    }
+    watcher.last_modified = modified;
+
+    let curriculum = match CurriculumDef::load(&watcher.path) {
+        Ok(def) => def,
+        Err(errors) => {
+            report_errors(&watcher.path, &errors);
+            return;
+        }
+    };
+    info!("Reloaded curriculum '{}' ({} words)", curriculum.name, curriculum.words.len());
+
+    for entity in words.iter() {
+        commands.entity(entity).despawn();
+    }
+    spawn_words(&mut commands, &curriculum, &genre, &locale);
 
-    // Initialize game resources
+    // Restart the session on the new word set; the student's
+    // CharacterSheet, SpellBook and review schedule carry over.
+    commands.insert_resource(Deck::default());
+    commands.insert_resource(Hand::default());
+    commands.insert_resource(DiscardPile::default());
+    commands.insert_resource(ActiveSynergies::default());
+    commands.insert_resource(Atmosphere::default());
+    commands.insert_resource(StoryMoment::default());
+    commands.insert_resource(ResonanceThreads::default());
     commands.insert_resource(StudentTrail::default());
-    commands.insert_resource(CurrentSlide::default());
-    commands.insert_resource(CharacterSheet::default());
-    commands.insert_resource(SpellBook::default());
+    commands.insert_resource(RecyclerSession::default());
+    commands.insert_resource(CurrentSlide {
+        story_text: genre.template.frame(&locale),
+        ..default()
+    });
+    next_state.set(GameState::Loading);
 }
 
 /// Demo curriculum: "Bias & Mirrors" — a 5-word set about self-awareness
@@ -154,9 +591,18 @@ fn demo_curriculum() -> CurriculumDef {
                 story_text: "You stand before an ancient stone archway. Warm light spills through from the other side. The air is still.".to_string(),
                 channel: "body".to_string(),    // Grounding, somatic awareness
                 stage: "hero".to_string(),       // Entry point — absorb through story
+                symbol: "stone".to_string(),     // Grounding place — a Setting noun
+                card_type: "setting".to_string(), // Its calm carries into what follows
                 yes_targets: vec!["Bias".to_string()],
                 no_targets: vec!["Patience".to_string()],
+                low_focus_targets: vec![],
                 synergy_partners: vec!["Patience".to_string()],
+                translations: spanish(
+                    "Presencia",
+                    "¿Qué significa estar aquí? No ayer, no mañana: solo ahora, con la luz en tu cara.",
+                    &["conciencia", "arraigo"],
+                    "Estás frente a un antiguo arco de piedra. Una luz cálida se derrama desde el otro lado. El aire está quieto.",
+                ),
             },
             WordDef {
                 word: "Bias".to_string(),
@@ -166,9 +612,18 @@ fn demo_curriculum() -> CurriculumDef {
                 story_text: "Dark glass trees reflect a warped version of you. A whisper echoes: 'You will fail here, just as before.'".to_string(),
                 channel: "mind".to_string(),     // Metacognitive — pattern-seeing
                 stage: "edge_lord".to_string(),  // Requires seeing the code behind the story
+                symbol: "star".to_string(),      // Key term — the Subject of the story
+                card_type: "spell".to_string(),
                 yes_targets: vec!["Resilience".to_string()],
                 no_targets: vec!["Patience".to_string()],
+                low_focus_targets: vec!["Clarity".to_string()], // Worn down, the mirror cracks open
                 synergy_partners: vec!["Clarity".to_string()],
+                translations: spanish(
+                    "Sesgo",
+                    "El susurro usa tu propia voz. ¿Por qué creemos las peores historias que nos contamos sobre nosotros mismos?",
+                    &["autoconciencia", "pensamiento crítico"],
+                    "Árboles de cristal oscuro reflejan una versión deformada de ti. Un susurro resuena: «Fracasarás aquí, igual que antes».",
+                ),
             },
             WordDef {
                 word: "Patience".to_string(),
@@ -178,9 +633,18 @@ fn demo_curriculum() -> CurriculumDef {
                 story_text: "A walled garden filled with sunlight and birdsong. A fountain murmurs at the center. Nothing here demands anything of you.".to_string(),
                 channel: "body".to_string(),     // Stillness, felt sense
                 stage: "hero".to_string(),       // Gentle entry — absorb the feeling
+                symbol: "stone".to_string(),     // A place of stillness — a Setting noun
+                card_type: "ally".to_string(),   // Steadies the words it resonates with
                 yes_targets: vec!["Resilience".to_string()],
                 no_targets: vec!["Clarity".to_string()],
+                low_focus_targets: vec![],
                 synergy_partners: vec!["Presence".to_string(), "Resilience".to_string()],
+                translations: spanish(
+                    "Paciencia",
+                    "La paciencia no es esperar. Es poder estar en paz mientras las cosas se despliegan. ¿Qué crece cuando dejas de tirar de ello?",
+                    &["crecimiento", "quietud"],
+                    "Un jardín amurallado lleno de sol y cantos de pájaros. Una fuente murmura en el centro. Aquí nada te exige nada.",
+                ),
             },
             WordDef {
                 word: "Resilience".to_string(),
@@ -190,9 +654,18 @@ fn demo_curriculum() -> CurriculumDef {
                 story_text: "A narrow bridge over a deep canyon. The wind pushes hard. Every step forward is a choice to keep going.".to_string(),
                 channel: "action".to_string(),   // Doing despite difficulty
                 stage: "outlaw".to_string(),     // Requires pushing back, finding will
+                symbol: "void".to_string(),      // Abstract — fits any slot
+                card_type: "spell".to_string(),
                 yes_targets: vec!["Clarity".to_string()],
                 no_targets: vec!["Patience".to_string()],
+                low_focus_targets: vec![],
                 synergy_partners: vec!["Patience".to_string()],
+                translations: spanish(
+                    "Resiliencia",
+                    "La resiliencia no consiste en no caer. Consiste en lo que haces con el viento. ¿Puedes inclinarte hacia él?",
+                    &["valentía", "persistencia"],
+                    "Un puente estrecho sobre un cañón profundo. El viento empuja con fuerza. Cada paso adelante es una decisión de seguir.",
+                ),
             },
             WordDef {
                 word: "Clarity".to_string(),
@@ -202,10 +675,119 @@ fn demo_curriculum() -> CurriculumDef {
                 story_text: "Above the clouds. The world stretches out below — every path you've walked visible from here.".to_string(),
                 channel: "mind".to_string(),     // Synthesis, integration
                 stage: "best_self".to_string(),  // Requires connecting prior words
+                symbol: "void".to_string(),      // Abstract — fits any slot
+                card_type: "spell".to_string(),
                 yes_targets: vec![],
                 no_targets: vec![],
+                low_focus_targets: vec![],
                 synergy_partners: vec!["Bias".to_string()],
+                translations: spanish(
+                    "Claridad",
+                    "La claridad no es saber la respuesta. Es ver la pregunta con nitidez por primera vez.",
+                    &["sabiduría", "resolución"],
+                    "Sobre las nubes. El mundo se extiende abajo: desde aquí se ve cada camino que has recorrido.",
+                ),
             },
         ],
     }
 }
+
+/// A demo word's Spanish telling.
+fn spanish(word: &str, depth_prompt: &str, themes: &[&str], story_text: &str) -> HashMap<String, WordTranslation> {
+    let translation = WordTranslation {
+        word: Some(word.to_string()),
+        depth_prompt: Some(depth_prompt.to_string()),
+        themes: Some(themes.iter().map(|t| t.to_string()).collect()),
+        story_text: Some(story_text.to_string()),
+    };
+    HashMap::from([("es".to_string(), translation)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(name: &str, yes: &[&str], no: &[&str]) -> WordDef {
+        WordDef {
+            word: name.to_string(),
+            depth_prompt: String::new(),
+            themes: vec![],
+            mood: "calm".to_string(),
+            story_text: String::new(),
+            channel: "body".to_string(),
+            stage: "hero".to_string(),
+            symbol: "void".to_string(),
+            card_type: "spell".to_string(),
+            yes_targets: yes.iter().map(|s| s.to_string()).collect(),
+            no_targets: no.iter().map(|s| s.to_string()).collect(),
+            low_focus_targets: vec![],
+            synergy_partners: vec![],
+            translations: HashMap::new(),
+        }
+    }
+
+    fn curriculum(start: &str, words: Vec<WordDef>) -> CurriculumDef {
+        CurriculumDef { name: "Test".to_string(), start_word: start.to_string(), words }
+    }
+
+    #[test]
+    fn test_demo_curriculum_is_valid() {
+        assert!(demo_curriculum().validate().is_ok());
+    }
+
+    #[test]
+    fn test_no_targets_may_loop_back() {
+        let def = curriculum("A", vec![word("A", &["B"], &[]), word("B", &[], &["A"])]);
+        assert!(def.validate().is_ok());
+    }
+
+    #[test]
+    fn test_yes_cycle_is_reported() {
+        let def = curriculum("A", vec![
+            word("A", &["B"], &[]),
+            word("B", &["C"], &[]),
+            word("C", &["A"], &[]),
+        ]);
+        let errors = def.validate().unwrap_err();
+        assert_eq!(errors, vec![CurriculumError::Cycle(vec![
+            "A".to_string(), "B".to_string(), "C".to_string(), "A".to_string(),
+        ])]);
+    }
+
+    #[test]
+    fn test_all_problems_are_collected() {
+        let mut bad = word("A", &["Ghost"], &[]);
+        bad.channel = "spirit".to_string();
+        bad.stage = "legend".to_string();
+        bad.symbol = "moon".to_string();
+        bad.card_type = "villain".to_string();
+        bad.synergy_partners = vec!["Nobody".to_string()];
+        let errors = curriculum("Missing", vec![bad]).validate().unwrap_err();
+
+        assert!(errors.contains(&CurriculumError::MissingStartWord("Missing".to_string())));
+        assert!(errors.contains(&CurriculumError::BadChannel {
+            word: "A".to_string(), value: "spirit".to_string(),
+        }));
+        assert!(errors.contains(&CurriculumError::BadStage {
+            word: "A".to_string(), value: "legend".to_string(),
+        }));
+        assert!(errors.contains(&CurriculumError::BadSymbol {
+            word: "A".to_string(), value: "moon".to_string(),
+        }));
+        assert!(errors.contains(&CurriculumError::BadCardType {
+            word: "A".to_string(), value: "villain".to_string(),
+        }));
+        assert!(errors.contains(&CurriculumError::UnknownReference {
+            word: "A".to_string(), field: "yes_targets", target: "Ghost".to_string(),
+        }));
+        assert!(errors.contains(&CurriculumError::UnknownReference {
+            word: "A".to_string(), field: "synergy_partners", target: "Nobody".to_string(),
+        }));
+    }
+
+    #[test]
+    fn test_from_json_reports_parse_errors() {
+        let errors = CurriculumDef::from_json("{ not json").unwrap_err();
+        assert!(matches!(errors[0], CurriculumError::Parse(_)));
+    }
+}
@@ -7,64 +7,139 @@
 //   Playing a card = processing one training example
 //   Synergy = multi-task learning bonus
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use std::collections::HashMap;
 use crate::components::*;
+use crate::dag::Curriculum;
+use crate::input::{JournalDraft, PendingAction, PlayerAction};
+use crate::locale::ActiveLocale;
+use crate::mastery::{synergy_discoveries, ProgressKind, SpellProgress};
+use crate::recycler::{self, ReviewClock, ReviewSchedule};
+use crate::rng::SessionRng;
 
 // ─── DECK INITIALIZATION ────────────────────────────────────
 
-/// System: shuffle all word entities into the deck at session start.
-/// Runs once on entering the Drawing state.
+/// System: shuffle the session's words into the deck at session start.
+/// New words and words the Recycler has due are dealt; the rest wait
+/// in the discard pile. Runs while Loading; once the curriculum's words
+/// exist, fills the deck and moves on to Drawing.
 pub fn init_deck(
     mut deck: ResMut<Deck>,
-    words: Query<Entity, With<WordCard>>,
+    mut discard: ResMut<DiscardPile>,
+    mut rng: ResMut<SessionRng>,
+    schedule: Res<ReviewSchedule>,
+    clock: Res<ReviewClock>,
+    words: Query<(Entity, &WordCard)>,
+    mut next_state: ResMut<NextState<GameState>>,
 ) {
     if !deck.cards.is_empty() {
         return; // Already initialized
     }
 
-    let mut all_words: Vec<Entity> = words.iter().collect();
-
-    // Simple deterministic shuffle using entity index bits
-    // (We avoid rand dependency to keep the build minimal)
-    let len = all_words.len();
-    if len > 1 {
-        for i in (1..len).rev() {
-            let j = (i * 7 + 3) % (i + 1);
-            all_words.swap(i, j);
-        }
+    let all_words: Vec<(Entity, &str)> = words.iter().map(|(e, w)| (e, w.word.as_str())).collect();
+    if all_words.is_empty() {
+        return; // Curriculum not spawned yet
     }
+    let (mut dealt, resting) = schedule.deal(&all_words, clock.now());
+
+    // Seeded shuffle — the same seed always deals the same session
+    rng.shuffle(&mut dealt);
+
+    deck.cards = dealt;
+    discard.cards.extend(resting);
+    info!(
+        "Deck initialized with {} cards, {} resting in the Recycler (seed {})",
+        deck.remaining(), discard.cards.len(), rng.seed(),
+    );
+    next_state.set(GameState::Drawing);
+}
 
-    deck.cards = all_words;
-    info!("Deck initialized with {} cards", deck.remaining());
+// ─── DRAWING ────────────────────────────────────────────────
+
+/// Draw weight multiplier for the current word's yes-targets.
+const YES_TARGET_PULL: f32 = 4.0;
+/// Draw weight multiplier for the current word's no-targets.
+const NO_TARGET_PULL: f32 = 2.0;
+/// Draw weight multiplier for special branches while Focus is low.
+const LOW_FOCUS_PULL: f32 = 4.0;
+
+/// What a draw works with: the deck and hand, the session's RNG, and
+/// the story and Hero that steer the pick.
+#[derive(SystemParam)]
+pub struct DrawContext<'w> {
+    deck: ResMut<'w, Deck>,
+    hand: ResMut<'w, Hand>,
+    rng: ResMut<'w, SessionRng>,
+    curriculum: Option<Res<'w, Curriculum>>,
+    hero: Res<'w, CharacterState>,
 }
 
 /// System: draw cards from deck into hand until hand is full.
+/// Draws follow the DAG: the last cast word's targets are pulled
+/// toward the hand, rarer stages surface less often, and words whose
+/// prerequisites haven't been cast stay in the deck. While the Hero's
+/// Focus is low, the current word's special branches open up. A word
+/// chosen at a branch point is dealt before anything else.
 /// Transitions to Playing state when hand is ready.
 pub fn draw_cards(
-    mut deck: ResMut<Deck>,
-    mut hand: ResMut<Hand>,
+    draw: DrawContext,
     mut trail: ResMut<StudentTrail>,
-    mut spellbook: ResMut<SpellBook>,
+    mut progress: MessageWriter<SpellProgress>,
     mut sheet: ResMut<CharacterSheet>,
-    words: Query<(&WordCard, &Channel)>,
+    words: Query<(&WordCard, &Channel, &Stage, &WordEdges)>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
+    let DrawContext { mut deck, mut hand, mut rng, curriculum, hero } = draw;
+    let prerequisites = prerequisite_map(words.iter().map(|(w, _, _, e)| (w, e)));
+    // Before anything is cast, the DAG pulls from the start word
+    let current_edges = match trail.current_word.and_then(|e| words.get(e).ok()) {
+        Some((_, _, _, edges)) => Some(edges.clone()),
+        None => curriculum.map(|c| WordEdges {
+            yes_targets: vec![c.start_word.clone()],
+            ..default()
+        }),
+    };
+    let low_focus = hero.is_low();
+
     while !hand.is_full() {
-        if let Some(entity) = deck.draw() {
-            hand.cards.push(entity);
+        let weights: Vec<f32> = deck.cards.iter().map(|e| {
+            words.get(*e).map_or(0.0, |(word, _, stage, _)| {
+                let special = low_focus && current_edges.as_ref()
+                    .is_some_and(|e| e.low_focus_targets.contains(&word.word));
+                if special || deck.open || prerequisites_met(&word.word, &prerequisites, &trail.cast_words) {
+                    draw_weight(&word.word, *stage, current_edges.as_ref(), low_focus)
+                } else {
+                    0.0
+                }
+            })
+        }).collect();
+
+        // A branch the student chose comes first, locked or not
+        let mut pick = deck.take_promise().or_else(|| rng.pick_weighted(&weights));
+        if pick.is_none() && hand.card_count() == 0 {
+            // Everything left is locked — never leave the student
+            // with an empty hand; draw by rarity alone.
+            let fallback: Vec<f32> = deck.cards.iter()
+                .map(|e| words.get(*e).map_or(0.0, |(_, _, stage, _)| stage.draw_weight()))
+                .collect();
+            pick = rng.pick_weighted(&fallback);
+        }
 
-            // Record encounter in SpellBook + CharacterSheet
-            if let Ok((word, channel)) = words.get(entity) {
-                spellbook.record_encounter(&word.word, *channel);
-                sheet.engage_channel(channel);
-                sheet.words_encountered += 1;
+        let Some(entity) = pick.and_then(|i| deck.take(i)) else {
+            break; // Deck is empty (or only locked cards remain)
+        };
+        hand.cards.push(entity);
 
-                if !trail.visited_words.contains(&word.word) {
-                    trail.visited_words.push(word.word.clone());
-                }
+        // Record encounter in SpellBook + CharacterSheet
+        if let Ok((word, channel, _, _)) = words.get(entity) {
+            progress.write(SpellProgress { entity, kind: ProgressKind::Encountered });
+            sheet.engage_channel(channel);
+            sheet.words_encountered += 1;
+
+            if !trail.visited_words.contains(&word.word) {
+                trail.visited_words.push(word.word.clone());
             }
-        } else {
-            break; // Deck is empty
         }
     }
 
@@ -77,104 +152,150 @@ pub fn draw_cards(
     }
 }
 
+/// Map each word to the words that lead to it via yes_targets.
+fn prerequisite_map<'a>(
+    words: impl Iterator<Item = (&'a WordCard, &'a WordEdges)>,
+) -> HashMap<String, Vec<String>> {
+    let mut map: HashMap<String, Vec<String>> = HashMap::new();
+    for (word, edges) in words {
+        for target in &edges.yes_targets {
+            map.entry(target.clone()).or_default().push(word.word.clone());
+        }
+    }
+    map
+}
+
+/// A word is unlocked once any of its yes-predecessors has been cast.
+/// Words nothing leads to (like the start word) are always unlocked.
+fn prerequisites_met(
+    word: &str,
+    prerequisites: &HashMap<String, Vec<String>>,
+    cast_words: &[String],
+) -> bool {
+    match prerequisites.get(word) {
+        Some(required) => required.iter().any(|r| cast_words.contains(r)),
+        None => true,
+    }
+}
+
+/// Weight a deck card by its rarity and its pull from the current word.
+fn draw_weight(word: &str, stage: Stage, current: Option<&WordEdges>, low_focus: bool) -> f32 {
+    let pull = match current {
+        Some(edges) if low_focus && edges.low_focus_targets.iter().any(|t| t == word) => LOW_FOCUS_PULL,
+        Some(edges) if edges.yes_targets.iter().any(|t| t == word) => YES_TARGET_PULL,
+        Some(edges) if edges.no_targets.iter().any(|t| t == word) => NO_TARGET_PULL,
+        _ => 1.0,
+    };
+    stage.draw_weight() * pull
+}
+
 // ─── CARD SELECTION ─────────────────────────────────────────
 
-/// System: select a card by keyboard number (1-5).
-pub fn select_card_by_key(
-    keys: Res<ButtonInput<KeyCode>>,
+/// System: select a hand card — by number key, tap, or script.
+/// Selecting another card while one is selected switches to it;
+/// Back deselects.
+pub fn select_card(
+    mut pending: ResMut<PendingAction>,
     mut hand: ResMut<Hand>,
     mut next_state: ResMut<NextState<GameState>>,
     state: Res<State<GameState>>,
 ) {
-    if *state.get() != GameState::Playing { return; }
-
-    let key_map = [
-        (KeyCode::Digit1, 0),
-        (KeyCode::Digit2, 1),
-        (KeyCode::Digit3, 2),
-        (KeyCode::Digit4, 3),
-        (KeyCode::Digit5, 4),
-    ];
-
-    for (key, index) in key_map {
-        if keys.just_pressed(key) && index < hand.card_count() {
+    if !matches!(state.get(), GameState::Playing | GameState::CardSelected) { return; }
+
+    if let Some(index) = pending.take_select() {
+        if index < hand.card_count() && hand.selected != Some(index) {
             hand.selected = Some(index);
             next_state.set(GameState::CardSelected);
-            return;
         }
-    }
-
-    // Escape deselects
-    if keys.just_pressed(KeyCode::Escape) {
-        hand.selected = None;
+    } else if pending.action == Some(PlayerAction::Back) {
+        pending.action = None;
+        if hand.selected.take().is_some() {
+            next_state.set(GameState::Playing);
+        }
     }
 }
 
 // ─── CARD PLAY / DISCARD ────────────────────────────────────
 
+/// What a swipe acts on: the pending action and the state it came in,
+/// the hand and discard pile cards move between, the table a cast is
+/// placed on, and the locale its story is told in.
+#[derive(SystemParam)]
+pub struct CardActionContext<'w> {
+    pending: ResMut<'w, PendingAction>,
+    state: Res<'w, State<GameState>>,
+    hand: ResMut<'w, Hand>,
+    discard: ResMut<'w, DiscardPile>,
+    moment: ResMut<'w, StoryMoment>,
+    locale: Res<'w, ActiveLocale>,
+}
+
 /// System: handle swipe actions on the selected card.
-/// RIGHT = cast (play into story), LEFT = discard, DOWN = dig deeper.
+/// RIGHT = cast (place onto the crafting table), LEFT = discard,
+/// DOWN = dig deeper. Keys, gestures and the headless simulation all
+/// arrive as a PendingAction.
 pub fn handle_card_action(
-    keys: Res<ButtonInput<KeyCode>>,
-    mut hand: ResMut<Hand>,
-    mut discard: ResMut<DiscardPile>,
+    action: CardActionContext,
     mut trail: ResMut<StudentTrail>,
     mut slide: ResMut<CurrentSlide>,
-    mut spellbook: ResMut<SpellBook>,
+    mut progress: MessageWriter<SpellProgress>,
     mut sheet: ResMut<CharacterSheet>,
-    words: Query<(&WordCard, &Channel, &WordEdges, &SpellPower)>,
+    words: Query<(Entity, &WordCard, &Channel, &Symbol, &SynergyLinks, &Setting, &SpellPower)>,
     mut next_state: ResMut<NextState<GameState>>,
-    state: Res<State<GameState>>,
 ) {
+    let CardActionContext { mut pending, state, mut hand, mut discard, mut moment, locale } = action;
     if *state.get() != GameState::CardSelected { return; }
 
-    let action = if keys.just_pressed(KeyCode::ArrowRight) || keys.just_pressed(KeyCode::KeyD) {
-        Some(SwipeChoice::Yes)
-    } else if keys.just_pressed(KeyCode::ArrowLeft) || keys.just_pressed(KeyCode::KeyA) {
-        Some(SwipeChoice::No)
-    } else if keys.just_pressed(KeyCode::ArrowDown) || keys.just_pressed(KeyCode::KeyS)
-        || keys.just_pressed(KeyCode::Space) {
-        Some(SwipeChoice::Deeper)
-    } else if keys.just_pressed(KeyCode::Escape) {
-        // Deselect — go back to hand view
-        hand.selected = None;
-        next_state.set(GameState::Playing);
-        return;
-    } else {
-        None
-    };
-
-    let Some(choice) = action else { return };
+    let Some(PlayerAction::Swipe(choice)) = pending.action else { return };
+    pending.action = None;
 
     match choice {
         SwipeChoice::Yes => {
-            // CAST: play the card into the story
+            // CAST: place the card onto the crafting table
             if let Some(entity) = hand.play_selected() {
-                if let Ok((word, channel, _edges, _power)) = words.get(entity) {
+                if let Ok((_, word, channel, symbol, links, setting, power)) = words.get(entity) {
+                    moment.place(entity, &word.word, *symbol);
+
                     // Update trail
                     trail.current_word = Some(entity);
                     trail.swipe_history.push(SwipeChoice::Yes);
-
-                    // Update story
-                    slide.story_text = get_play_text(&word.word);
+                    trail.cast_words.push(word.word.clone());
+
+                    // Update story — the moment so far, then the word's
+                    // beat as the active genre tells it, richer for a
+                    // word the student already knows
+                    let name = |w: &str| words.iter()
+                        .find(|(_, card, ..)| card.word == w)
+                        .map_or_else(|| w.to_string(), |(_, card, ..)| card.name.clone());
+                    let beat = recycler::recycled_story(&setting.story_text, word, power, &locale, name);
+                    slide.story_text = format!("{}\n\n{}", locale.moment_text(&moment, name), beat);
+                    slide.setting_mood = setting.mood.clone();
                     slide.ready_for_input = true;
 
-                    // Advance mastery
+                    // Advance mastery — casting, plus any partner already cast
                     sheet.engage_channel(channel);
-                    spellbook.record_encounter(&word.word, *channel);
+                    progress.write(SpellProgress { entity, kind: ProgressKind::Cast });
+                    progress.write_batch(synergy_discoveries(
+                        entity, &word.word, links, &trail.cast_words,
+                        |partner| words.iter()
+                            .find(|(_, w, ..)| w.word == partner)
+                            .map(|(e, ..)| e),
+                    ));
+                } else {
+                    discard.cards.push(entity);
                 }
 
-                discard.cards.push(entity);
-
                 // Draw to refill hand
                 next_state.set(GameState::Drawing);
             }
         },
         SwipeChoice::No => {
-            // DISCARD: skip this card, draw a new one
+            // DISCARD: skip this card, draw a new one — the Recycler
+            // may bring it back before the session ends
             if let Some(entity) = hand.play_selected() {
-                if let Ok((word, _ch, _edges, _power)) = words.get(entity) {
+                if words.contains(entity) {
                     trail.swipe_history.push(SwipeChoice::No);
+                    progress.write(SpellProgress { entity, kind: ProgressKind::Skipped });
                 }
                 discard.cards.push(entity);
                 next_state.set(GameState::Drawing);
@@ -182,6 +303,10 @@ pub fn handle_card_action(
         },
         SwipeChoice::Deeper => {
             // DIG DEEPER: show depth overlay for selected card
+            if let Some(entity) = hand.selected.and_then(|i| hand.cards.get(i).copied()) {
+                trail.swipe_history.push(SwipeChoice::Deeper);
+                progress.write(SpellProgress { entity, kind: ProgressKind::Deeper });
+            }
             slide.depth_showing = true;
             next_state.set(GameState::DepthView);
         },
@@ -189,73 +314,147 @@ pub fn handle_card_action(
 }
 
 /// System: dismiss depth view and return to card selected state.
+/// Any action closes it — a tap, a key, a swipe. An Answer keeps
+/// what the student typed, in the SpellBook against the word.
 pub fn dismiss_depth(
-    keys: Res<ButtonInput<KeyCode>>,
+    mut pending: ResMut<PendingAction>,
+    mut draft: ResMut<JournalDraft>,
     mut slide: ResMut<CurrentSlide>,
+    mut progress: MessageWriter<SpellProgress>,
+    hand: Res<Hand>,
     mut next_state: ResMut<NextState<GameState>>,
     state: Res<State<GameState>>,
 ) {
     if *state.get() != GameState::DepthView { return; }
 
-    if keys.just_pressed(KeyCode::Escape) || keys.just_pressed(KeyCode::Space)
-        || keys.just_pressed(KeyCode::ArrowUp)
-    {
-        slide.depth_showing = false;
-        next_state.set(GameState::CardSelected);
+    let Some(action) = pending.action.take() else { return };
+    let text = std::mem::take(&mut draft.text);
+    let entity = hand.selected.and_then(|i| hand.cards.get(i).copied());
+    if let (PlayerAction::Answer, Some(entity)) = (action, entity) {
+        let text = text.trim();
+        if !text.is_empty() {
+            progress.write(SpellProgress { entity, kind: ProgressKind::Journaled { text: text.to_string() } });
+        }
     }
+    slide.depth_showing = false;
+    next_state.set(GameState::CardSelected);
 }
 
-// ─── SYNERGY DETECTION ──────────────────────────────────────
+// ─── STORY MOMENTS ──────────────────────────────────────────
 
-/// System: check for synergies between cards in the current hand.
-pub fn detect_synergies(
-    hand: Res<Hand>,
-    words: Query<(&WordCard, &SynergyLinks)>,
-    mut synergies: ResMut<ActiveSynergies>,
+/// Close the current moment: weave its resonances into threads,
+/// record it in the trail's current chapter, and send its cards to
+/// the discard pile.
+fn close_moment(
+    moment: &mut StoryMoment,
+    trail: &mut StudentTrail,
+    discard: &mut DiscardPile,
+    threads: &mut ResonanceThreads,
 ) {
-    synergies.synergies.clear();
-
-    // Build a set of words currently in hand
-    let hand_words: Vec<(Entity, String)> = hand.cards.iter()
-        .filter_map(|e| words.get(*e).ok().map(|(w, _)| (*e, w.word.clone())))
-        .collect();
-
-    // Check each card's synergy partners against the hand
-    for (entity, _word_name) in &hand_words {
-        if let Ok((word, links)) = words.get(*entity) {
-            for entry in &links.links {
-                if hand_words.iter().any(|(_, w)| w == &entry.partner) {
-                    synergies.synergies.push(ActiveSynergy {
-                        source_word: word.word.clone(),
-                        target_word: entry.partner.clone(),
-                        bonus: 2,
-                        flavor_text: format!(
-                            "{} + {} resonate together",
-                            word.word, entry.partner
-                        ),
-                    });
-                }
-            }
-        }
+    if moment.is_empty() {
+        return;
+    }
+    for (a, b, synergy_type) in &moment.resonances {
+        threads.record(a, b, *synergy_type, &moment.generated_text);
     }
+    discard.cards.extend(moment.placed_entities());
+    trail.chapter_mut().moments.push(std::mem::take(moment));
 }
 
-// ─── HELPERS ────────────────────────────────────────────────
-
-/// Get the story text when a card is played.
-/// In the future this comes from the curriculum JSON or LLM.
-fn get_play_text(word: &str) -> String {
-    match word {
-        "Presence" => "You step through the stone archway. The warm light \
-            envelops you. For a moment, everything is still.".to_string(),
-        "Bias" => "The glass trees reflect a version of you that isn't \
-            quite right. What are you not seeing?".to_string(),
-        "Patience" => "The garden fountain whispers. A feather lands on \
-            the water. You realize you've been holding your breath.".to_string(),
-        "Resilience" => "The bridge sways beneath your feet. The canyon \
-            wind howls. You take another step forward.".to_string(),
-        "Clarity" => "Above the clouds, the whole world is a map below \
-            you. Everything connects. Everything makes sense.".to_string(),
-        _ => format!("You cast the spell of {}. The world shifts around you.", word),
+/// System: a full table completes the moment; the next one starts empty.
+/// Runs after synergy resolution so the final card's resonance counts.
+pub fn complete_story_moment(
+    mut moment: ResMut<StoryMoment>,
+    mut trail: ResMut<StudentTrail>,
+    mut discard: ResMut<DiscardPile>,
+    mut threads: ResMut<ResonanceThreads>,
+) {
+    if moment.is_complete() {
+        close_moment(&mut moment, &mut trail, &mut discard, &mut threads);
+    }
+}
+
+/// System: keep a half-built moment when the session ends.
+pub fn close_story_moment(
+    mut moment: ResMut<StoryMoment>,
+    mut trail: ResMut<StudentTrail>,
+    mut discard: ResMut<DiscardPile>,
+    mut threads: ResMut<ResonanceThreads>,
+) {
+    close_moment(&mut moment, &mut trail, &mut discard, &mut threads);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edges(yes: &[&str], no: &[&str]) -> WordEdges {
+        WordEdges {
+            yes_targets: yes.iter().map(|s| s.to_string()).collect(),
+            no_targets: no.iter().map(|s| s.to_string()).collect(),
+            low_focus_targets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_draw_weight_prefers_dag_targets_and_common_cards() {
+        let current = edges(&["Bias"], &["Patience"]);
+        let yes = draw_weight("Bias", Stage::EdgeLord, Some(&current), false);
+        let no = draw_weight("Patience", Stage::EdgeLord, Some(&current), false);
+        let other = draw_weight("Clarity", Stage::EdgeLord, Some(&current), false);
+        assert!(yes > no && no > other);
+        assert!(draw_weight("X", Stage::Hero, None, false) > draw_weight("X", Stage::BestSelf, None, false));
+    }
+
+    #[test]
+    fn test_special_branches_pull_only_at_low_focus() {
+        let mut current = edges(&[], &[]);
+        current.low_focus_targets.push("Clarity".to_string());
+        let steady = draw_weight("Clarity", Stage::BestSelf, Some(&current), false);
+        let strained = draw_weight("Clarity", Stage::BestSelf, Some(&current), true);
+        assert_eq!(strained, steady * LOW_FOCUS_PULL);
+    }
+
+    #[test]
+    fn test_prerequisites_unlock_after_cast() {
+        let mut prerequisites = HashMap::new();
+        prerequisites.insert("Clarity".to_string(), vec!["Resilience".to_string()]);
+
+        assert!(prerequisites_met("Presence", &prerequisites, &[]));
+        assert!(!prerequisites_met("Clarity", &prerequisites, &[]));
+        assert!(prerequisites_met("Clarity", &prerequisites, &["Resilience".to_string()]));
+    }
+
+    #[test]
+    fn test_an_answer_is_kept_against_the_word() {
+        use crate::dag::CurriculumSource;
+        use crate::sim::{harness::{act, deal, state}, headless_app};
+        let mut app = headless_app(3, &CurriculumSource::Demo);
+        app.insert_resource(ReviewClock::Fixed(1_700_000_000));
+        deal(&mut app);
+        act(&mut app, PlayerAction::Select(0));
+        act(&mut app, PlayerAction::Swipe(SwipeChoice::Deeper));
+        assert_eq!(state(&app), GameState::DepthView);
+
+        app.world_mut().resource_mut::<JournalDraft>().text = "  Being here, not elsewhere. ".to_string();
+        act(&mut app, PlayerAction::Answer);
+        assert_eq!(state(&app), GameState::CardSelected);
+        assert!(app.world().resource::<JournalDraft>().text.is_empty());
+
+        let book = app.world().resource::<SpellBook>();
+        let entry = book.entries.iter().find(|e| !e.journal.is_empty()).unwrap();
+        assert_eq!(entry.times_explored_deeper, 2);
+        assert_eq!(entry.journal, vec![JournalEntry {
+            text: "Being here, not elsewhere.".to_string(),
+            written_at: 1_700_000_000,
+            mastery: MasteryLevel::Experienced,
+        }]);
+        assert_eq!(app.world().resource::<CharacterSheet>().total_deeper_swipes, 2);
+
+        // Closing without an answer keeps nothing
+        act(&mut app, PlayerAction::Swipe(SwipeChoice::Deeper));
+        act(&mut app, PlayerAction::Back);
+        let book = app.world().resource::<SpellBook>();
+        assert_eq!(book.entries.iter().map(|e| e.journal.len()).sum::<usize>(), 1);
     }
 }
@@ -0,0 +1,162 @@
+// Daydream Engine — Hero Focus
+// The student (the Author) may play any card at any time. The Hero
+// inside the story may not: every word carried into a moment costs
+// Focus by its Stage.
+//
+//   Steady    → the story reads as written
+//   Strained  → the story shows the effort; special branches open
+//   Exhausted → the branch locks; a rest point restores the Hero
+//
+// The rest point is somatic: slow, paced breaths (one tap or key per
+// breath). Rushing doesn't count — the pace is the point.
+
+use bevy::prelude::*;
+use crate::components::*;
+use crate::input::PendingAction;
+use crate::locale::ActiveLocale;
+use crate::mastery::{ProgressKind, SpellProgress};
+
+/// Tunables for Focus and rest.
+#[derive(Resource, Clone, Debug)]
+pub struct FocusRules {
+    pub max_focus: u32,
+    /// Paced breaths needed to clear a rest point.
+    pub breaths_to_rest: u32,
+    /// Minimum seconds between breaths for one to count.
+    pub breath_pace_secs: f32,
+}
+
+impl Default for FocusRules {
+    fn default() -> Self {
+        Self {
+            max_focus: 10,
+            breaths_to_rest: 3,
+            breath_pace_secs: 2.0,
+        }
+    }
+}
+
+/// Resource: progress through the current rest point.
+#[derive(Resource, Default, Debug)]
+pub struct RestPoint {
+    pub breaths: u32,
+    pub last_breath: Option<f32>,
+    /// The last breath came too soon and didn't count.
+    pub rushed: bool,
+}
+
+/// System: a fresh Hero for a fresh session.
+pub fn reset_hero(mut commands: Commands, rules: Res<FocusRules>) {
+    commands.insert_resource(CharacterState::new(rules.max_focus));
+}
+
+/// System: the Hero pays for each cast. The moment's story shows the
+/// strain, and an exhausted Hero rests instead of drawing on.
+/// Runs right after card actions, so it can redirect their transition.
+pub fn spend_focus(
+    mut progress: MessageReader<SpellProgress>,
+    stages: Query<&Stage>,
+    mut hero: ResMut<CharacterState>,
+    mut moment: ResMut<StoryMoment>,
+    mut slide: ResMut<CurrentSlide>,
+    mut next_state: ResMut<NextState<GameState>>,
+    locale: Res<ActiveLocale>,
+) {
+    let mut spent = false;
+    for event in progress.read() {
+        if event.kind != ProgressKind::Cast {
+            continue;
+        }
+        let cost = stages.get(event.entity).map_or(1, Stage::focus_cost);
+        hero.spend(cost);
+        spent = true;
+    }
+    if !spent {
+        return;
+    }
+
+    let tier = hero.tier();
+    if moment.focus != tier {
+        moment.focus = tier;
+        moment.generated_text = moment.assemble_text();
+    }
+    if let Some(line) = locale.focus_line(tier) {
+        slide.story_text.push_str("\n\n");
+        slide.story_text.push_str(&line);
+    }
+    if hero.exhausted {
+        info!("The hero is exhausted — resting before the story goes on");
+        next_state.set(GameState::Resting);
+    }
+}
+
+/// System: start a rest point from zero breaths.
+pub fn begin_rest(mut rest: ResMut<RestPoint>) {
+    *rest = RestPoint::default();
+}
+
+/// System: count paced breaths; enough of them restore the Hero and
+/// let drawing continue.
+pub fn take_rest_breaths(
+    mut pending: ResMut<PendingAction>,
+    time: Res<Time>,
+    rules: Res<FocusRules>,
+    mut rest: ResMut<RestPoint>,
+    mut hero: ResMut<CharacterState>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if pending.action.take().is_none() {
+        return;
+    }
+
+    let now = time.elapsed_secs();
+    let paced = rest.last_breath.is_none_or(|last| now - last >= rules.breath_pace_secs);
+    rest.last_breath = Some(now);
+    rest.rushed = !paced;
+    if !paced {
+        return;
+    }
+
+    rest.breaths += 1;
+    if rest.breaths >= rules.breaths_to_rest {
+        hero.rest();
+        info!("The hero rests. Focus restored to {}", hero.focus);
+        next_state.set(GameState::Drawing);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_focus_tiers_follow_spending() {
+        let mut hero = CharacterState::new(9);
+        assert_eq!(hero.tier(), FocusTier::Steady);
+
+        hero.spend(Stage::BestSelf.focus_cost() + Stage::Outlaw.focus_cost());
+        assert_eq!(hero.focus, 3);
+        assert_eq!(hero.tier(), FocusTier::Strained);
+        assert!(hero.is_low());
+
+        // Overspending bottoms out at zero and exhausts
+        hero.spend(Stage::BestSelf.focus_cost());
+        assert_eq!(hero.focus, 0);
+        assert!(hero.exhausted);
+
+        hero.rest();
+        assert_eq!(hero.focus, 9);
+        assert_eq!(hero.tier(), FocusTier::Steady);
+    }
+
+    #[test]
+    fn test_strain_shows_in_the_moment() {
+        let mut moment = StoryMoment::default();
+        let entity = Entity::from_raw_u32(1).unwrap();
+        moment.place(entity, "Presence", Symbol::Stone);
+        assert!(!moment.generated_text.contains("breath"));
+
+        moment.focus = FocusTier::Strained;
+        assert!(moment.assemble_text().contains("breath comes heavier"));
+    }
+}
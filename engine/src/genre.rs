@@ -0,0 +1,434 @@
+// Daydream Engine — Genre Skinning
+// The same VAAM DAG experienced through different Story DAGs.
+//
+// A GenreTemplate overrides how each word *feels* — its story beat,
+// mood and backdrop art — without touching what it teaches. Channels,
+// stages, edges and depth prompts never change with the genre.
+//
+// Fallbacks, per word:
+//   story_text: translated story_text → the genre's translated
+//               override → its translated fallback_text → genre
+//               override → curriculum story_text → genre fallback_text
+//   mood:       genre override → curriculum mood
+//
+// A template's `translations` tell its frame and story beats in other
+// languages, by locale id; the student's locale picks one the way it
+// picks a curriculum word's translation.
+//
+// A Setting card changes the weather for everything after it: once
+// cast, its mood and backdrop become the Atmosphere, and every other
+// word's Setting takes them until another Setting card is cast.
+//
+// Five templates are bundled (arcana/genres/*.json). A curriculum file
+// can ship its own in a `genres/` folder beside it, replacing the
+// bundled template of the same genre.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use crate::components::*;
+use crate::dag::CurriculumSource;
+use crate::locale::ActiveLocale;
+use crate::mastery::{ProgressKind, SpellProgress};
+
+/// The genres a student can choose from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Genre {
+    #[default]
+    Fantasy,
+    SciFi,
+    Noir,
+    Ghibli,
+    Cyberpunk,
+}
+
+impl Genre {
+    pub const ALL: [Genre; 5] = [Genre::Fantasy, Genre::SciFi, Genre::Noir, Genre::Ghibli, Genre::Cyberpunk];
+
+    /// Identifier used in templates and on the command line.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Genre::Fantasy   => "fantasy",
+            Genre::SciFi     => "sci-fi",
+            Genre::Noir      => "noir",
+            Genre::Ghibli    => "ghibli",
+            Genre::Cyberpunk => "cyberpunk",
+        }
+    }
+
+    /// Parse an id, tolerating "scifi" / "sci_fi" spellings.
+    pub fn parse(s: &str) -> Option<Genre> {
+        let id = s.trim().to_lowercase().replace('_', "-");
+        match id.as_str() {
+            "fantasy"            => Some(Genre::Fantasy),
+            "sci-fi" | "scifi"   => Some(Genre::SciFi),
+            "noir"               => Some(Genre::Noir),
+            "ghibli"             => Some(Genre::Ghibli),
+            "cyberpunk"          => Some(Genre::Cyberpunk),
+            _ => None,
+        }
+    }
+
+    /// Template file name, e.g. "sci_fi.json".
+    fn file_name(&self) -> String {
+        format!("{}.json", self.id().replace('-', "_"))
+    }
+
+    /// The template compiled into the engine.
+    fn bundled_json(&self) -> &'static str {
+        match self {
+            Genre::Fantasy   => include_str!("../arcana/genres/fantasy.json"),
+            Genre::SciFi     => include_str!("../arcana/genres/sci_fi.json"),
+            Genre::Noir      => include_str!("../arcana/genres/noir.json"),
+            Genre::Ghibli    => include_str!("../arcana/genres/ghibli.json"),
+            Genre::Cyberpunk => include_str!("../arcana/genres/cyberpunk.json"),
+        }
+    }
+}
+
+// ─── TEMPLATE FORMAT ────────────────────────────────────────
+
+/// How one word is told in a genre. Every field is optional.
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct WordOverride {
+    pub story_text: Option<String>,
+    pub mood: Option<String>,
+    pub setting_image: Option<String>,
+}
+
+/// A genre template's text in one language.
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct GenreTranslation {
+    pub frame: Option<String>,
+    pub fallback_text: Option<String>,
+    /// Story beats by word.
+    pub story_text: HashMap<String, String>,
+}
+
+/// JSON schema for a genre template.
+#[derive(Deserialize, Clone, Debug, Default)]
+pub struct GenreTemplate {
+    pub genre: String,
+    #[serde(default)]
+    pub name: String,
+    /// The story frame: "You are a detective uncovering hidden meanings."
+    #[serde(default)]
+    pub frame: String,
+    /// Used when neither the genre nor the curriculum tells this word;
+    /// `{word}` is replaced by the word.
+    #[serde(default)]
+    pub fallback_text: String,
+    #[serde(default)]
+    pub word_overrides: HashMap<String, WordOverride>,
+    /// The template in other languages, by locale id ("es").
+    #[serde(default)]
+    pub translations: HashMap<String, GenreTranslation>,
+}
+
+impl GenreTemplate {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+
+    /// The story frame in the student's language.
+    pub fn frame(&self, locale: &ActiveLocale) -> String {
+        locale.translation(&self.translations)
+            .and_then(|t| t.frame.clone())
+            .unwrap_or_else(|| self.frame.clone())
+    }
+
+    /// The story beat for a word: in the student's language if the
+    /// genre tells it there, otherwise the override, then the
+    /// curriculum's own, then the genre's generic line. `name` is the
+    /// word as the student reads it.
+    pub fn story_text(&self, word: &str, name: &str, curriculum_text: &str, locale: &ActiveLocale) -> String {
+        if let Some(translation) = locale.translation(&self.translations) {
+            if let Some(text) = translation.story_text.get(word) {
+                return text.clone();
+            }
+            if let Some(text) = &translation.fallback_text {
+                return text.replace("{word}", name);
+            }
+        }
+        if let Some(text) = self.word_overrides.get(word).and_then(|o| o.story_text.as_ref()) {
+            return text.clone();
+        }
+        if !curriculum_text.is_empty() {
+            return curriculum_text.to_string();
+        }
+        if self.fallback_text.is_empty() {
+            locale.format("genre.fallback", &[("word", name)])
+        } else {
+            self.fallback_text.replace("{word}", name)
+        }
+    }
+
+    /// The mood for a word: override, then the curriculum's.
+    pub fn mood(&self, word: &str, curriculum_mood: &str) -> String {
+        self.word_overrides.get(word)
+            .and_then(|o| o.mood.clone())
+            .unwrap_or_else(|| curriculum_mood.to_string())
+    }
+
+    /// Backdrop art for a word, if the genre has any.
+    pub fn setting_image(&self, word: &str) -> Option<String> {
+        self.word_overrides.get(word).and_then(|o| o.setting_image.clone())
+    }
+
+    /// Overridden words the curriculum doesn't have (likely typos).
+    pub fn unknown_words<'a>(&'a self, words: &[&str]) -> Vec<&'a str> {
+        let mut unknown: Vec<&str> = self.word_overrides.keys()
+            .map(String::as_str)
+            .filter(|w| !words.contains(w))
+            .collect();
+        unknown.sort();
+        unknown
+    }
+}
+
+// ─── ACTIVE GENRE ───────────────────────────────────────────
+
+/// Resource: the genre the student is playing in.
+#[derive(Resource, Clone, Debug)]
+pub struct ActiveGenre {
+    pub genre: Genre,
+    pub template: GenreTemplate,
+}
+
+impl ActiveGenre {
+    /// Load a genre's template: from the curriculum's `genres/` folder
+    /// if present, otherwise the bundled one.
+    pub fn load(genre: Genre, source: &CurriculumSource) -> Self {
+        let template = local_template(genre, source)
+            .unwrap_or_else(|| GenreTemplate::from_json(genre.bundled_json()).unwrap_or_else(|e| {
+                error!("Bundled {} template is malformed: {}", genre.id(), e);
+                GenreTemplate::default()
+            }));
+        Self { genre, template }
+    }
+
+    /// `--genre=noir` from the command line, or Fantasy.
+    pub fn from_args(source: &CurriculumSource) -> Self {
+        let genre = std::env::args()
+            .find_map(|a| a.strip_prefix("--genre=").map(str::to_string))
+            .map(|id| Genre::parse(&id).unwrap_or_else(|| {
+                let known: Vec<&str> = Genre::ALL.iter().map(Genre::id).collect();
+                warn!("Unknown genre '{}' (try {}), playing Fantasy", id, known.join(", "));
+                Genre::Fantasy
+            }))
+            .unwrap_or_default();
+        Self::load(genre, source)
+    }
+
+    /// The skinned Setting for a word in this genre.
+    pub fn setting_for(&self, word: &WordCard, style: &CardStyle, channel: Channel, locale: &ActiveLocale) -> Setting {
+        Setting {
+            mood: self.template.mood(&word.word, &style.mood),
+            genre: self.genre.id().to_string(),
+            background_color: channel.background_color(),
+            story_text: if word.localized_story {
+                word.story_text.clone()
+            } else {
+                self.template.story_text(&word.word, &word.name, &word.story_text, locale)
+            },
+            setting_image: self.template.setting_image(&word.word),
+        }
+    }
+}
+
+/// A template beside a curriculum file: `<dir>/genres/<genre>.json`.
+fn local_template(genre: Genre, source: &CurriculumSource) -> Option<GenreTemplate> {
+    let CurriculumSource::File(path) = source else { return None };
+    let file: PathBuf = path.parent()?.join("genres").join(genre.file_name());
+    let json = std::fs::read_to_string(&file).ok()?;
+    match GenreTemplate::from_json(&json) {
+        Ok(template) => {
+            if Genre::parse(&template.genre) != Some(genre) {
+                warn!("{} says it is '{}', but is used as {}", file.display(), template.genre, genre.id());
+            }
+            info!("Genre template loaded from {}", file.display());
+            Some(template)
+        }
+        Err(e) => {
+            warn!("{}: {} — using the bundled {} template", file.display(), e, genre.id());
+            None
+        }
+    }
+}
+
+/// Resource: the mood and backdrop left by the last Setting card cast
+/// this session. Empty until one is.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct Atmosphere {
+    /// The Setting card that set it
+    pub word: Option<String>,
+    pub mood: String,
+    pub background_color: Color,
+}
+
+impl Atmosphere {
+    /// Lay the atmosphere over a word's Setting. Setting cards keep
+    /// their own — each one is a place of its own.
+    pub fn cover(&self, setting: &mut Setting, card_type: CardType) {
+        if self.word.is_none() || card_type == CardType::Setting {
+            return;
+        }
+        setting.mood.clone_from(&self.mood);
+        setting.background_color = self.background_color;
+    }
+}
+
+/// System: re-skin every word when the student switches genre.
+/// Words are skinned at spawn, so this only runs on a change.
+pub fn reskin_words(
+    genre: Res<ActiveGenre>,
+    locale: Res<ActiveLocale>,
+    atmosphere: Res<Atmosphere>,
+    mut words: Query<(&WordCard, &CardStyle, &Channel, &CardType, &mut Setting)>,
+) {
+    if !genre.is_changed() || genre.is_added() {
+        return;
+    }
+    for (word, style, channel, card_type, mut setting) in &mut words {
+        *setting = genre.setting_for(word, style, *channel, &locale);
+        atmosphere.cover(&mut setting, *card_type);
+    }
+    info!("Now playing as {}", genre.template.name);
+}
+
+/// System: a cast Setting card becomes the Atmosphere, and every
+/// other word's Setting takes its mood and backdrop.
+pub fn shift_atmosphere(
+    mut progress: MessageReader<SpellProgress>,
+    mut atmosphere: ResMut<Atmosphere>,
+    mut words: Query<(&WordCard, &CardType, &mut Setting)>,
+) {
+    for event in progress.read() {
+        if event.kind != ProgressKind::Cast {
+            continue;
+        }
+        let Ok((word, &CardType::Setting, setting)) = words.get(event.entity) else { continue };
+        *atmosphere = Atmosphere {
+            word: Some(word.word.clone()),
+            mood: setting.mood.clone(),
+            background_color: setting.background_color,
+        };
+        info!("{} sets the scene: {}", word.word, atmosphere.mood);
+        for (_, card_type, mut setting) in &mut words {
+            atmosphere.cover(&mut setting, *card_type);
+        }
+    }
+}
+
+/// System: warn about overrides for words this curriculum lacks.
+/// Only when the template was written for it (some overrides match),
+/// so the bundled demo skins don't nag every custom curriculum.
+pub fn check_genre_coverage(genre: Res<ActiveGenre>, words: Query<&WordCard, Added<WordCard>>) {
+    if words.is_empty() {
+        return;
+    }
+    let names: Vec<&str> = words.iter().map(|w| w.word.as_str()).collect();
+    let unknown = genre.template.unknown_words(&names);
+    if !unknown.is_empty() && unknown.len() < genre.template.word_overrides.len() {
+        warn!("{} template overrides words not in this curriculum: {}", genre.genre.id(), unknown.join(", "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_templates_parse_and_match_their_genre() {
+        for genre in Genre::ALL {
+            let template = GenreTemplate::from_json(genre.bundled_json()).unwrap();
+            assert_eq!(Genre::parse(&template.genre), Some(genre));
+            assert!(!template.fallback_text.is_empty());
+            let spanish = &template.translations["es"];
+            assert!(spanish.frame.is_some() && spanish.fallback_text.is_some());
+            let mut told: Vec<&String> = spanish.story_text.keys().collect();
+            let mut overridden: Vec<&String> = template.word_overrides.keys().collect();
+            told.sort();
+            overridden.sort();
+            assert_eq!(told, overridden, "{} tells different words in Spanish", genre.id());
+        }
+    }
+
+    #[test]
+    fn test_story_text_falls_back_in_order() {
+        let template = GenreTemplate::from_json(r#"{
+            "genre": "noir",
+            "fallback_text": "You note {word} down.",
+            "word_overrides": { "Bias": { "story_text": "The witness saw what she expected." } }
+        }"#).unwrap();
+        let en = ActiveLocale::default();
+
+        assert_eq!(template.story_text("Bias", "Bias", "curriculum text", &en), "The witness saw what she expected.");
+        assert_eq!(template.story_text("Clarity", "Clarity", "curriculum text", &en), "curriculum text");
+        assert_eq!(template.story_text("Clarity", "Clarity", "", &en), "You note Clarity down.");
+        // No mood override: keep the curriculum's
+        assert_eq!(template.mood("Bias", "mysterious"), "mysterious");
+    }
+
+    #[test]
+    fn test_a_translated_genre_tells_the_whole_story_in_that_language() {
+        let template = GenreTemplate::from_json(r#"{
+            "genre": "noir",
+            "frame": "You are a detective.",
+            "fallback_text": "You note {word} down.",
+            "word_overrides": { "Bias": { "story_text": "The witness saw what she expected." } },
+            "translations": { "es": {
+                "frame": "Eres detective.",
+                "fallback_text": "Apuntas {word}.",
+                "story_text": { "Bias": "La testigo vio lo que esperaba ver." }
+            } }
+        }"#).unwrap();
+        let mexico = ActiveLocale::load("es-MX", &CurriculumSource::Demo);
+
+        assert_eq!(template.frame(&mexico), "Eres detective.");
+        assert_eq!(template.story_text("Bias", "Sesgo", "", &mexico), "La testigo vio lo que esperaba ver.");
+        // The genre's Spanish line, not the English curriculum text
+        assert_eq!(template.story_text("Clarity", "Claridad", "curriculum text", &mexico), "Apuntas Claridad.");
+        assert_eq!(template.frame(&ActiveLocale::default()), "You are a detective.");
+
+        // A genre with nothing to say leaves it to the catalog
+        assert_eq!(GenreTemplate::default().story_text("Clarity", "Claridad", "", &mexico),
+            "Lanzas el hechizo de Claridad. El mundo cambia a tu alrededor.");
+    }
+
+    #[test]
+    fn test_genre_ids_round_trip() {
+        for genre in Genre::ALL {
+            assert_eq!(Genre::parse(genre.id()), Some(genre));
+        }
+        assert_eq!(Genre::parse("Sci_Fi"), Some(Genre::SciFi));
+        assert_eq!(Genre::parse("western"), None);
+    }
+
+    #[test]
+    fn test_a_setting_card_changes_the_atmosphere() {
+        let mut app = App::new();
+        app.add_message::<SpellProgress>()
+            .init_resource::<Atmosphere>()
+            .add_systems(Update, shift_atmosphere);
+        let storm_setting = Setting { mood: "tense".to_string(), background_color: Color::BLACK, ..default() };
+        let storm = app.world_mut().spawn((WordCard::default(), CardType::Setting, storm_setting)).id();
+        let spell = app.world_mut().spawn((WordCard::default(), CardType::Spell, Setting::default())).id();
+        let shore = app.world_mut().spawn((WordCard::default(), CardType::Setting, Setting::default())).id();
+
+        app.world_mut().write_message(SpellProgress { entity: spell, kind: ProgressKind::Cast });
+        app.update();
+        assert!(app.world().resource::<Atmosphere>().word.is_none(), "only Setting cards set the scene");
+
+        app.world_mut().write_message(SpellProgress { entity: storm, kind: ProgressKind::Cast });
+        app.update();
+        let spell_setting = app.world().get::<Setting>(spell).unwrap();
+        assert_eq!(spell_setting.mood, "tense");
+        assert_eq!(spell_setting.background_color, Color::BLACK);
+        // Another Setting card keeps its own scene
+        assert_eq!(app.world().get::<Setting>(shore).unwrap().mood, "calm");
+    }
+}
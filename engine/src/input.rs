@@ -1,105 +1,347 @@
 // Daydream Engine — Input: Swipe Gesture Detection
-// Right = Yes, Left = No, Down = Dig Deeper
+// Right = Yes, Left = No, Down = Dig Deeper, Up = Back
+// Tap a hand card to select it; tap elsewhere to go back.
+// In the depth view the keyboard writes: typed text answers the depth
+// question, Enter keeps the answer, Escape closes without it.
+//
+// Keyboard, mouse and touch all feed one PendingAction resource;
+// the game systems consume it without caring where it came from.
 // Uses a shared Resource for inter-system communication (Bevy 0.18 compatible)
 
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 use crate::components::*;
 
-/// Tracks an active drag gesture
+/// Something the student asked for, independent of the device.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum PlayerAction {
+    /// Pick the hand card at this index.
+    Select(usize),
+    /// Cast / skip / dig deeper on the selected card.
+    Swipe(SwipeChoice),
+    /// Step back: deselect a card, or close the depth view.
+    Back,
+    /// Keep the JournalDraft as the answer and close the depth view.
+    Answer,
+}
+
+/// Pending player action — consumed by the game systems this frame,
+/// discarded at the end of the frame if nothing wanted it.
+#[derive(Resource, Default)]
+pub struct PendingAction {
+    pub action: Option<PlayerAction>,
+}
+
+impl PendingAction {
+    /// Take the pending selection, if that's what's waiting.
+    pub fn take_select(&mut self) -> Option<usize> {
+        match self.action {
+            Some(PlayerAction::Select(i)) => {
+                self.action = None;
+                Some(i)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The answer being typed in the depth view. Read by the rules when
+/// an Answer action arrives.
+#[derive(Resource, Default, Debug)]
+pub struct JournalDraft {
+    pub text: String,
+}
+
+/// Longest answer kept, in characters.
+pub const MAX_ANSWER_CHARS: usize = 280;
+
+/// Tracks an active drag gesture (mouse or a single touch)
 #[derive(Resource, Default)]
 pub struct DragState {
     pub active: bool,
+    /// The finger being tracked; None for the mouse.
+    pub touch_id: Option<u64>,
     pub start_pos: Vec2,
     pub current_pos: Vec2,
+    /// Smoothed pointer velocity in pixels per second (window coordinates).
+    pub velocity: Vec2,
+    pub last_moved: f32,
 }
 
-/// Pending swipe action — checked each frame by the render system
-#[derive(Resource, Default)]
-pub struct PendingSwipe {
-    pub direction: Option<SwipeChoice>,
+/// Minimum drag distance to register as a slow swipe
+const SWIPE_THRESHOLD: f32 = 80.0;
+/// A quick flick counts as a swipe from this distance...
+const FLICK_MIN_DISTANCE: f32 = 30.0;
+/// ...when the pointer leaves at this speed (px/s)
+const FLICK_VELOCITY: f32 = 600.0;
+/// A press that moves less than this is a tap
+const TAP_SLOP: f32 = 12.0;
+/// While the pointer holds still, its velocity halves this often (s),
+/// so a flick that stops before release isn't a flick any more
+const VELOCITY_HALF_LIFE: f32 = 0.03;
+
+/// What a finished drag meant.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Gesture {
+    Tap,
+    Swipe(PlayerAction),
+    /// Too long for a tap, too short and slow for a swipe.
+    Nothing,
 }
 
-/// Minimum drag distance to register as a swipe
-const SWIPE_THRESHOLD: f32 = 80.0;
+/// Classify a drag from its total movement and release velocity
+/// (window coordinates: +y is down the screen).
+pub fn classify_gesture(delta: Vec2, velocity: Vec2) -> Gesture {
+    let distance = delta.length();
+    if distance < TAP_SLOP {
+        return Gesture::Tap;
+    }
+
+    let direction = if distance >= SWIPE_THRESHOLD {
+        delta
+    } else if distance >= FLICK_MIN_DISTANCE && velocity.length() >= FLICK_VELOCITY {
+        velocity
+    } else {
+        return Gesture::Nothing;
+    };
+
+    let action = if direction.x.abs() > direction.y.abs() {
+        if direction.x > 0.0 { PlayerAction::Swipe(SwipeChoice::Yes) } else { PlayerAction::Swipe(SwipeChoice::No) }
+    } else if direction.y > 0.0 {
+        PlayerAction::Swipe(SwipeChoice::Deeper)
+    } else {
+        PlayerAction::Back
+    };
+    Gesture::Swipe(action)
+}
 
-/// System: detect mouse drag start
+/// System: detect mouse or touch drag start
 pub fn drag_start(
     mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
     windows: Query<&Window>,
+    time: Res<Time>,
     mut drag: ResMut<DragState>,
-    slide: Res<CurrentSlide>,
 ) {
-    if !slide.ready_for_input || slide.depth_showing {
-        return;
-    }
+    if drag.active { return; }
 
-    if mouse.just_pressed(MouseButton::Left) {
-        if let Some(window) = windows.iter().next() {
-            if let Some(pos) = window.cursor_position() {
-                drag.active = true;
-                drag.start_pos = pos;
-                drag.current_pos = pos;
-            }
-        }
+    let press = if let Some(touch) = touches.iter_just_pressed().next() {
+        Some((Some(touch.id()), touch.position()))
+    } else if mouse.just_pressed(MouseButton::Left) {
+        windows.iter().next()
+            .and_then(|w| w.cursor_position())
+            .map(|pos| (None, pos))
+    } else {
+        None
+    };
+
+    if let Some((touch_id, pos)) = press {
+        *drag = DragState {
+            active: true,
+            touch_id,
+            start_pos: pos,
+            current_pos: pos,
+            velocity: Vec2::ZERO,
+            last_moved: time.elapsed_secs(),
+        };
     }
 }
 
-/// System: track drag movement
+/// System: track drag movement and velocity
 pub fn drag_move(
+    touches: Res<Touches>,
     windows: Query<&Window>,
+    time: Res<Time>,
     mut drag: ResMut<DragState>,
 ) {
     if !drag.active { return; }
-    if let Some(window) = windows.iter().next() {
-        if let Some(pos) = window.cursor_position() {
-            drag.current_pos = pos;
-        }
+
+    let pos = match drag.touch_id {
+        Some(id) => touches.get_pressed(id).map(|t| t.position()),
+        None => windows.iter().next().and_then(|w| w.cursor_position()),
+    };
+    let Some(pos) = pos else { return };
+    if pos == drag.current_pos {
+        drag.velocity = decay_velocity(drag.velocity, time.delta_secs());
+        return;
     }
+
+    let now = time.elapsed_secs();
+    let dt = (now - drag.last_moved).max(1.0 / 240.0);
+    let sample = (pos - drag.current_pos) / dt;
+    // Smooth so one jittery frame can't fake a flick
+    drag.velocity = drag.velocity.lerp(sample, 0.5);
+    drag.current_pos = pos;
+    drag.last_moved = now;
+}
+
+/// Velocity after `dt` seconds without movement.
+pub fn decay_velocity(velocity: Vec2, dt: f32) -> Vec2 {
+    velocity * 0.5_f32.powf(dt / VELOCITY_HALF_LIFE)
 }
 
-/// System: detect swipe on release
+/// System: turn a finished drag into a swipe, or a tap into a selection
 pub fn drag_end(
     mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
     mut drag: ResMut<DragState>,
-    mut pending: ResMut<PendingSwipe>,
-    slide: Res<CurrentSlide>,
+    mut pending: ResMut<PendingAction>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    slots: Query<(&HandSlot, &GlobalTransform, &Sprite)>,
 ) {
     if !drag.active { return; }
 
-    if mouse.just_released(MouseButton::Left) {
-        let delta = drag.current_pos - drag.start_pos;
-        let magnitude = delta.length();
-
-        if magnitude > SWIPE_THRESHOLD && slide.ready_for_input {
-            let abs_x = delta.x.abs();
-            let abs_y = delta.y.abs();
-
-            pending.direction = if abs_x > abs_y {
-                Some(if delta.x > 0.0 { SwipeChoice::Yes } else { SwipeChoice::No })
-            } else if delta.y > 0.0 {
-                Some(SwipeChoice::Deeper)
-            } else {
-                None // Up swipe ignored
-            };
+    let released = match drag.touch_id {
+        Some(id) => match touches.iter_just_released().find(|t| t.id() == id) {
+            Some(touch) => {
+                drag.current_pos = touch.position();
+                true
+            }
+            None => touches.iter_just_canceled().any(|t| t.id() == id),
+        },
+        None => mouse.just_released(MouseButton::Left),
+    };
+    if !released { return; }
+    drag.active = false;
+
+    pending.action = match classify_gesture(drag.current_pos - drag.start_pos, drag.velocity) {
+        Gesture::Swipe(action) => Some(action),
+        Gesture::Tap => {
+            // Which hand card is under the tap? None means the background.
+            let hit = cameras.iter().next()
+                .and_then(|(camera, cam_tf)| camera.viewport_to_world_2d(cam_tf, drag.start_pos).ok())
+                .and_then(|point| slots.iter().find(|(_, tf, sprite)| {
+                    let size = sprite.custom_size.unwrap_or(Vec2::ZERO) * tf.scale().truncate();
+                    (point - tf.translation().truncate()).abs().cmple(size / 2.0).all()
+                }))
+                .map(|(slot, _, _)| slot.0);
+            Some(hit.map_or(PlayerAction::Back, PlayerAction::Select))
         }
-        drag.active = false;
-    }
+        Gesture::Nothing => None,
+    };
 }
 
-/// System: keyboard arrow keys
+/// System: keyboard — number keys select, arrows swipe, Escape goes back.
+/// Off in the depth view, where the keyboard writes (see journal_typing).
 pub fn keyboard_input(
     keys: Res<ButtonInput<KeyCode>>,
-    mut pending: ResMut<PendingSwipe>,
-    slide: Res<CurrentSlide>,
+    mut pending: ResMut<PendingAction>,
 ) {
-    if !slide.ready_for_input || slide.depth_showing { return; }
+    const SELECT_KEYS: [KeyCode; 5] = [
+        KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4, KeyCode::Digit5,
+    ];
 
-    if keys.just_pressed(KeyCode::ArrowRight) || keys.just_pressed(KeyCode::KeyD) {
-        pending.direction = Some(SwipeChoice::Yes);
+    let action = if let Some(index) = SELECT_KEYS.iter().position(|k| keys.just_pressed(*k)) {
+        Some(PlayerAction::Select(index))
+    } else if keys.just_pressed(KeyCode::ArrowRight) || keys.just_pressed(KeyCode::KeyD) {
+        Some(PlayerAction::Swipe(SwipeChoice::Yes))
     } else if keys.just_pressed(KeyCode::ArrowLeft) || keys.just_pressed(KeyCode::KeyA) {
-        pending.direction = Some(SwipeChoice::No);
+        Some(PlayerAction::Swipe(SwipeChoice::No))
     } else if keys.just_pressed(KeyCode::ArrowDown) || keys.just_pressed(KeyCode::KeyS)
         || keys.just_pressed(KeyCode::Space) {
-        pending.direction = Some(SwipeChoice::Deeper);
+        Some(PlayerAction::Swipe(SwipeChoice::Deeper))
+    } else if keys.just_pressed(KeyCode::Escape) || keys.just_pressed(KeyCode::ArrowUp) {
+        Some(PlayerAction::Back)
+    } else {
+        None
+    };
+
+    if action.is_some() {
+        pending.action = action;
+    }
+}
+
+/// System: typing in the depth view — characters go into the draft,
+/// Backspace takes one back, Enter answers, Escape closes. Keys pressed
+/// anywhere else are let go, so they never turn up in an answer.
+pub fn journal_typing(
+    mut keys: MessageReader<KeyboardInput>,
+    mut draft: ResMut<JournalDraft>,
+    mut pending: ResMut<PendingAction>,
+    state: Res<State<GameState>>,
+) {
+    if *state.get() != GameState::DepthView {
+        keys.clear();
+        return;
+    }
+    for key in keys.read() {
+        if key.state != ButtonState::Pressed {
+            continue;
+        }
+        match &key.logical_key {
+            Key::Enter => pending.action = Some(PlayerAction::Answer),
+            Key::Escape => pending.action = Some(PlayerAction::Back),
+            Key::Backspace => {
+                draft.text.pop();
+            }
+            Key::Space => push_answer_text(&mut draft, " "),
+            Key::Character(chars) => push_answer_text(&mut draft, chars),
+            _ => {}
+        }
+    }
+}
+
+fn push_answer_text(draft: &mut JournalDraft, chars: &str) {
+    let room = MAX_ANSWER_CHARS.saturating_sub(draft.text.chars().count());
+    draft.text.extend(chars.chars().filter(|c| !c.is_control()).take(room));
+}
+
+/// System: drop an action nobody consumed, so a key pressed while
+/// cards are being drawn doesn't fire later by surprise.
+pub fn clear_pending_action(mut pending: ResMut<PendingAction>) {
+    pending.action = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_drag_is_a_tap() {
+        assert_eq!(classify_gesture(Vec2::new(5.0, 3.0), Vec2::ZERO), Gesture::Tap);
+    }
+
+    #[test]
+    fn test_long_drag_swipes_by_distance() {
+        let right = classify_gesture(Vec2::new(120.0, 10.0), Vec2::ZERO);
+        assert_eq!(right, Gesture::Swipe(PlayerAction::Swipe(SwipeChoice::Yes)));
+        let down = classify_gesture(Vec2::new(10.0, 100.0), Vec2::ZERO);
+        assert_eq!(down, Gesture::Swipe(PlayerAction::Swipe(SwipeChoice::Deeper)));
+        let up = classify_gesture(Vec2::new(0.0, -100.0), Vec2::ZERO);
+        assert_eq!(up, Gesture::Swipe(PlayerAction::Back));
+    }
+
+    #[test]
+    fn test_answer_text_is_capped() {
+        let mut draft = JournalDraft { text: "a".repeat(MAX_ANSWER_CHARS - 2) };
+        push_answer_text(&mut draft, "bcd\u{7}");
+        assert_eq!(draft.text.chars().count(), MAX_ANSWER_CHARS);
+        assert!(draft.text.ends_with("bc"));
+    }
+
+    #[test]
+    fn test_fast_flick_swipes_before_the_threshold() {
+        let delta = Vec2::new(-40.0, 0.0);
+        assert_eq!(classify_gesture(delta, Vec2::new(-100.0, 0.0)), Gesture::Nothing);
+        assert_eq!(
+            classify_gesture(delta, Vec2::new(-900.0, 50.0)),
+            Gesture::Swipe(PlayerAction::Swipe(SwipeChoice::No)),
+        );
+    }
+
+    #[test]
+    fn test_flick_then_hold_still_is_not_a_flick() {
+        let delta = Vec2::new(-40.0, 0.0);
+        let mut velocity = Vec2::new(-900.0, 50.0);
+        // A quarter second of still frames at 60 fps before release
+        for _ in 0..15 {
+            velocity = decay_velocity(velocity, 1.0 / 60.0);
+        }
+        assert_eq!(classify_gesture(delta, velocity), Gesture::Nothing);
+        // One still frame isn't enough to cancel a flick
+        let released = decay_velocity(Vec2::new(-900.0, 50.0), 1.0 / 60.0);
+        assert_eq!(classify_gesture(delta, released), Gesture::Swipe(PlayerAction::Swipe(SwipeChoice::No)));
     }
 }
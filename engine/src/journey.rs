@@ -0,0 +1,234 @@
+// Daydream Engine — The Hero's Journey
+// A session is told in chapters, one per Journey stage:
+//
+//   The Ordinary World → The Call to Adventure → Crossing the Threshold
+//   → Tests and Allies → The Ordeal → The Transformation → The Return
+//
+// Each chapter holds a few story moments. When its last moment closes,
+// the student reaches a branch point: up to three undrawn words, grown
+// from the DAG edges of the words they just cast.
+//
+//   → right  the first way   (a yes-target: press on)
+//   ← left   the second way  (a no-target: turn aside)
+//   ↓ down   the third way   (a special branch when Focus is low)
+//
+// When the edges run out, the student may wander to an undrawn word.
+// The chosen word opens the next chapter's hand. The Return has no
+// branch point — it runs until the deck is spent.
+
+use bevy::prelude::*;
+use crate::components::*;
+use crate::input::{PendingAction, PlayerAction};
+use crate::locale::WordNames;
+
+/// Tunables for chapter pacing.
+#[derive(Resource, Clone, Debug)]
+pub struct JourneyRules {
+    /// Completed story moments that make a chapter.
+    pub moments_per_chapter: usize,
+}
+
+impl Default for JourneyRules {
+    fn default() -> Self {
+        Self { moments_per_chapter: 2 }
+    }
+}
+
+/// The current chapter is told and waiting on a branch.
+fn branch_due(trail: &StudentTrail, rules: &JourneyRules) -> bool {
+    trail.chapter().is_some_and(|c| {
+        c.branch.is_none()
+            && c.stage.next().is_some()
+            && c.moments.len() >= rules.moments_per_chapter
+    })
+}
+
+/// Run condition: draw on unless a chapter just ended.
+pub fn no_branch_due(trail: Res<StudentTrail>, rules: Res<JourneyRules>) -> bool {
+    !branch_due(&trail, &rules)
+}
+
+/// Close the current chapter and open the next.
+fn begin_next_chapter(trail: &mut StudentTrail, branch: Option<Branch>) -> JourneyStage {
+    let chapter = trail.chapter_mut();
+    chapter.branch = branch;
+    let next = chapter.stage.next().unwrap_or(chapter.stage);
+    trail.chapters.push(Chapter::new(next));
+    next
+}
+
+/// Reads one kind of edge off a word.
+type EdgeTargets = fn(&WordEdges) -> &[String];
+
+/// Up to three ways forward, ordered right, left, down. One of each
+/// kind of edge first, then any other edge targets, then wandering to
+/// the top of the (shuffled) deck. Only undrawn words can be branched to.
+pub fn branch_options(recent: &[&WordEdges], undrawn: &[(Entity, &str)], low_focus: bool) -> Vec<Branch> {
+    let mut kinds: Vec<(BranchEdge, EdgeTargets)> = vec![
+        (BranchEdge::Onward, |e| e.yes_targets.as_slice()),
+        (BranchEdge::Aside, |e| e.no_targets.as_slice()),
+    ];
+    if low_focus {
+        kinds.push((BranchEdge::Special, |e| e.low_focus_targets.as_slice()));
+    }
+
+    let mut options: Vec<Branch> = Vec::new();
+    let offer = |options: &mut Vec<Branch>, word: &str, edge: BranchEdge| {
+        if options.len() >= 3 || options.iter().any(|b| b.word == word) {
+            return false;
+        }
+        let Some((entity, _)) = undrawn.iter().find(|(_, w)| *w == word) else { return false };
+        options.push(Branch { entity: *entity, word: word.to_string(), edge });
+        true
+    };
+
+    for (edge, targets) in &kinds {
+        for target in recent.iter().flat_map(|e| targets(e)) {
+            if offer(&mut options, target, *edge) {
+                break;
+            }
+        }
+    }
+    for (edge, targets) in &kinds {
+        for target in recent.iter().flat_map(|e| targets(e)) {
+            offer(&mut options, target, *edge);
+        }
+    }
+
+    for (_, word) in undrawn.iter().rev() {
+        offer(&mut options, word, BranchEdge::Wander);
+    }
+    options
+}
+
+/// System: when a chapter's moments are told, stop at a branch point
+/// instead of drawing. With nothing left to branch to, the next
+/// chapter simply begins.
+/// Runs on entering Drawing, before the draw.
+pub fn offer_branch(
+    mut trail: ResMut<StudentTrail>,
+    rules: Res<JourneyRules>,
+    deck: Res<Deck>,
+    hero: Res<CharacterState>,
+    words: Query<(&WordCard, &WordEdges)>,
+    mut branch_point: ResMut<BranchPoint>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if !branch_due(&trail, &rules) {
+        return;
+    }
+
+    // The last word cast leads; the rest of its moment follows
+    let mut recent_words: Vec<Entity> = trail.current_word.into_iter().collect();
+    if let Some(moment) = trail.chapter().and_then(|c| c.moments.last()) {
+        recent_words.extend(moment.placed_entities());
+    }
+    let recent: Vec<&WordEdges> = recent_words.iter()
+        .filter_map(|e| words.get(*e).ok().map(|(_, edges)| edges))
+        .collect();
+    let undrawn: Vec<(Entity, &str)> = deck.cards.iter()
+        .filter_map(|e| words.get(*e).ok().map(|(word, _)| (*e, word.word.as_str())))
+        .collect();
+
+    let options = branch_options(&recent, &undrawn, hero.is_low());
+    if options.is_empty() {
+        let stage = begin_next_chapter(&mut trail, None);
+        info!("Chapter {} begins: {}", stage.numeral(), stage.title());
+        return;
+    }
+    branch_point.options = options;
+    next_state.set(GameState::BranchPoint);
+}
+
+/// System: a swipe picks a branch — right, left or down. The chosen
+/// word is promised to the next draw and the next chapter begins.
+pub fn take_branch(
+    mut pending: ResMut<PendingAction>,
+    mut branch_point: ResMut<BranchPoint>,
+    mut trail: ResMut<StudentTrail>,
+    mut deck: ResMut<Deck>,
+    mut slide: ResMut<CurrentSlide>,
+    mut next_state: ResMut<NextState<GameState>>,
+    names: WordNames,
+) {
+    let Some(PlayerAction::Swipe(choice)) = pending.action else { return };
+    pending.action = None;
+    let Some(index) = branch_point.index_for(choice) else { return };
+
+    let branch = branch_point.options.swap_remove(index);
+    branch_point.options.clear();
+    deck.promised = Some(branch.entity);
+    slide.story_text = names.locale.branch_prompt(branch.edge, &names.name(&branch.word));
+
+    let stage = begin_next_chapter(&mut trail, Some(branch));
+    info!("Chapter {} begins: {}", stage.numeral(), stage.title());
+    next_state.set(GameState::Drawing);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edges(yes: &[&str], no: &[&str], special: &[&str]) -> WordEdges {
+        let strings = |words: &[&str]| words.iter().map(|s| s.to_string()).collect();
+        WordEdges {
+            yes_targets: strings(yes),
+            no_targets: strings(no),
+            low_focus_targets: strings(special),
+        }
+    }
+
+    fn undrawn(words: &[&'static str]) -> Vec<(Entity, &'static str)> {
+        words.iter().enumerate()
+            .map(|(i, w)| (Entity::from_raw_u32(i as u32 + 1).unwrap(), *w))
+            .collect()
+    }
+
+    #[test]
+    fn test_branches_follow_each_kind_of_edge() {
+        let cast = edges(&["Bias", "Clarity"], &["Patience"], &["Resilience"]);
+        let deck = undrawn(&["Clarity", "Patience", "Resilience", "Bias", "Mirror"]);
+        let steady = branch_options(&[&cast], &deck, false);
+        let picked: Vec<(&str, BranchEdge)> = steady.iter().map(|b| (b.word.as_str(), b.edge)).collect();
+        assert_eq!(picked, vec![
+            ("Bias", BranchEdge::Onward),
+            ("Patience", BranchEdge::Aside),
+            ("Clarity", BranchEdge::Onward),
+        ]);
+
+        // A strained Hero is offered the special branch as the third way
+        let strained = branch_options(&[&cast], &deck, true);
+        assert_eq!(strained[2].word, "Resilience");
+        assert_eq!(strained[2].edge, BranchEdge::Special);
+    }
+
+    #[test]
+    fn test_branches_skip_drawn_words_and_wander_when_edges_run_out() {
+        let cast = edges(&["Bias"], &["Patience"], &[]);
+        let deck = undrawn(&["Patience", "Mirror"]);
+        let options = branch_options(&[&cast], &deck, false);
+        assert_eq!(options.len(), 2);
+        assert_eq!(options[0].edge, BranchEdge::Aside);
+        assert_eq!(options[1].word, "Mirror");
+        assert_eq!(options[1].edge, BranchEdge::Wander);
+
+        assert!(branch_options(&[&cast], &[], false).is_empty());
+    }
+
+    #[test]
+    fn test_chapters_advance_through_the_journey() {
+        let mut trail = StudentTrail::default();
+        trail.chapter_mut().moments.push(StoryMoment::default());
+        let rules = JourneyRules { moments_per_chapter: 1 };
+        assert!(branch_due(&trail, &rules));
+
+        assert_eq!(begin_next_chapter(&mut trail, None), JourneyStage::CallToAdventure);
+        assert!(!branch_due(&trail, &rules));
+
+        // The Return never branches
+        trail.chapters.last_mut().unwrap().stage = JourneyStage::TheReturn;
+        trail.chapter_mut().moments.push(StoryMoment::default());
+        assert!(!branch_due(&trail, &rules));
+        assert_eq!(trail.moments().count(), 2);
+    }
+}
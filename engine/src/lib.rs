@@ -0,0 +1,287 @@
+// Daydream Engine — Library
+// The game as three Bevy plugins, so the same rules run in the windowed
+// game, headless on a server, in tests, and in the wasm client:
+//
+//   DaydreamPlugin        rules: state machine, resources, every system
+//                         that plays by the rules (no window, no device)
+//   DaydreamInputPlugin   keys, mouse and touch → one PendingAction
+//   DaydreamRenderPlugin  sprites and text for each state
+//
+// Anything that acts as the player — a device, the simulation, a
+// server — only has to set PendingAction. The rules never look further.
+
+pub mod archetype;
+pub mod backend;
+// The curriculum builder is a native CLI tool; the wasm client never ships it
+#[cfg(not(target_arch = "wasm32"))]
+pub mod builder;
+pub mod challenge;
+pub mod components;
+pub mod constructed;
+pub mod dag;
+pub mod deck;
+pub mod focus;
+pub mod genre;
+pub mod input;
+pub mod journey;
+pub mod locale;
+pub mod mastery;
+pub mod recycler;
+pub mod render;
+pub mod replay;
+pub mod rng;
+pub mod save;
+pub mod sim;
+pub mod synergy;
+
+use bevy::prelude::*;
+use components::*;
+use dag::CurriculumSource;
+use genre::{ActiveGenre, Genre};
+use locale::ActiveLocale;
+use rng::SessionRng;
+use save::SaveStore;
+
+/// The game itself — state machine, resources, and the systems that
+/// play by the rules — without any window, rendering, or device input.
+/// Needs the StatesPlugin (part of DefaultPlugins, not MinimalPlugins).
+///
+/// SessionRng, SaveStore, ActiveGenre, ActiveLocale and DeckChoice may
+/// be inserted before the plugin is added; otherwise an entropy seed, no
+/// saving, the Fantasy genre, English and the whole curriculum are used.
+#[derive(Clone, Debug)]
+pub struct DaydreamPlugin {
+    pub curriculum: CurriculumSource,
+}
+
+impl Default for DaydreamPlugin {
+    fn default() -> Self {
+        Self { curriculum: CurriculumSource::Demo }
+    }
+}
+
+impl Plugin for DaydreamPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<SessionRng>() {
+            app.insert_resource(SessionRng::from_seed(rng::entropy_seed()));
+        }
+        if !app.world().contains_resource::<SaveStore>() {
+            app.insert_resource(SaveStore::Disabled);
+        }
+        if !app.world().contains_resource::<ActiveGenre>() {
+            app.insert_resource(ActiveGenre::load(Genre::default(), &self.curriculum));
+        }
+        if !app.world().contains_resource::<ActiveLocale>() {
+            app.insert_resource(ActiveLocale::load(locale::ENGLISH, &self.curriculum));
+        }
+
+        app
+            // State
+            .init_state::<GameState>()
+            // Resources — TCG deck/hand system
+            .init_resource::<Deck>()
+            .init_resource::<Hand>()
+            .init_resource::<DiscardPile>()
+            .init_resource::<ActiveSynergies>()
+            .init_resource::<StoryMoment>()
+            .init_resource::<ResonanceThreads>()
+            // Player intent from any device (or the simulation)
+            .init_resource::<input::PendingAction>()
+            .init_resource::<input::JournalDraft>()
+            .add_systems(Last, input::clear_pending_action)
+            // Mastery: play systems report progress, mastery turns it into growth
+            .init_resource::<mastery::MasteryRules>()
+            .add_message::<mastery::SpellProgress>()
+            .add_message::<mastery::MasteryLevelUp>()
+            // Focus: the Hero tires as words are cast
+            .init_resource::<focus::FocusRules>()
+            .init_resource::<focus::RestPoint>()
+            .init_resource::<CharacterState>()
+            // Journey: chapters of moments, ending at branch points
+            .init_resource::<journey::JourneyRules>()
+            .init_resource::<BranchPoint>()
+            // Archetype: read from how the student played, at the review
+            .init_resource::<archetype::ArchetypeReading>()
+            // Recycler: played words return on a spaced-repetition schedule
+            .init_resource::<recycler::ReviewSchedule>()
+            .init_resource::<recycler::ReviewClock>()
+            .init_resource::<recycler::RecyclerSession>()
+            // Curriculum: CLI path, asset folder, backend, or the demo set
+            .insert_resource(self.curriculum.clone())
+            // Startup: curriculum data, then saved progress over it
+            .add_systems(Startup,
+                ((dag::spawn_curriculum, save::load_progress).chain(), focus::reset_hero),
+            )
+            // Genre: re-skin words on a switch, flag overrides for missing
+            // words; a cast Setting card changes the atmosphere
+            .init_resource::<genre::Atmosphere>()
+            .add_systems(Update, (
+                genre::reskin_words,
+                genre::check_genre_coverage,
+                genre::shift_atmosphere.after(deck::handle_card_action),
+            ))
+            // Loading → Drawing: init the deck once the curriculum has
+            // spawned — the student's chosen deck if it is legal, otherwise
+            // the whole curriculum
+            .init_resource::<constructed::DeckRules>()
+            .init_resource::<constructed::DeckLibrary>()
+            .init_resource::<constructed::DeckChoice>()
+            .add_systems(Update, (
+                constructed::deal_constructed_deck,
+                deck::init_deck,
+            ).chain().run_if(in_state(GameState::Loading)))
+            // Drawing: an empty deck takes back due words from the
+            // Recycler (not in a daily challenge — every student gets the
+            // same deal); a finished chapter stops at a branch point;
+            // otherwise fill hand from deck, then transition to Playing
+            .add_systems(OnEnter(GameState::Drawing), (
+                recycler::recycle_due_cards.run_if(challenge::no_challenge),
+                journey::offer_branch,
+                deck::draw_cards.run_if(journey::no_branch_due),
+            ).chain())
+            // Playing: select cards, preview synergies
+            .add_systems(Update,
+                synergy::detect_synergies.run_if(in_state(GameState::Playing)),
+            )
+            // CardSelected: switch or drop the selection, handle
+            // cast/skip/deeper, let the Hero pay for a cast, then resolve
+            // resonance on the table before a full moment closes
+            .add_systems(Update, (
+                deck::select_card,
+                deck::handle_card_action
+                    .run_if(in_state(GameState::CardSelected)),
+                focus::spend_focus,
+                synergy::resolve_moment_synergies,
+                deck::complete_story_moment,
+            ).chain())
+            // DepthView: any action closes it; an Answer keeps the typed text
+            .add_systems(Update,
+                deck::dismiss_depth.run_if(in_state(GameState::DepthView)),
+            )
+            // Resting: paced breaths restore Focus, then drawing resumes
+            .add_systems(OnEnter(GameState::Resting),
+                focus::begin_rest,
+            )
+            .add_systems(Update,
+                focus::take_rest_breaths.run_if(in_state(GameState::Resting)),
+            )
+            // BranchPoint: a swipe picks the way the next chapter goes
+            .add_systems(Update,
+                journey::take_branch.run_if(in_state(GameState::BranchPoint)),
+            )
+            // TrailReview: keep a half-built moment when the session ends,
+            // then read the archetype from the whole session
+            .add_systems(OnEnter(GameState::TrailReview), (
+                deck::close_story_moment,
+                archetype::reflect_archetype,
+            ).chain())
+            // Mastery progression runs in every state, after this frame's
+            // casts, so a cast word is rescheduled before the next draw;
+            // the Recycler then reschedules what was cast or skipped
+            .add_systems(Update, (
+                mastery::apply_spell_progress,
+                recycler::schedule_reviews,
+            ).chain().after(deck::complete_story_moment).after(deck::dismiss_depth))
+            // Saved SpellPower reaches words as they spawn; every state
+            // change writes progress back out
+            .add_systems(Update, (
+                save::restore_spell_powers,
+                save::autosave_on_transition,
+            ).chain());
+    }
+}
+
+/// Keys, mouse and touch, gathered into the rules' PendingAction.
+pub struct DaydreamInputPlugin;
+
+impl Plugin for DaydreamInputPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<input::DragState>()
+            .add_systems(PreUpdate, (
+                input::keyboard_input.run_if(not(in_state(GameState::DepthView))),
+                input::journal_typing,
+                (input::drag_start, input::drag_move, input::drag_end).chain(),
+            ).after(bevy::input::InputSystems));
+    }
+}
+
+/// Sprites and text for each state of the rules.
+pub struct DaydreamRenderPlugin;
+
+impl Plugin for DaydreamRenderPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Startup: camera, and the play view that lives all session
+            .add_systems(Startup, (render::setup_camera, render::spawn_play_view).chain())
+            // Every frame: redraw what changed, then move the cards
+            .add_systems(PostUpdate, (
+                render::update_setting_background,
+                render::update_active_card,
+                render::update_story_slots,
+                render::update_story_text,
+                render::update_synergy_indicator,
+                render::update_swipe_hint,
+                render::update_hud,
+                (render::sync_hand, render::layout_hand, render::animate_card_motion).chain(),
+            ).before(bevy::transform::TransformSystems::Propagate))
+            // DepthView: show depth overlay while it is open
+            .add_systems(OnEnter(GameState::DepthView),
+                render::spawn_depth_view,
+            )
+            .add_systems(Update,
+                render::update_journal_draft.run_if(in_state(GameState::DepthView)),
+            )
+            .add_systems(OnExit(GameState::DepthView),
+                render::clear_depth_view,
+            )
+            // Resting: breath counter while the Hero recovers
+            .add_systems(OnEnter(GameState::Resting),
+                render::spawn_rest_view,
+            )
+            .add_systems(Update,
+                render::update_rest_view.run_if(in_state(GameState::Resting)),
+            )
+            .add_systems(OnExit(GameState::Resting),
+                render::clear_rest_view,
+            )
+            // BranchPoint: the three ways forward
+            .add_systems(OnEnter(GameState::BranchPoint),
+                render::spawn_branch_view,
+            )
+            .add_systems(OnExit(GameState::BranchPoint),
+                render::clear_branch_view,
+            )
+            // TrailReview: end-of-session summary
+            .add_systems(OnEnter(GameState::TrailReview), (
+                render::hide_play_view,
+                render::spawn_trail_review.after(archetype::reflect_archetype),
+            ))
+            .add_systems(OnExit(GameState::TrailReview), (
+                render::clear_trail_review,
+                render::show_play_view,
+            ))
+            // Level-up toasts follow mastery progression
+            .add_systems(Update, (
+                render::announce_mastery_gains,
+                render::fade_mastery_toasts,
+            ).chain().after(mastery::apply_spell_progress));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    use crate::sim::harness::{deal, state};
+
+    #[test]
+    fn test_rules_run_headless_without_caller_resources() {
+        // Not sim::headless_app, which brings its own seed
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin, DaydreamPlugin::default()));
+        deal(&mut app);
+        assert_eq!(state(&app), GameState::Playing);
+        assert!(app.world().resource::<Hand>().card_count() > 0);
+    }
+}
@@ -0,0 +1,503 @@
+// Daydream Engine — Localization
+// Every string the engine writes for the student comes from a message
+// catalog, looked up by key:
+//
+//   "trail.title":   "Your journey, traced."
+//   "trail.path":    "You walk the path of: {class}"
+//   "trail.moments": { "one": "{n} moment", "other": "{n} moments" }
+//
+// `{name}` placeholders are filled by the caller; a message given as
+// forms is picked by count (`{n}`) with the language's plural rule.
+// Words themselves — name, depth prompt, themes, story beat — are
+// translated in the curriculum (see dag.rs: WordTranslation).
+//
+// Fallbacks, per key:
+//   `<dir>/locales/<id>.json` beside the curriculum → bundled catalog
+//   for the locale ("es-MX") → for its language ("es") → English → the
+//   key itself, so a missing string shows up instead of going blank.
+//
+// English and Spanish are bundled (arcana/locales/*.json). The story
+// lines the rules assemble — moments, synergy, focus and branch beats,
+// recycled words, archetypes — are told from the catalog too; the
+// English rules text in components.rs stays the canonical record kept
+// in saves and reports. Genre templates carry their own translations
+// (see genre.rs: GenreTranslation).
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use crate::archetype::{Archetype, ArchetypeReading};
+use crate::components::*;
+use crate::dag::CurriculumSource;
+
+/// The locale every catalog falls back to.
+pub const ENGLISH: &str = "en";
+
+// ─── CATALOG FORMAT ─────────────────────────────────────────
+
+/// One message: plain text, or one text per plural form
+/// ("zero", "one", "few", "many", "other").
+#[derive(Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum Message {
+    Text(String),
+    Plural(HashMap<String, String>),
+}
+
+/// JSON schema for a message catalog.
+#[derive(Deserialize, Clone, Debug, Default)]
+pub struct Catalog {
+    pub locale: String,
+    /// The language's own name: "Español".
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub messages: HashMap<String, Message>,
+}
+
+impl Catalog {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+
+    /// The catalog compiled into the engine for this locale, if any.
+    fn bundled(id: &str) -> Option<Self> {
+        let json = match id {
+            "en" => include_str!("../arcana/locales/en.json"),
+            "es" => include_str!("../arcana/locales/es.json"),
+            _ => return None,
+        };
+        Catalog::from_json(json).map_err(|e| error!("Bundled {} catalog is malformed: {}", id, e)).ok()
+    }
+}
+
+/// The plural form a count takes in a language. English, Spanish and
+/// most European languages say "one" for 1 only; French and Portuguese
+/// for 0 and 1.
+pub fn plural_form(language: &str, n: u64) -> &'static str {
+    match language {
+        "fr" | "pt" if n <= 1 => "one",
+        "fr" | "pt" => "other",
+        _ if n == 1 => "one",
+        _ => "other",
+    }
+}
+
+/// Fill `{name}` placeholders.
+fn fill(template: &str, args: &[(&str, &str)]) -> String {
+    args.iter().fold(template.to_string(), |text, (name, value)| {
+        text.replace(&format!("{{{}}}", name), value)
+    })
+}
+
+/// "Edge Lord" → "edge_lord": the key part for an English label.
+fn key_part(label: &str) -> String {
+    label.to_lowercase().replace(' ', "_")
+}
+
+// ─── ACTIVE LOCALE ──────────────────────────────────────────
+
+/// Resource: the student's language, and the catalogs to find its
+/// strings in, most specific first.
+#[derive(Resource, Clone, Debug)]
+pub struct ActiveLocale {
+    pub id: String,
+    catalogs: Vec<Catalog>,
+}
+
+impl Default for ActiveLocale {
+    fn default() -> Self {
+        Self::load(ENGLISH, &CurriculumSource::Demo)
+    }
+}
+
+impl ActiveLocale {
+    /// Load a locale's catalogs: the curriculum's own, then the bundled
+    /// ones for the locale and its language, then English.
+    pub fn load(id: &str, source: &CurriculumSource) -> Self {
+        let id = id.trim().replace('_', "-");
+        let mut ids = vec![id.clone()];
+        let language = language_of(&id).to_string();
+        for fallback in [language, ENGLISH.to_string()] {
+            if !ids.contains(&fallback) {
+                ids.push(fallback);
+            }
+        }
+
+        let mut catalogs = Vec::new();
+        for locale in &ids {
+            catalogs.extend(local_catalog(locale, source));
+            catalogs.extend(Catalog::bundled(locale));
+        }
+        if catalogs.first().is_none_or(|c| c.locale != id) && id != ENGLISH {
+            warn!("No catalog for locale '{}', falling back to {}", id, catalogs.first().map_or(ENGLISH, |c| c.locale.as_str()));
+        }
+        Self { id, catalogs }
+    }
+
+    /// `--locale=es` from the command line, or English.
+    pub fn from_args(source: &CurriculumSource) -> Self {
+        let id = std::env::args()
+            .find_map(|a| a.strip_prefix("--locale=").map(str::to_string))
+            .unwrap_or_else(|| ENGLISH.to_string());
+        Self::load(&id, source)
+    }
+
+    /// The language part of the locale: "es" for "es-MX".
+    pub fn language(&self) -> &str {
+        language_of(&self.id)
+    }
+
+    fn message(&self, key: &str) -> Option<&Message> {
+        self.catalogs.iter().find_map(|c| c.messages.get(key))
+    }
+
+    /// A message with no placeholders.
+    pub fn text(&self, key: &str) -> String {
+        self.format(key, &[])
+    }
+
+    /// A message with its `{name}` placeholders filled.
+    pub fn format(&self, key: &str, args: &[(&str, &str)]) -> String {
+        match self.message(key) {
+            Some(Message::Text(text)) => fill(text, args),
+            Some(Message::Plural(forms)) => forms.get("other").map_or_else(|| key.to_string(), |t| fill(t, args)),
+            None => key.to_string(),
+        }
+    }
+
+    /// A message for a count: its plural form, with `{n}` and the
+    /// other placeholders filled. An explicit "zero" form wins for 0.
+    pub fn plural(&self, key: &str, n: u64, args: &[(&str, &str)]) -> String {
+        let forms = match self.message(key) {
+            Some(Message::Plural(forms)) => forms,
+            Some(Message::Text(_)) | None => return self.format(key, args),
+        };
+        let form = if n == 0 && forms.contains_key("zero") { "zero" } else { plural_form(self.language(), n) };
+        let Some(template) = forms.get(form).or_else(|| forms.get("other")) else {
+            return key.to_string();
+        };
+        let count = n.to_string();
+        let mut args = args.to_vec();
+        args.push(("n", &count));
+        fill(template, &args)
+    }
+
+    /// Something's translation for this locale (or its language), if
+    /// there is one: a curriculum word, a genre template.
+    pub fn translation<'a, T>(&self, translations: &'a HashMap<String, T>) -> Option<&'a T> {
+        translations.get(&self.id).or_else(|| translations.get(self.language()))
+    }
+
+    // ─── THE GAME'S OWN TERMS ───────────────────────────────
+
+    pub fn channel(&self, channel: Channel) -> String {
+        self.text(&format!("channel.{}", key_part(channel.label())))
+    }
+
+    /// The core question a channel asks.
+    pub fn channel_question(&self, channel: Channel) -> String {
+        self.text(&format!("channel.{}.question", key_part(channel.label())))
+    }
+
+    pub fn stage(&self, stage: Stage) -> String {
+        self.text(&format!("stage.{}", key_part(stage.label())))
+    }
+
+    pub fn rarity(&self, stage: Stage) -> String {
+        self.text(&format!("rarity.{}", key_part(stage.rarity())))
+    }
+
+    pub fn mastery(&self, level: MasteryLevel) -> String {
+        self.text(&format!("mastery.{}", key_part(level.label())))
+    }
+
+    pub fn slot(&self, slot: StorySlot) -> String {
+        self.text(&format!("slot.{}", key_part(slot.label())))
+    }
+
+    pub fn card_type(&self, card_type: CardType) -> String {
+        self.text(&format!("card_type.{}", key_part(card_type.label())))
+    }
+
+    pub fn synergy(&self, synergy: SynergyType) -> String {
+        self.text(&format!("synergy.{}", key_part(synergy.label())))
+    }
+
+    /// "II · The Call to Adventure"
+    pub fn chapter_heading(&self, stage: JourneyStage) -> String {
+        let title = self.text(&format!("journey.{}", key_part(stage.title())));
+        self.format("chapter.heading", &[("numeral", stage.numeral()), ("title", &title)])
+    }
+
+    /// A way forward from a branch point, as the student reads it.
+    pub fn branch_prompt(&self, edge: BranchEdge, word: &str) -> String {
+        let key = match edge {
+            BranchEdge::Onward  => "branch.onward",
+            BranchEdge::Aside   => "branch.aside",
+            BranchEdge::Special => "branch.special",
+            BranchEdge::Wander  => "branch.wander",
+        };
+        self.format(key, &[("word", word)])
+    }
+
+    pub fn synergy_flavor(&self, synergy: SynergyType, a: &str, b: &str) -> String {
+        self.format(&format!("synergy.{}.flavor", key_part(synergy.label())), &[("a", a), ("b", b)])
+    }
+
+    /// The line the story gains at a focus tier.
+    pub fn focus_line(&self, tier: FocusTier) -> Option<String> {
+        match tier {
+            FocusTier::Steady    => None,
+            FocusTier::Strained  => Some(self.text("focus.strained")),
+            FocusTier::Exhausted => Some(self.text("focus.exhausted")),
+        }
+    }
+
+    /// A moment's story text, with each word as `name` gives it.
+    pub fn moment_text(&self, moment: &StoryMoment, name: impl Fn(&str) -> String) -> String {
+        let setting = moment.slot_phrase_in(StorySlot::Setting, &name);
+        let subject = moment.slot_phrase_in(StorySlot::Subject, &name);
+        let action = moment.slot_phrase_in(StorySlot::Action, &name);
+        let mut lines = vec![self.format("moment.text", &[("setting", &setting), ("subject", &subject), ("action", &action)])];
+        if moment.is_dissonant() {
+            lines.push(self.text("moment.dissonant"));
+        }
+        if !moment.resonances.is_empty() {
+            lines.push(self.text("moment.resonant"));
+        }
+        lines.extend(self.focus_line(moment.focus));
+        lines.join("\n")
+    }
+
+    pub fn archetype(&self, archetype: Archetype) -> String {
+        self.text(&format!("archetype.{}", key_part(archetype.title())))
+    }
+
+    /// The emergent class on a Character Sheet: an archetype's title or
+    /// the newcomer's. A class the catalog doesn't know is shown as saved.
+    pub fn class_title(&self, class: &str) -> String {
+        let key = format!("archetype.{}", key_part(class));
+        match self.message(&key) {
+            Some(_) => self.text(&key),
+            None => class.to_string(),
+        }
+    }
+
+    /// What the game whispers at the trail review, and the evidence behind it.
+    pub fn archetype_whisper(&self, reading: &ArchetypeReading) -> String {
+        let Some(signal) = reading.signals.first() else {
+            return self.text("archetype.beginning");
+        };
+        let key = format!("archetype.{}", key_part(signal.archetype.title()));
+        let facts: Vec<(&str, &str)> = signal.facts.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        format!("{}\n{}", self.text(&format!("{}.whisper", key)), self.format(&format!("{}.evidence", key), &facts))
+    }
+
+    /// Channel attunement as text bars, for the trail review.
+    pub fn attunement_display(&self, sheet: &CharacterSheet) -> String {
+        let labels: Vec<String> = Channel::ALL.iter().map(|c| format!("{}:", self.channel(*c))).collect();
+        let width = labels.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        Channel::ALL.iter().zip(labels).map(|(channel, label)| {
+            let value = sheet.attunement(*channel);
+            let filled = (value * 8.0).round() as usize;
+            format!("{:<width$} {}{} {:.0}%", label, "█".repeat(filled), "░".repeat(8 - filled), value * 100.0)
+        }).collect::<Vec<_>>().join("\n")
+    }
+
+    /// The SpellBook's size and its words at each mastery level.
+    pub fn spellbook_summary(&self, spellbook: &SpellBook) -> String {
+        let mut lines = vec![self.plural("spellbook.title", spellbook.entries.len() as u64, &[])];
+        for level in MasteryLevel::ALL {
+            let count = spellbook.entries.iter().filter(|e| e.mastery == level).count().to_string();
+            let name = self.mastery(level);
+            lines.push(self.format("spellbook.level", &[("icon", level.icon()), ("level", &name), ("n", &count)]));
+        }
+        lines.join("\n")
+    }
+}
+
+/// System param: the active locale, and the story's words to name in it.
+#[derive(SystemParam)]
+pub struct WordNames<'w, 's> {
+    pub locale: Res<'w, ActiveLocale>,
+    words: Query<'w, 's, &'static WordCard>,
+}
+
+impl WordNames<'_, '_> {
+    /// A word's name as the student reads it; the word itself if it
+    /// isn't in the story.
+    pub fn name(&self, word: &str) -> String {
+        self.words.iter()
+            .find(|w| w.word == word)
+            .map_or_else(|| word.to_string(), |w| w.name.clone())
+    }
+}
+
+/// "es" for "es-MX".
+fn language_of(id: &str) -> &str {
+    id.split('-').next().unwrap_or(id)
+}
+
+/// A catalog beside a curriculum file: `<dir>/locales/<id>.json`.
+fn local_catalog(id: &str, source: &CurriculumSource) -> Option<Catalog> {
+    let CurriculumSource::File(path) = source else { return None };
+    let file: PathBuf = path.parent()?.join("locales").join(format!("{}.json", id));
+    let json = std::fs::read_to_string(&file).ok()?;
+    match Catalog::from_json(&json) {
+        Ok(catalog) => {
+            info!("Locale catalog loaded from {}", file.display());
+            Some(catalog)
+        }
+        Err(e) => {
+            warn!("{}: {} — skipping it", file.display(), e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dag::WordTranslation;
+
+    fn spanish() -> ActiveLocale {
+        ActiveLocale::load("es", &CurriculumSource::Demo)
+    }
+
+    #[test]
+    fn test_every_english_message_is_translated() {
+        let english = Catalog::bundled("en").unwrap();
+        let spanish = Catalog::bundled("es").unwrap();
+        let mut missing: Vec<&String> = english.messages.keys()
+            .filter(|k| !spanish.messages.contains_key(*k))
+            .collect();
+        missing.sort();
+        assert!(missing.is_empty(), "es.json lacks {:?}", missing);
+    }
+
+    #[test]
+    fn test_english_catalog_matches_the_game_terms() {
+        let en = ActiveLocale::default();
+        for stage in [Stage::Hero, Stage::Outlaw, Stage::EdgeLord, Stage::BestSelf] {
+            assert_eq!(en.stage(stage), stage.label());
+            assert_eq!(en.rarity(stage), stage.rarity());
+        }
+        for channel in Channel::ALL {
+            assert_eq!(en.channel_question(channel), channel.question());
+        }
+        for level in MasteryLevel::ALL {
+            assert_eq!(en.mastery(level), level.label());
+        }
+        for card_type in CardType::ALL {
+            assert_eq!(en.card_type(card_type), card_type.label());
+        }
+        for stage in JourneyStage::ALL {
+            assert_eq!(en.chapter_heading(stage), Chapter::new(stage).heading());
+        }
+        assert_eq!(en.branch_prompt(BranchEdge::Aside, "Bias"), BranchEdge::Aside.prompt("Bias"));
+        for synergy in [SynergyType::Nourishing, SynergyType::Generating, SynergyType::Complementary, SynergyType::Resonant] {
+            assert_eq!(en.synergy_flavor(synergy, "Bias", "Clarity"), synergy.flavor("Bias", "Clarity"));
+        }
+        for tier in [FocusTier::Steady, FocusTier::Strained, FocusTier::Exhausted] {
+            assert_eq!(en.focus_line(tier).as_deref(), tier.story_line());
+        }
+        for archetype in Archetype::ALL {
+            assert_eq!(en.archetype(archetype), archetype.title());
+            assert_eq!(en.class_title(archetype.title()), archetype.title());
+        }
+        assert_eq!(en.class_title(crate::archetype::NEWCOMER), crate::archetype::NEWCOMER);
+    }
+
+    #[test]
+    fn test_english_story_lines_match_the_rules() {
+        let en = ActiveLocale::default();
+        let mut moment = StoryMoment::default();
+        assert_eq!(en.moment_text(&moment, str::to_string), moment.assemble_text());
+        moment.place(Entity::from_raw_u32(1).unwrap(), "Bias", Symbol::Stone);
+        moment.resonances.push(("Bias".to_string(), "Clarity".to_string(), SynergyType::Resonant));
+        moment.focus = FocusTier::Strained;
+        assert_eq!(en.moment_text(&moment, str::to_string), moment.assemble_text());
+
+        // Every archetype's evidence, as the reading wrote it
+        let trail = StudentTrail {
+            cast_words: ["Bias", "Patience", "Presence", "Resilience"].map(String::from).to_vec(),
+            visited_words: ["Bias", "Patience", "Presence"].map(String::from).to_vec(),
+            swipe_history: vec![SwipeChoice::Deeper; 3],
+            ..default()
+        };
+        let channel_of = |word: &str| match word {
+            "Bias" => Some(Channel::Mind),
+            "Patience" => Some(Channel::Heart),
+            "Presence" => Some(Channel::Body),
+            _ => Some(Channel::Action),
+        };
+        let reading = crate::archetype::read_archetype(&trail, &default(), &default(), channel_of);
+        assert_eq!(reading.signals.len(), 2);
+        for signal in &reading.signals {
+            let alone = ArchetypeReading { signals: vec![signal.clone()] };
+            assert_eq!(en.archetype_whisper(&alone), alone.whisper());
+        }
+        assert_eq!(en.archetype_whisper(&ArchetypeReading::default()), ArchetypeReading::default().whisper());
+    }
+
+    #[test]
+    fn test_the_trail_review_is_told_in_spanish() {
+        let es = spanish();
+        let mut moment = StoryMoment::default();
+        moment.place(Entity::from_raw_u32(1).unwrap(), "Patience", Symbol::Stone);
+        moment.place(Entity::from_raw_u32(2).unwrap(), "Bias", Symbol::Star);
+        moment.focus = FocusTier::Exhausted;
+        let name = |word: &str| match word {
+            "Patience" => "Paciencia".to_string(),
+            "Bias" => "Sesgo".to_string(),
+            _ => word.to_string(),
+        };
+        let told = es.moment_text(&moment, name);
+        assert!(told.starts_with("En [PACIENCIA], [SESGO] empieza a _____."), "{}", told);
+        assert!(told.ends_with("no sin descansar."));
+
+        assert_eq!(es.class_title("The Hermeticist"), "El Hermetista");
+        assert_eq!(es.class_title("Newcomer"), "Recién llegado");
+        assert_eq!(es.class_title("Wayfarer"), "Wayfarer");
+
+        let trail = StudentTrail {
+            visited_words: ["Bias", "Patience", "Presence"].map(String::from).to_vec(),
+            swipe_history: vec![SwipeChoice::Deeper; 3],
+            ..default()
+        };
+        let reading = crate::archetype::read_archetype(&trail, &default(), &default(), |_| None);
+        assert_eq!(es.archetype_whisper(&reading),
+            "Ves el código detrás de las palabras.\nProfundizaste 3 veces entre 3 palabras.");
+    }
+
+    #[test]
+    fn test_plural_forms_follow_the_language() {
+        let en = ActiveLocale::default();
+        assert_eq!(en.plural("trail.moments", 1, &[]), "1 moment");
+        assert_eq!(en.plural("trail.moments", 0, &[]), "0 moments");
+        assert_eq!(spanish().plural("trail.resonated", 3, &[("a", "Paciencia"), ("b", "Presencia")]),
+            "'Paciencia' y 'Presencia' resonaron 3 veces.");
+        assert_eq!(plural_form("fr", 0), "one");
+        assert_eq!(plural_form("es", 0), "other");
+    }
+
+    #[test]
+    fn test_regional_locale_falls_back_to_language_then_english() {
+        let mexico = ActiveLocale::load("es_MX", &CurriculumSource::Demo);
+        assert_eq!(mexico.id, "es-MX");
+        assert_eq!(mexico.channel(Channel::Heart), "Corazón");
+
+        let mut partial = spanish();
+        partial.catalogs[0].messages.remove("trail.title");
+        assert_eq!(partial.text("trail.title"), "Your journey, traced.");
+        assert_eq!(partial.text("no.such.key"), "no.such.key");
+
+        let mut translations = HashMap::new();
+        translations.insert("es".to_string(), WordTranslation { word: Some("Sesgo".to_string()), ..default() });
+        assert_eq!(mexico.translation(&translations).and_then(|t| t.word.as_deref()), Some("Sesgo"));
+        assert!(ActiveLocale::default().translation(&translations).is_none());
+    }
+}
@@ -9,17 +9,44 @@
 // - The Triple Sandwich (Mind/Heart/Body) maps to card/story/setting
 // - Swipe right = cast, left = skip, down = dig deeper
 
-mod components;
-mod dag;
-mod deck;
-mod input;
-mod render;
-
 use bevy::prelude::*;
-use components::*;
+use daydream_engine::{
+    backend, challenge, constructed, dag, genre, locale, replay, rng, save, sim,
+    DaydreamInputPlugin, DaydreamPlugin, DaydreamRenderPlugin,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use daydream_engine::builder;
 
 fn main() {
-    App::new()
+    // `--headless` plays scripted sessions without a window (see sim.rs)
+    if std::env::args().any(|a| a == "--headless") {
+        std::process::exit(sim::run_from_args());
+    }
+    // `--replay=FILE` re-runs a recorded session and checks it still plays the same
+    if std::env::args().any(|a| a.starts_with("--replay=")) {
+        std::process::exit(replay::run_from_args());
+    }
+    // `--decks` and `--make-deck=NAME --words=...` manage constructed decks
+    if std::env::args().any(|a| a == "--decks" || a.starts_with("--make-deck=")) {
+        std::process::exit(constructed::run_from_args());
+    }
+    // `--export-challenge=PATH` writes a daily challenge for the backend
+    if std::env::args().any(|a| a.starts_with("--export-challenge=")) {
+        std::process::exit(challenge::run_from_args());
+    }
+    // `--build FILE...` drafts a curriculum from chapter text (see builder.rs)
+    #[cfg(not(target_arch = "wasm32"))]
+    if std::env::args().any(|a| a == "--build") {
+        std::process::exit(builder::run_from_args());
+    }
+
+    // `--backend=URL` plays the backend's story and reports play to it
+    // (as `--player=ID`, if given);
+    // an unreachable backend means the local curriculum, offline
+    let (curriculum_source, backend) = backend::connect(dag::CurriculumSource::from_args());
+
+    let mut app = App::new();
+    app
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
                 title: "Daydream — The Great Game".to_string(),
@@ -31,55 +58,43 @@ fn main() {
             }),
             ..default()
         }))
-        // State
-        .init_state::<GameState>()
-        // Resources — TCG deck/hand system
-        .init_resource::<Deck>()
-        .init_resource::<Hand>()
-        .init_resource::<DiscardPile>()
-        .init_resource::<ActiveSynergies>()
-        .init_resource::<input::DragState>()
-        .init_resource::<input::PendingSwipe>()
-        // Startup: camera + curriculum data
-        .add_systems(Startup, (
-            render::setup_camera,
-            dag::spawn_demo_curriculum,
-        ))
-        // Loading → Drawing: init the deck after curriculum spawns
-        .add_systems(OnEnter(GameState::Loading), (
-            deck::init_deck.after(dag::spawn_demo_curriculum),
-        ))
-        // Drawing: fill hand from deck, then transition to Playing
-        .add_systems(OnEnter(GameState::Drawing),
-            deck::draw_cards,
-        )
-        // Playing: show hand, detect card selection + synergies
-        .add_systems(OnEnter(GameState::Playing),
-            render::spawn_play_view,
-        )
-        .add_systems(Update, (
-            deck::select_card_by_key,
-            deck::detect_synergies,
-        ).run_if(in_state(GameState::Playing)))
-        // CardSelected: handle cast/skip/deeper actions
-        .add_systems(OnEnter(GameState::CardSelected),
-            render::spawn_play_view,
-        )
-        .add_systems(Update,
-            deck::handle_card_action
-                .run_if(in_state(GameState::CardSelected)),
-        )
-        // DepthView: show depth overlay, dismiss on keypress
-        .add_systems(OnEnter(GameState::DepthView),
-            render::spawn_depth_view,
-        )
-        .add_systems(Update,
-            render::dismiss_depth_view
-                .run_if(in_state(GameState::DepthView)),
-        )
-        // TrailReview: end-of-session summary
-        .add_systems(OnEnter(GameState::TrailReview),
-            render::spawn_trail_review,
-        )
-        .run();
+        // Seeded RNG for draws: `--seed=N` reproduces a session
+        .insert_resource(rng::SessionRng::from_args_or_entropy())
+        // Progress persists between sessions: `--save=PATH`, `--no-save`
+        .insert_resource(save::SaveStore::from_args())
+        // Story skin: `--genre=noir` (fantasy, sci-fi, noir, ghibli, cyberpunk)
+        .insert_resource(genre::ActiveGenre::from_args(&curriculum_source))
+        // Language of the game's text and the curriculum's: `--locale=es`
+        .insert_resource(locale::ActiveLocale::from_args(&curriculum_source))
+        // A deck the student built: `--deck=NAME` (see constructed.rs)
+        .insert_resource(constructed::DeckChoice::from_args())
+        .add_plugins((
+            DaydreamPlugin { curriculum: curriculum_source.clone() },
+            DaydreamInputPlugin,
+            DaydreamRenderPlugin,
+        ));
+
+    if let Some(link) = backend {
+        app.insert_resource(link)
+            .add_systems(Update, backend::report_choices);
+    }
+
+    // `--challenge[=DATE]` plays the class's daily challenge (see challenge.rs)
+    if let Some(daily) = challenge::ActiveChallenge::from_args(&curriculum_source) {
+        challenge::add_challenge(&mut app, daily);
+    }
+
+    // `--record=FILE` writes the session down as it's played (see replay.rs)
+    if let Some(recorder) = replay::SessionRecorder::from_args() {
+        replay::add_recorder(&mut app, recorder);
+    }
+
+    // Hot-reload the curriculum file while authoring (native only)
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(watcher) = dag::CurriculumWatcher::for_source(&curriculum_source) {
+        app.insert_resource(watcher)
+            .add_systems(Update, dag::hot_reload_curriculum);
+    }
+
+    app.run();
 }
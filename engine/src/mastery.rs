@@ -0,0 +1,270 @@
+// Daydream Engine — Mastery Progression
+// The Great Recycler IS the progression:
+//   Encountered → Experienced → Owned → Mastered
+//
+// Play systems never touch mastery directly. They report what the
+// student did with a word (saw it, cast it, dug deeper, found a
+// partner, answered its depth question) as a SpellProgress message; this module turns those into
+// SpellPower, keeps the SpellBook in step, and announces level-ups.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use crate::components::*;
+use crate::recycler::ReviewClock;
+
+/// Something the student did with a word.
+#[derive(Message, Clone, Debug)]
+pub struct SpellProgress {
+    pub entity: Entity,
+    pub kind: ProgressKind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProgressKind {
+    /// The card was drawn into the hand.
+    Encountered,
+    /// The card was cast into a story moment.
+    Cast,
+    /// The student swiped "Deeper" on the card.
+    Deeper,
+    /// The card was skipped (the Recycler schedules it to return).
+    Skipped,
+    /// A synergy partner of this word was cast in the same session.
+    SynergyDiscovered { partner: String },
+    /// An Ally card resonated with this word in a story moment.
+    AllyBoost { ally: String },
+    /// The student typed an answer to the word's depth question.
+    Journaled { text: String },
+}
+
+/// Announced whenever a word reaches a new mastery level.
+#[derive(Message, Clone, Debug)]
+pub struct MasteryLevelUp {
+    pub entity: Entity,
+    pub word: String,
+    pub from: MasteryLevel,
+    pub to: MasteryLevel,
+}
+
+/// Thresholds for each mastery level. Each level also requires
+/// the one before it, so progress never skips a step.
+#[derive(Resource, Clone, Debug)]
+pub struct MasteryRules {
+    /// Experienced: "You've built a story with this word."
+    pub experienced_casts: u32,
+    /// ...or looked beneath its surface.
+    pub experienced_deeper: u32,
+    /// Owned: "This word keeps appearing in your stories."
+    pub owned_casts: u32,
+    /// ...or an Ally keeps standing beside it.
+    pub owned_ally_boosts: u32,
+    pub owned_appearances: u32,
+    /// Mastered: "This word resonates with others."
+    pub mastered_synergies: u32,
+    pub mastered_deeper: u32,
+}
+
+impl Default for MasteryRules {
+    fn default() -> Self {
+        Self {
+            experienced_casts: 1,
+            experienced_deeper: 1,
+            owned_casts: 2,
+            owned_ally_boosts: 1,
+            owned_appearances: 3,
+            mastered_synergies: 1,
+            mastered_deeper: 1,
+        }
+    }
+}
+
+impl MasteryRules {
+    /// The highest level this word's history has earned.
+    pub fn level_for(&self, power: &SpellPower) -> MasteryLevel {
+        let experienced = power.times_cast >= self.experienced_casts
+            || power.times_explored_deeper >= self.experienced_deeper;
+        let owned = experienced
+            && (power.times_cast >= self.owned_casts || power.ally_boosts >= self.owned_ally_boosts)
+            && power.times_encountered >= self.owned_appearances;
+        let mastered = owned
+            && power.synergies_discovered >= self.mastered_synergies
+            && power.times_explored_deeper >= self.mastered_deeper;
+
+        if mastered {
+            MasteryLevel::Mastered
+        } else if owned {
+            MasteryLevel::Owned
+        } else if experienced {
+            MasteryLevel::Experienced
+        } else {
+            MasteryLevel::Encountered
+        }
+    }
+}
+
+/// Where a word's progress is written down beyond its SpellPower.
+#[derive(SystemParam)]
+pub struct ProgressRecords<'w> {
+    spellbook: ResMut<'w, SpellBook>,
+    sheet: ResMut<'w, CharacterSheet>,
+    trail: ResMut<'w, StudentTrail>,
+}
+
+/// System: apply SpellProgress to SpellPower, sync the SpellBook,
+/// and announce any level-ups. A journal answer is kept in the
+/// SpellBook with the mastery the word had when it was written.
+pub fn apply_spell_progress(
+    mut progress: MessageReader<SpellProgress>,
+    mut level_ups: MessageWriter<MasteryLevelUp>,
+    rules: Res<MasteryRules>,
+    clock: Res<ReviewClock>,
+    mut words: Query<(&WordCard, &Channel, &mut SpellPower)>,
+    records: ProgressRecords,
+) {
+    let ProgressRecords { mut spellbook, mut sheet, mut trail } = records;
+    for event in progress.read() {
+        let Ok((word, channel, mut power)) = words.get_mut(event.entity) else { continue };
+
+        let mut answer = None;
+        match &event.kind {
+            ProgressKind::Encountered => power.times_encountered += 1,
+            ProgressKind::Cast => power.times_cast += 1,
+            ProgressKind::Deeper => {
+                power.times_explored_deeper += 1;
+                sheet.total_deeper_swipes += 1;
+            }
+            // A skip only reschedules the word; mastery never falls
+            ProgressKind::Skipped => {}
+            ProgressKind::SynergyDiscovered { partner } => {
+                if !power.discovered_partners.contains(partner) {
+                    power.discovered_partners.push(partner.clone());
+                    power.synergies_discovered += 1;
+                }
+            }
+            ProgressKind::AllyBoost { .. } => power.ally_boosts += 1,
+            // Answering the question is digging deeper too
+            ProgressKind::Journaled { text } => {
+                power.times_explored_deeper += 1;
+                sheet.total_deeper_swipes += 1;
+                answer = Some(JournalEntry { text: text.clone(), written_at: clock.now(), mastery: power.mastery });
+            }
+        }
+
+        // Mastery only ever rises — a quiet session never takes a word away
+        let from = power.mastery;
+        let earned = rules.level_for(&power);
+        if earned > from {
+            power.mastery = earned;
+            trail.mastery_gains.push((word.word.clone(), earned));
+            level_ups.write(MasteryLevelUp {
+                entity: event.entity,
+                word: word.word.clone(),
+                from,
+                to: earned,
+            });
+            info!("{} grew: {} → {}", word.word, from.label(), earned.label());
+        }
+
+        spellbook.sync_power(&word.word, *channel, &power);
+        if let Some(entry) = answer {
+            spellbook.write_journal(&word.word, entry);
+        }
+    }
+}
+
+/// Messages for every synergy partner of a just-cast word that has
+/// already been cast this session. Both words are credited.
+pub fn synergy_discoveries(
+    entity: Entity,
+    word: &str,
+    links: &SynergyLinks,
+    cast_words: &[String],
+    find_entity: impl Fn(&str) -> Option<Entity>,
+) -> Vec<SpellProgress> {
+    let mut found = Vec::new();
+    for entry in &links.links {
+        if entry.partner == word || !cast_words.contains(&entry.partner) {
+            continue;
+        }
+        found.push(SpellProgress {
+            entity,
+            kind: ProgressKind::SynergyDiscovered { partner: entry.partner.clone() },
+        });
+        if let Some(partner_entity) = find_entity(&entry.partner) {
+            found.push(SpellProgress {
+                entity: partner_entity,
+                kind: ProgressKind::SynergyDiscovered { partner: word.to_string() },
+            });
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels_require_the_previous_step() {
+        let rules = MasteryRules::default();
+        let mut power = SpellPower::default();
+        assert_eq!(rules.level_for(&power), MasteryLevel::Encountered);
+
+        power.times_explored_deeper = 1;
+        assert_eq!(rules.level_for(&power), MasteryLevel::Experienced);
+
+        // Synergies alone don't skip past Owned
+        power.synergies_discovered = 3;
+        assert_eq!(rules.level_for(&power), MasteryLevel::Experienced);
+
+        power.times_cast = 2;
+        power.times_encountered = 3;
+        assert_eq!(rules.level_for(&power), MasteryLevel::Mastered);
+    }
+
+    #[test]
+    fn test_an_ally_boost_is_not_a_cast() {
+        let mut app = App::new();
+        app.add_message::<SpellProgress>()
+            .add_message::<MasteryLevelUp>()
+            .init_resource::<MasteryRules>()
+            .init_resource::<ReviewClock>()
+            .init_resource::<SpellBook>()
+            .init_resource::<CharacterSheet>()
+            .init_resource::<StudentTrail>()
+            .add_systems(Update, apply_spell_progress);
+        let power = SpellPower { times_cast: 1, times_encountered: 3, ..default() };
+        let word = app.world_mut().spawn((WordCard::default(), Channel::Body, power)).id();
+
+        app.world_mut().write_message(SpellProgress { entity: word, kind: ProgressKind::AllyBoost { ally: "Patience".to_string() } });
+        app.update();
+        let power = app.world().get::<SpellPower>(word).unwrap();
+        assert_eq!((power.times_cast, power.ally_boosts), (1, 1));
+        // One cast with an Ally beside it is enough to own the word
+        assert_eq!(power.mastery, MasteryLevel::Owned);
+
+        let strict = MasteryRules { owned_ally_boosts: 2, ..default() };
+        assert_eq!(strict.level_for(power), MasteryLevel::Experienced);
+    }
+
+    #[test]
+    fn test_casting_a_partner_credits_both_words() {
+        let links = SynergyLinks {
+            links: vec![SynergyEntry {
+                partner: "Patience".to_string(),
+                synergy_type: SynergyType::Resonant,
+                bonus: String::new(),
+            }],
+        };
+        let me = Entity::from_raw_u32(1).unwrap();
+        let partner = Entity::from_raw_u32(2).unwrap();
+
+        let none = synergy_discoveries(me, "Presence", &links, &[], |_| Some(partner));
+        assert!(none.is_empty());
+
+        let cast = vec!["Patience".to_string(), "Presence".to_string()];
+        let found = synergy_discoveries(me, "Presence", &links, &cast, |_| Some(partner));
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[1].entity, partner);
+    }
+}
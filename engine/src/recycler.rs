@@ -0,0 +1,314 @@
+// Daydream Engine — The Recycler
+// Played cards don't disappear. They rest in the Recycler and come
+// back later, evolved. Underneath, this is spaced repetition (SM-2):
+//
+//   cast                  → a good review    (quality 4)
+//   dug deeper, then cast → a perfect review (quality 5)
+//   skipped               → a lapse          (quality 1)
+//
+// After a good review a word rests 1 day, then 6, then its last
+// interval × its ease — or the time it actually stayed away, if that
+// was longer. Mastery stretches the rest: new words return sooner,
+// mastered ones later. A lapse starts the word over and lets it come
+// back this same session, once, when the deck runs dry.
+//
+// A session deals new words and words that are due. If too few are
+// ready, the soonest-due words top the deck up, so there's always a
+// story to tell. The schedule is saved with the rest of the progress.
+//
+// A returning word tells a richer story the better the student knows it.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use crate::components::*;
+use crate::locale::ActiveLocale;
+use crate::mastery::{ProgressKind, SpellProgress};
+use crate::rng::SessionRng;
+
+pub const DAY_SECS: u64 = 86_400;
+/// SM-2's floor: below this a word would return too often to feel fresh.
+const MIN_EASE: f32 = 1.3;
+/// A session deals at least this many words (when the curriculum has them).
+pub const MIN_SESSION_DECK: usize = 5;
+
+/// When a word is next due, and how its reviews have gone.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct ReviewCard {
+    pub ease: f32,
+    pub interval_days: f32,
+    /// Good reviews in a row.
+    pub repetitions: u32,
+    /// Unix seconds.
+    pub last_seen: u64,
+    pub due: u64,
+}
+
+impl Default for ReviewCard {
+    fn default() -> Self {
+        Self { ease: 2.5, interval_days: 0.0, repetitions: 0, last_seen: 0, due: 0 }
+    }
+}
+
+impl ReviewCard {
+    pub fn is_due(&self, now: u64) -> bool {
+        self.due <= now
+    }
+
+    /// Grade a review (quality 0–5) and schedule the next one.
+    pub fn review(&mut self, quality: u8, mastery: MasteryLevel, now: u64) {
+        let q = quality.min(5) as f32;
+        self.ease = (self.ease + 0.1 - (5.0 - q) * (0.08 + (5.0 - q) * 0.02)).max(MIN_EASE);
+
+        if quality < 3 {
+            self.repetitions = 0;
+            self.interval_days = 0.0;
+        } else {
+            let away_days = now.saturating_sub(self.last_seen) as f32 / DAY_SECS as f32;
+            self.repetitions += 1;
+            self.interval_days = match self.repetitions {
+                1 => 1.0,
+                2 => 6.0,
+                _ => self.interval_days.max(away_days) * self.ease,
+            };
+        }
+        self.last_seen = now;
+        let rest = self.interval_days * mastery_stretch(mastery);
+        self.due = now + (rest * DAY_SECS as f32) as u64;
+    }
+}
+
+/// How much longer a word rests at each mastery level.
+fn mastery_stretch(mastery: MasteryLevel) -> f32 {
+    match mastery {
+        MasteryLevel::Encountered => 0.5,
+        MasteryLevel::Experienced => 1.0,
+        MasteryLevel::Owned       => 1.5,
+        MasteryLevel::Mastered    => 2.0,
+    }
+}
+
+/// Resource: every word's review schedule, by word. Persisted.
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(transparent)]
+pub struct ReviewSchedule {
+    pub cards: BTreeMap<String, ReviewCard>,
+}
+
+impl ReviewSchedule {
+    /// New words (never scheduled) are always ready.
+    pub fn is_due(&self, word: &str, now: u64) -> bool {
+        self.cards.get(word).is_none_or(|c| c.is_due(now))
+    }
+
+    /// Split a session's words into the deck and those still resting.
+    /// Too few ready words are topped up with the soonest due.
+    pub fn deal(&self, words: &[(Entity, &str)], now: u64) -> (Vec<Entity>, Vec<Entity>) {
+        let (ready, mut resting): (Vec<&(Entity, &str)>, Vec<_>) = words.iter().partition(|(_, w)| self.is_due(w, now));
+        let mut deck: Vec<Entity> = ready.iter().map(|w| w.0).collect();
+
+        resting.sort_by_key(|(_, w)| self.cards.get(*w).map_or(0, |c| c.due));
+        let top_up = MIN_SESSION_DECK.saturating_sub(deck.len()).min(resting.len());
+        deck.extend(resting.drain(..top_up).map(|w| w.0));
+        (deck, resting.iter().map(|w| w.0).collect())
+    }
+}
+
+/// Resource: the wall clock, or a fixed time for simulations.
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub enum ReviewClock {
+    #[default]
+    Wall,
+    /// Unix seconds.
+    Fixed(u64),
+}
+
+impl ReviewClock {
+    pub fn now(&self) -> u64 {
+        match self {
+            ReviewClock::Wall => crate::rng::unix_now().as_secs(),
+            ReviewClock::Fixed(secs) => *secs,
+        }
+    }
+}
+
+/// Resource: this session's Recycler bookkeeping.
+#[derive(Resource, Default, Debug)]
+pub struct RecyclerSession {
+    /// Cards already brought back this session (each returns once).
+    pub returned: Vec<Entity>,
+    /// Cards the student dug deeper on this session.
+    pub explored: Vec<Entity>,
+}
+
+// ─── SYSTEMS ────────────────────────────────────────────────
+
+/// System: grade each cast or skip and reschedule the word.
+/// Runs after mastery, so a cast that levels a word up rests longer.
+pub fn schedule_reviews(
+    mut progress: MessageReader<SpellProgress>,
+    words: Query<(&WordCard, &SpellPower)>,
+    clock: Res<ReviewClock>,
+    mut schedule: ResMut<ReviewSchedule>,
+    mut session: ResMut<RecyclerSession>,
+) {
+    let now = clock.now();
+    for event in progress.read() {
+        let quality = match event.kind {
+            ProgressKind::Deeper => {
+                session.explored.push(event.entity);
+                continue;
+            }
+            ProgressKind::Cast if session.explored.contains(&event.entity) => 5,
+            ProgressKind::Cast => 4,
+            ProgressKind::Skipped => 1,
+            _ => continue,
+        };
+        let Ok((word, power)) = words.get(event.entity) else { continue };
+        schedule.cards.entry(word.word.clone()).or_default().review(quality, power.mastery, now);
+    }
+}
+
+/// System: when the deck runs dry, skipped words that are due come
+/// back into it, reshuffled. Each returns once per session.
+/// Runs on entering Drawing, before the draw.
+pub fn recycle_due_cards(
+    mut deck: ResMut<Deck>,
+    mut discard: ResMut<DiscardPile>,
+    mut session: ResMut<RecyclerSession>,
+    mut rng: ResMut<SessionRng>,
+    schedule: Res<ReviewSchedule>,
+    clock: Res<ReviewClock>,
+    words: Query<&WordCard>,
+) {
+    if !deck.cards.is_empty() {
+        return;
+    }
+    let now = clock.now();
+    let (mut due, resting): (Vec<Entity>, Vec<Entity>) = discard.cards.iter().copied().partition(|e| {
+        !session.returned.contains(e)
+            && words.get(*e).is_ok_and(|w| schedule.is_due(&w.word, now))
+    });
+    if due.is_empty() {
+        return;
+    }
+    rng.shuffle(&mut due);
+    info!("The Recycler returns {} word(s) to the deck", due.len());
+    session.returned.extend(&due);
+    discard.cards = resting;
+    deck.cards = due;
+}
+
+/// A word's story beat, grown with the student's mastery of it, told
+/// in the student's language; `name` gives a partner word's name.
+pub fn recycled_story(
+    beat: &str,
+    word: &WordCard,
+    power: &SpellPower,
+    locale: &ActiveLocale,
+    name: impl Fn(&str) -> String,
+) -> String {
+    let word_name = word.name.to_uppercase();
+    let echo = match power.mastery {
+        MasteryLevel::Encountered => return beat.to_string(),
+        MasteryLevel::Experienced => locale.format("recycle.experienced", &[("word", &word_name)]),
+        MasteryLevel::Owned if !word.themes.is_empty() => {
+            locale.format("recycle.owned.themes", &[("word", &word_name), ("themes", &word.themes.join(", "))])
+        }
+        MasteryLevel::Owned => locale.format("recycle.owned", &[("word", &word_name)]),
+        MasteryLevel::Mastered if !power.discovered_partners.is_empty() => {
+            let partners: Vec<String> = power.discovered_partners.iter().map(|p| name(p)).collect();
+            locale.format("recycle.mastered.partners", &[("word", &word_name), ("partners", &partners.join(", "))])
+        }
+        MasteryLevel::Mastered => locale.format("recycle.mastered", &[("word", &word_name)]),
+    };
+    format!("{}\n{}", beat, echo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1_700_000_000;
+
+    #[test]
+    fn test_good_reviews_space_out_and_lapses_return_now() {
+        let mut card = ReviewCard::default();
+        card.review(4, MasteryLevel::Experienced, NOW);
+        assert_eq!(card.due, NOW + DAY_SECS);
+
+        card.review(4, MasteryLevel::Experienced, NOW + DAY_SECS);
+        assert_eq!(card.due, NOW + 7 * DAY_SECS);
+
+        // Third review: 6 days × ease, stretched for an Owned word
+        card.review(5, MasteryLevel::Owned, NOW + 7 * DAY_SECS);
+        assert!(card.interval_days > 6.0 * 2.5);
+        assert!(card.due > NOW + 7 * DAY_SECS + 22 * DAY_SECS);
+
+        let ease = card.ease;
+        card.review(1, MasteryLevel::Owned, NOW + 40 * DAY_SECS);
+        assert_eq!(card.repetitions, 0);
+        assert!(card.is_due(NOW + 40 * DAY_SECS));
+        assert!(card.ease < ease);
+    }
+
+    #[test]
+    fn test_long_absence_counts_toward_the_interval() {
+        let mut card = ReviewCard { repetitions: 2, interval_days: 6.0, last_seen: NOW, ..default() };
+        card.review(4, MasteryLevel::Experienced, NOW + 30 * DAY_SECS);
+        assert!(card.interval_days >= 30.0 * MIN_EASE);
+    }
+
+    #[test]
+    fn test_deal_holds_back_resting_words_but_never_runs_short() {
+        let mut schedule = ReviewSchedule::default();
+        let words: Vec<(Entity, String)> = (0..8)
+            .map(|i| (Entity::from_raw_u32(i + 1).unwrap(), format!("W{}", i)))
+            .collect();
+        // W0..W5 rest for i+1 days; W6, W7 are new
+        for (i, (_, word)) in words.iter().enumerate().take(6) {
+            schedule.cards.insert(word.clone(), ReviewCard { due: NOW + (i as u64 + 1) * DAY_SECS, ..default() });
+        }
+        let named: Vec<(Entity, &str)> = words.iter().map(|(e, w)| (*e, w.as_str())).collect();
+
+        // Two days on: W0 is due, plus the two new words; two more top up
+        let (deck, resting) = schedule.deal(&named, NOW + DAY_SECS);
+        assert_eq!(deck.len(), MIN_SESSION_DECK);
+        assert!(deck.contains(&words[0].0) && deck.contains(&words[6].0) && deck.contains(&words[7].0));
+        assert!(deck.contains(&words[1].0) && deck.contains(&words[2].0));
+        assert_eq!(resting.len(), 3);
+
+        // Much later, everything is ready
+        let (deck, resting) = schedule.deal(&named, NOW + 30 * DAY_SECS);
+        assert_eq!((deck.len(), resting.len()), (8, 0));
+    }
+
+    #[test]
+    fn test_richer_story_at_higher_mastery() {
+        let word = WordCard {
+            word: "Patience".to_string(),
+            name: "Patience".to_string(),
+            depth_prompt: String::new(),
+            themes: vec!["waiting".to_string()],
+            story_text: String::new(),
+            localized_story: false,
+        };
+        let en = ActiveLocale::default();
+        let story = |power: &SpellPower| recycled_story("Beat.", &word, power, &en, |w| w.to_string());
+        let mut power = SpellPower::default();
+        assert_eq!(story(&power), "Beat.");
+
+        power.mastery = MasteryLevel::Owned;
+        assert!(story(&power).contains("waiting"));
+
+        power.mastery = MasteryLevel::Mastered;
+        power.discovered_partners = vec!["Presence".to_string()];
+        assert!(story(&power).contains("Presence"));
+
+        // In Spanish, partners go by their Spanish names
+        let es = ActiveLocale::load("es", &crate::dag::CurriculumSource::Demo);
+        let told = recycled_story("Beat.", &word, &power, &es, |_| "Presencia".to_string());
+        assert!(told.ends_with("PATIENCE vibra con todo lo que ha tocado: Presencia."), "{}", told);
+    }
+}
@@ -10,18 +10,44 @@
 // swaps the active word, and the story/setting adapts. This
 // teaches that CONTEXT changes MEANING — the core VAAM insight.
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
-use bevy::text::Justify;
+use bevy::sprite::Anchor;
+use bevy::text::{Justify, TextBounds};
+use std::collections::HashMap;
 use crate::components::*;
+use crate::archetype::ArchetypeReading;
+use crate::focus::{FocusRules, RestPoint};
+use crate::input::JournalDraft;
+use crate::locale::ActiveLocale;
+use crate::mastery::MasteryLevelUp;
 
 
 // ─── MARKER COMPONENTS ──────────────────────────────────────────
 
+/// Root of the retained play view. Every play visual is its child,
+/// so hiding the root hides the whole Triple Sandwich.
 #[derive(Component)]
-pub struct CardFrame;
+pub struct PlayView;
 
+/// The active word card at the top of the play view; hidden until
+/// a word has been cast
 #[derive(Component)]
-pub struct CardWordText;
+pub struct ActiveCard;
+
+/// A part of the active word card
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ActiveCardPart {
+    Frame,
+    Ring,
+    Fill,
+    Glow,
+    Kind,
+    Word,
+    Label,
+    Themes,
+    Question,
+}
 
 #[derive(Component)]
 pub struct StoryText;
@@ -35,24 +61,81 @@ pub struct DepthOverlay;
 #[derive(Component)]
 pub struct DepthText;
 
+/// The answer being typed in the depth view
+#[derive(Component)]
+pub struct JournalDraftText;
+
 #[derive(Component)]
 pub struct SwipeHint;
 
 #[derive(Component)]
 pub struct TrailElement;
 
-/// Marks a visual entity as part of the HUD (deck counter, channel bars)
-#[derive(Component)]
-pub struct HudElement;
+/// Marks a visual entity as part of the HUD, and which part it shows
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HudElement {
+    DeckCount,
+    Chapter,
+    Setting,
+    Attunement,
+    Focus,
+}
 
-/// Marks a visual entity as part of the hand display
+/// A card in the hand display, showing one word entity. The card
+/// keeps its visual for as long as the word stays in the hand; its
+/// place in the fan comes from `HandSlot`, its highlight from `Selected`.
+#[derive(Component, Debug)]
+pub struct HandCardVisual {
+    pub card: Entity,
+    /// Border color when not selected (the card's channel style)
+    pub border: Color,
+}
+
+/// The keyboard shortcut number on a hand card
 #[derive(Component)]
-pub struct HandCardVisual;
+pub struct HandSlotNumber;
+
+/// Eases a visual toward a target transform. A leaving card
+/// (cast or discarded) despawns when it arrives.
+#[derive(Component, Debug)]
+pub struct CardMotion {
+    pub target: Transform,
+    pub leaving: bool,
+}
 
 /// Marks a visual entity as the synergy indicator
 #[derive(Component)]
 pub struct SynergyIndicator;
 
+/// Part of a crafting-table slot, and which slot it belongs to
+#[derive(Component, Clone, Copy, Debug)]
+pub struct StorySlotVisual {
+    pub slot: StorySlot,
+    pub part: SlotPart,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlotPart {
+    Border,
+    Content,
+}
+
+/// Marks the branch point overlay
+#[derive(Component)]
+pub struct BranchOverlay;
+
+/// Marks the rest point overlay
+#[derive(Component)]
+pub struct RestOverlay;
+
+/// The breath counter on the rest point overlay
+#[derive(Component)]
+pub struct RestProgressText;
+
+/// A short-lived "word grew" notice; despawns when its timer ends.
+#[derive(Component)]
+pub struct MasteryToast(pub Timer);
+
 // ─── SETUP ──────────────────────────────────────────────────────
 
 /// System: set up camera
@@ -60,305 +143,640 @@ pub fn setup_camera(mut commands: Commands) {
     commands.spawn(Camera2d);
 }
 
-// ─── HAND RENDERING ─────────────────────────────────────────────
-
-/// System: spawn the full play view — active card, story, hand, HUD.
-/// Runs on entering the Playing state.
-pub fn spawn_play_view(
-    mut commands: Commands,
-    hand: Res<Hand>,
-    deck: Res<Deck>,
-    slide: Res<CurrentSlide>,
-    trail: Res<StudentTrail>,
-    sheet: Res<CharacterSheet>,
-    synergies: Res<ActiveSynergies>,
-    words: Query<(&WordCard, &Channel, &Stage, &CardStyle, &Setting, &SpellPower)>,
-    // Clean up any old visuals first
-    old_visuals: Query<Entity, Or<(
-        With<CardFrame>, With<CardWordText>, With<StoryText>,
-        With<SettingBackground>, With<SwipeHint>, With<HudElement>,
-        With<HandCardVisual>, With<SynergyIndicator>,
-    )>>,
-) {
-    // Despawn previous frame
-    for e in old_visuals.iter() {
-        commands.entity(e).despawn();
+// ─── PLAY VIEW ──────────────────────────────────────────────────
+//
+// The play view is retained: its entities are spawned once, and each
+// update system below rewrites only the parts whose source changed —
+// the active card follows the trail and the word's SpellPower, the
+// story text follows CurrentSlide, the synergy line ActiveSynergies,
+// and the hand follows Hand. A hand card keeps its entity while its
+// word stays in the hand, so draws, casts and discards can animate.
+
+const ACTIVE_CARD_Y: f32 = 200.0;
+const SLOT_SPACING: f32 = 145.0;
+const SLOT_Y: f32 = 88.0;
+const HAND_Y: f32 = -180.0;
+const HAND_CARD_SIZE: Vec2 = Vec2::new(90.0, 120.0);
+/// The hand fans along an arc of this radius...
+const FAN_RADIUS: f32 = 900.0;
+/// ...with this many radians between neighbouring cards.
+const FAN_STEP: f32 = 0.12;
+/// Where a drawn card appears from: the deck counter.
+const DECK_POSITION: Vec3 = Vec3::new(-280.0, 300.0, 3.0);
+/// How quickly cards ease toward their target (per second)
+const CARD_EASE: f32 = 12.0;
+
+/// Where a hand card sits in the fan. The selected card stands up
+/// straight, lifts and grows, and draws over its neighbours.
+pub fn fan_transform(slot: usize, count: usize, selected: bool) -> Transform {
+    let offset = slot as f32 - (count.max(1) - 1) as f32 / 2.0;
+    let angle = offset * FAN_STEP;
+    let x = FAN_RADIUS * angle.sin();
+    let y = HAND_Y - FAN_RADIUS * (1.0 - angle.cos());
+    if selected {
+        Transform::from_xyz(x, y + 20.0, 4.6).with_scale(Vec3::splat(1.15))
+    } else {
+        Transform::from_xyz(x, y, 3.0 + slot as f32 * 0.3)
+            .with_rotation(Quat::from_rotation_z(-angle))
     }
+}
 
-    // Get the active word (first card played / current context)
-    let active_entity = trail.current_word;
-    let active_data = active_entity.and_then(|e| words.get(e).ok());
+const CARD_FILL: Color = Color::srgba(0.06, 0.06, 0.1, 0.95);
+/// The gold band inside an Ally card's border
+const ALLY_RING: Color = Color::srgba(1.0, 0.85, 0.4, 0.9);
+
+/// Each card type has its own frame: a Spell a thin border in its
+/// channel color, a Setting a wide one like a window onto a scene,
+/// an Ally a thin border with a gold band inside it. Returns the
+/// Ally band's size, if any, and the inner fill's size.
+fn frame_layers(size: Vec2, border: f32, card_type: CardType) -> (Option<Vec2>, Vec2) {
+    match card_type {
+        CardType::Spell => (None, size - 2.0 * border),
+        CardType::Setting => (None, size - 6.0 * border),
+        CardType::Ally => (Some(size - 2.0 * border), size - 4.0 * border),
+    }
+}
 
-    // ─── SETTING BACKGROUND (full screen tint) ──────────────
-    let bg_color = active_data
-        .map(|(_, ch, _, _, _, _)| ch.background_color())
-        .unwrap_or(Color::srgba(0.04, 0.04, 0.08, 1.0));
+/// The type tag at the top of a card; Spell cards need none.
+fn kind_tag(card_type: CardType, locale: &ActiveLocale) -> String {
+    match card_type {
+        CardType::Spell => String::new(),
+        _ => locale.card_type(card_type).to_uppercase(),
+    }
+}
 
-    commands.spawn((
-        Sprite {
-            color: bg_color,
-            custom_size: Some(Vec2::new(2000.0, 2000.0)),
-            ..default()
-        },
-        Transform::from_xyz(0.0, 0.0, -10.0),
-        SettingBackground,
-    ));
+/// Crafting-table position of a story slot
+fn slot_position(slot: StorySlot) -> Vec2 {
+    let i = StorySlot::ALL.iter().position(|s| *s == slot).unwrap_or(1);
+    Vec2::new((i as f32 - 1.0) * SLOT_SPACING, SLOT_Y)
+}
 
-    // ─── TOP: Active Word Card ──────────────────────────────
-    if let Some((word, channel, stage, style, _setting, power)) = active_data {
-        let card_w = 300.0;
-        let card_h = 160.0;
-        let card_y = 200.0;
+/// System: spawn the play view's persistent entities — background,
+/// active card, crafting table, story, synergy line, hint and HUD.
+/// Their content is filled in by the update systems.
+pub fn spawn_play_view(mut commands: Commands, locale: Res<ActiveLocale>) {
+    let text = |size: f32, color: Color, pos: Vec3| (
+        Text2d::default(),
+        TextFont::from_font_size(size),
+        TextColor(color),
+        Transform::from_translation(pos),
+    );
 
-        // Card border (channel color)
-        commands.spawn((
+    commands.spawn((PlayView, Transform::default(), Visibility::default())).with_children(|view| {
+        // ─── SETTING BACKGROUND (full screen tint) ──────────
+        view.spawn((
             Sprite {
-                color: style.color,
-                custom_size: Some(Vec2::new(card_w, card_h)),
+                color: Color::srgba(0.04, 0.04, 0.08, 1.0),
+                custom_size: Some(Vec2::new(2000.0, 2000.0)),
                 ..default()
             },
-            Transform::from_xyz(0.0, card_y, 1.0),
-            CardFrame,
+            Transform::from_xyz(0.0, 0.0, -10.0),
+            SettingBackground,
         ));
 
-        // Card inner fill
-        commands.spawn((
-            Sprite {
-                color: Color::srgba(0.06, 0.06, 0.1, 0.95),
-                custom_size: Some(Vec2::new(card_w - 8.0, card_h - 8.0)),
-                ..default()
-            },
-            Transform::from_xyz(0.0, card_y, 1.5),
-            CardFrame,
-        ));
+        // ─── TOP: Active Word Card ──────────────────────────
+        let card = Vec2::new(300.0, 160.0);
+        view.spawn((
+            ActiveCard,
+            Transform::from_xyz(0.0, ACTIVE_CARD_Y, 0.0),
+            Visibility::Hidden,
+        )).with_children(|active| {
+            // Mastery glow (subtle outline brightens with mastery)
+            active.spawn((
+                Sprite::from_color(Color::NONE, card + 6.0),
+                Transform::from_xyz(0.0, 0.0, 0.9),
+                ActiveCardPart::Glow,
+            ));
+            // Card border (channel color)
+            active.spawn((
+                Sprite::from_color(Color::NONE, card),
+                Transform::from_xyz(0.0, 0.0, 1.0),
+                ActiveCardPart::Frame,
+            ));
+            // Ally ring and card inner fill, sized by the card type
+            active.spawn((
+                Sprite::from_color(Color::NONE, card),
+                Transform::from_xyz(0.0, 0.0, 1.2),
+                ActiveCardPart::Ring,
+            ));
+            active.spawn((
+                Sprite::from_color(CARD_FILL, card - 8.0),
+                Transform::from_xyz(0.0, 0.0, 1.5),
+                ActiveCardPart::Fill,
+            ));
+            // Card type tag, word name, channel + stage label, theme
+            // tags, and the channel question (the soul of this card type)
+            active.spawn((
+                text(9.0, Color::srgba(1.0, 1.0, 1.0, 0.4), Vec3::new(0.0, 58.0, 2.0)),
+                ActiveCardPart::Kind,
+            ));
+            active.spawn((text(32.0, Color::WHITE, Vec3::new(0.0, 22.0, 2.0)), ActiveCardPart::Word));
+            active.spawn((text(11.0, Color::WHITE, Vec3::new(0.0, 0.0, 2.0)), ActiveCardPart::Label));
+            active.spawn((
+                text(11.0, Color::srgba(1.0, 1.0, 1.0, 0.35), Vec3::new(0.0, -18.0, 2.0)),
+                ActiveCardPart::Themes,
+            ));
+            active.spawn((
+                text(10.0, Color::srgba(1.0, 1.0, 1.0, 0.15), Vec3::new(0.0, -35.0, 2.0)),
+                ActiveCardPart::Question,
+            ));
+        });
+
+        // ─── CRAFTING TABLE: Setting / Subject / Action slots ───
+        let slot_size = Vec2::new(130.0, 44.0);
+        for slot in StorySlot::ALL {
+            let at = slot_position(slot);
+            view.spawn((
+                Sprite::from_color(Color::NONE, slot_size),
+                Transform::from_translation(at.extend(1.0)),
+                StorySlotVisual { slot, part: SlotPart::Border },
+            ));
+            view.spawn((
+                Sprite::from_color(Color::srgba(0.05, 0.05, 0.09, 0.95), slot_size - 4.0),
+                Transform::from_translation(at.extend(1.5)),
+            ));
 
-        // Mastery glow (subtle outline brightens with mastery)
-        let glow_alpha = match power.mastery {
-            MasteryLevel::Encountered => 0.0,
-            MasteryLevel::Experienced => 0.1,
-            MasteryLevel::Owned => 0.25,
-            MasteryLevel::Mastered => 0.5,
-        };
-        if glow_alpha > 0.0 {
-            commands.spawn((
-                Sprite {
-                    color: Color::srgba(1.0, 0.95, 0.6, glow_alpha),
-                    custom_size: Some(Vec2::new(card_w + 6.0, card_h + 6.0)),
-                    ..default()
-                },
-                Transform::from_xyz(0.0, card_y, 0.9),
-                CardFrame,
+            // Slot type: "◆ SETTING"
+            let mut label = text(9.0, Color::srgba(1.0, 1.0, 1.0, 0.35), (at + Vec2::new(0.0, 11.0)).extend(2.0));
+            label.0 = Text2d::new(format!("{} {}", slot.symbol().icon(), locale.slot(slot).to_uppercase()));
+            view.spawn(label);
+
+            view.spawn((
+                text(12.0, Color::WHITE, (at + Vec2::new(0.0, -6.0)).extend(2.0)),
+                StorySlotVisual { slot, part: SlotPart::Content },
             ));
         }
 
-        // Word name (large, centered)
-        commands.spawn((
-            Text2d::new(word.word.to_uppercase()),
-            TextFont::from_font_size(32.0),
-            TextColor(Color::WHITE),
-            Transform::from_xyz(0.0, card_y + 22.0, 2.0),
-            CardWordText,
+        // ─── MIDDLE: Story Text ─────────────────────────────
+        view.spawn((
+            text(17.0, Color::srgba(1.0, 1.0, 1.0, 0.8), Vec3::new(0.0, 10.0, 2.0)),
+            TextLayout::new_with_justify(Justify::Center),
+            TextBounds::new_horizontal(560.0),
+            StoryText,
         ));
 
-        // Channel + Stage label
-        let label = format!(
-            "{} {} {}",
-            channel.label(), stage.stars(), stage.label()
-        );
-        commands.spawn((
-            Text2d::new(label),
-            TextFont::from_font_size(11.0),
-            TextColor(channel.color()),
-            Transform::from_xyz(0.0, card_y, 2.0),
-            CardWordText,
+        // ─── SYNERGY INDICATOR ──────────────────────────────
+        view.spawn((
+            text(11.0, Color::srgba(1.0, 0.85, 0.3, 0.7), Vec3::new(0.0, -70.0, 2.0)),
+            TextLayout::new_with_justify(Justify::Center),
+            SynergyIndicator,
         ));
 
-        // Theme tags
-        let themes_str = word.themes.join(" · ");
-        commands.spawn((
-            Text2d::new(themes_str),
-            TextFont::from_font_size(11.0),
-            TextColor(Color::srgba(1.0, 1.0, 1.0, 0.35)),
-            Transform::from_xyz(0.0, card_y - 18.0, 2.0),
-            CardWordText,
+        // ─── SWIPE HINTS ────────────────────────────────────
+        view.spawn((
+            text(11.0, Color::srgba(1.0, 1.0, 1.0, 0.2), Vec3::new(0.0, -270.0, 2.0)),
+            TextLayout::new_with_justify(Justify::Center),
+            SwipeHint,
         ));
 
-        // Channel question (the soul of this card type)
-        commands.spawn((
-            Text2d::new(channel.question()),
-            TextFont::from_font_size(10.0),
-            TextColor(Color::srgba(1.0, 1.0, 1.0, 0.15)),
-            Transform::from_xyz(0.0, card_y - 35.0, 2.0),
-            CardWordText,
+        // ─── HUD: Top bar ───────────────────────────────────
+        // Deck counter (top-left), with the genre + mood under it;
+        // chapter heading (top-center); attunement bars and the
+        // Hero's focus meter (top-right)
+        view.spawn((text(13.0, Color::srgba(1.0, 1.0, 1.0, 0.4), Vec3::new(-280.0, 300.0, 2.0)), HudElement::DeckCount));
+        view.spawn((text(10.0, Color::srgba(1.0, 1.0, 1.0, 0.25), Vec3::new(-260.0, 282.0, 2.0)), HudElement::Setting));
+        view.spawn((
+            text(11.0, Color::srgba(1.0, 0.9, 0.7, 0.4), Vec3::new(0.0, 300.0, 2.0)),
+            TextLayout::new_with_justify(Justify::Center),
+            HudElement::Chapter,
         ));
+        view.spawn((text(10.0, Color::srgba(1.0, 1.0, 1.0, 0.35), Vec3::new(180.0, 300.0, 2.0)), HudElement::Attunement));
+        view.spawn((text(10.0, Color::WHITE, Vec3::new(180.0, 282.0, 2.0)), HudElement::Focus));
+    });
+}
+
+/// System: hide the play view under the trail review
+pub fn hide_play_view(mut view: Single<&mut Visibility, With<PlayView>>) {
+    **view = Visibility::Hidden;
+}
+
+/// System: show the play view again when a new session starts
+pub fn show_play_view(mut view: Single<&mut Visibility, With<PlayView>>) {
+    **view = Visibility::Inherited;
+}
+
+/// System: tint the background with the active word's Setting —
+/// its channel's backdrop, or the atmosphere of a Setting card
+pub fn update_setting_background(
+    trail: Res<StudentTrail>,
+    settings: Query<Ref<Setting>>,
+    mut background: Single<&mut Sprite, With<SettingBackground>>,
+) {
+    let setting = trail.current_word.and_then(|e| settings.get(e).ok());
+    if !trail.is_changed() && !setting.as_ref().is_some_and(|s| s.is_changed()) { return; }
+    background.color = setting.map_or(Color::srgba(0.04, 0.04, 0.08, 1.0), |s| s.background_color);
+}
+
+/// System: show the active word on the top card. Redrawn when the
+/// trail moves to another word or the word's SpellPower changes.
+pub fn update_active_card(
+    trail: Res<StudentTrail>,
+    locale: Res<ActiveLocale>,
+    words: Query<(&WordCard, &Channel, &Stage, &CardStyle, &CardType)>,
+    powers: Query<Ref<SpellPower>>,
+    mut card: Single<&mut Visibility, With<ActiveCard>>,
+    mut sprites: Query<(&ActiveCardPart, &mut Sprite)>,
+    mut texts: Query<(&ActiveCardPart, &mut Text2d, &mut TextColor)>,
+) {
+    let active = trail.current_word.and_then(|e| Some((words.get(e).ok()?, powers.get(e).ok()?)));
+    let power_changed = active.as_ref().is_some_and(|(_, power)| power.is_changed());
+    if !trail.is_changed() && !locale.is_changed() && !power_changed { return; }
+
+    let Some(((word, channel, stage, style, card_type), power)) = active else {
+        **card = Visibility::Hidden;
+        return;
+    };
+    **card = Visibility::Inherited;
+
+    let (ring, fill) = frame_layers(Vec2::new(300.0, 160.0), 4.0, *card_type);
+    for (part, mut sprite) in &mut sprites {
+        sprite.color = match part {
+            ActiveCardPart::Frame => style.color,
+            ActiveCardPart::Ring => match ring {
+                Some(size) => {
+                    sprite.custom_size = Some(size);
+                    ALLY_RING
+                }
+                None => Color::NONE,
+            },
+            ActiveCardPart::Fill => {
+                sprite.custom_size = Some(fill);
+                CARD_FILL
+            }
+            ActiveCardPart::Glow => {
+                let alpha = match power.mastery {
+                    MasteryLevel::Encountered => 0.0,
+                    MasteryLevel::Experienced => 0.1,
+                    MasteryLevel::Owned => 0.25,
+                    MasteryLevel::Mastered => 0.5,
+                };
+                Color::srgba(1.0, 0.95, 0.6, alpha)
+            }
+            _ => continue,
+        };
     }
+    for (part, mut text, mut color) in &mut texts {
+        text.0 = match part {
+            ActiveCardPart::Kind => kind_tag(*card_type, &locale),
+            ActiveCardPart::Word => word.name.to_uppercase(),
+            ActiveCardPart::Label => {
+                color.0 = channel.color();
+                format!(
+                    "{} {} {} · {}",
+                    locale.channel(*channel), stage.stars(), locale.stage(*stage), locale.rarity(*stage)
+                )
+            }
+            ActiveCardPart::Themes => word.themes.join(" · "),
+            ActiveCardPart::Question => locale.channel_question(*channel).to_string(),
+            _ => continue,
+        };
+    }
+}
 
-    // ─── MIDDLE: Story Text ─────────────────────────────────
-    commands.spawn((
-        Text2d::new(&slide.story_text),
-        TextFont::from_font_size(17.0),
-        TextColor(Color::srgba(1.0, 1.0, 1.0, 0.8)),
-        TextLayout::new_with_justify(Justify::Center),
-        Transform::from_xyz(0.0, 30.0, 2.0),
-        StoryText,
-    ));
+/// System: redraw the crafting-table slots when the moment changes
+pub fn update_story_slots(
+    moment: Res<StoryMoment>,
+    mut borders: Query<(&StorySlotVisual, &mut Sprite)>,
+    mut contents: Query<(&StorySlotVisual, &mut Text2d)>,
+    words: Query<&WordCard>,
+) {
+    if !moment.is_changed() { return; }
+
+    // Slot border — glowing when resonating, warm when filled,
+    // faint when open, red-tinted when dissonant
+    for (visual, mut sprite) in &mut borders {
+        sprite.color = match moment.slot(visual.slot) {
+            Some(card) if moment.is_resonating(&card.word) => Color::srgba(1.0, 0.8, 0.2, 1.0),
+            Some(card) if !card.fits => Color::srgba(0.8, 0.35, 0.35, 0.8),
+            Some(_) => Color::srgba(1.0, 0.85, 0.5, 0.8),
+            None => Color::srgba(1.0, 1.0, 1.0, 0.15),
+        };
+    }
+    // Placed word (with any △ modifiers), or an empty line
+    let name = |word: &str| words.iter().find(|w| w.word == word).map_or_else(|| word.to_string(), |w| w.name.clone());
+    for (visual, mut text) in &mut contents {
+        text.0 = if moment.slot(visual.slot).is_some() {
+            moment.slot_phrase_in(visual.slot, name).trim_matches(|c| c == '[' || c == ']').to_string()
+        } else {
+            "—".to_string()
+        };
+    }
+}
 
-    // ─── SYNERGY INDICATOR ──────────────────────────────────
-    if !synergies.synergies.is_empty() {
-        let syn_text: Vec<String> = synergies.synergies.iter()
-            .map(|s| format!("⚡ {} + {} = +{}", s.source_word, s.target_word, s.bonus))
-            .collect();
-        commands.spawn((
-            Text2d::new(syn_text.join("  ")),
-            TextFont::from_font_size(11.0),
-            TextColor(Color::srgba(1.0, 0.85, 0.3, 0.7)),
-            TextLayout::new_with_justify(Justify::Center),
-            Transform::from_xyz(0.0, -30.0, 2.0),
-            SynergyIndicator,
-        ));
+/// System: show the story so far when the slide changes
+pub fn update_story_text(slide: Res<CurrentSlide>, mut text: Single<&mut Text2d, With<StoryText>>) {
+    if !slide.is_changed() { return; }
+    text.0.clone_from(&slide.story_text);
+}
+
+/// System: list the hand's active synergies when they change
+pub fn update_synergy_indicator(
+    synergies: Res<ActiveSynergies>,
+    locale: Res<ActiveLocale>,
+    words: Query<&WordCard>,
+    mut text: Single<&mut Text2d, With<SynergyIndicator>>,
+) {
+    if !synergies.is_changed() { return; }
+    let names: HashMap<&str, &str> = words.iter().map(|w| (w.word.as_str(), w.name.as_str())).collect();
+    let name = |word: &str| names.get(word).map_or_else(|| word.to_string(), |n| n.to_string());
+    let syn_text: Vec<String> = synergies.synergies.iter()
+        .map(|s| format!(
+            "⚡ {} + {} · {} +{}",
+            name(&s.source_word), name(&s.target_word), locale.synergy(s.synergy_type), s.bonus
+        ))
+        .collect();
+    text.0 = syn_text.join("  ");
+}
+
+/// System: prompt for a selection, or for a swipe once one is made
+pub fn update_swipe_hint(
+    hand: Res<Hand>,
+    locale: Res<ActiveLocale>,
+    mut text: Single<&mut Text2d, With<SwipeHint>>,
+) {
+    if !hand.is_changed() { return; }
+    text.0 = if hand.selected.is_some() {
+        locale.text("play.hint.swipe")
+    } else {
+        locale.text("play.hint.select")
+    };
+}
+
+/// System: refresh each HUD part whose source changed
+pub fn update_hud(
+    deck: Res<Deck>,
+    trail: Res<StudentTrail>,
+    sheet: Res<CharacterSheet>,
+    hero: Res<CharacterState>,
+    locale: Res<ActiveLocale>,
+    settings: Query<Ref<Setting>>,
+    mut hud: Query<(&HudElement, &mut Text2d, &mut TextColor)>,
+) {
+    let setting = trail.current_word.and_then(|e| settings.get(e).ok());
+    let setting_changed = trail.is_changed() || setting.as_ref().is_some_and(|s| s.is_changed());
+
+    for (part, mut text, mut color) in &mut hud {
+        match part {
+            HudElement::DeckCount if deck.is_changed() => {
+                text.0 = format!("⟐ {}", deck.remaining());
+            }
+            HudElement::Chapter if trail.is_changed() || locale.is_changed() => {
+                text.0 = locale.chapter_heading(trail.chapter().map_or(JourneyStage::OrdinaryWorld, |c| c.stage));
+            }
+            HudElement::Setting if setting_changed => {
+                text.0 = setting.as_ref().map(|s| format!("{} · {}", s.genre, s.mood)).unwrap_or_default();
+            }
+            HudElement::Attunement if sheet.is_changed() => {
+                let attn_vals = [
+                    ("🟢", sheet.mind_attunement),
+                    ("🟠", sheet.heart_attunement),
+                    ("🔵", sheet.body_attunement),
+                    ("🟡", sheet.action_attunement),
+                ];
+                let max_val = attn_vals.iter().map(|(_, v)| *v).fold(0.01_f32, f32::max);
+                let bar_text: Vec<String> = attn_vals.iter().map(|(icon, val)| {
+                    let bars = (val / max_val * 5.0).round() as usize;
+                    format!("{}{}{}", icon, "█".repeat(bars), "░".repeat(5 - bars))
+                }).collect();
+                text.0 = bar_text.join(" ");
+            }
+            HudElement::Focus if hero.is_changed() || locale.is_changed() => {
+                color.0 = match hero.tier() {
+                    FocusTier::Steady => Color::srgba(0.6, 0.75, 1.0, 0.5),
+                    FocusTier::Strained => Color::srgba(1.0, 0.6, 0.3, 0.7),
+                    FocusTier::Exhausted => Color::srgba(1.0, 0.3, 0.3, 0.7),
+                };
+                let filled = hero.focus.min(hero.max_focus) as usize;
+                text.0 = format!(
+                    "{} {}{}", locale.text("play.focus"), "◆".repeat(filled), "◇".repeat(hero.max_focus as usize - filled)
+                );
+            }
+            _ => {}
+        }
     }
+}
+
+// ─── HAND RENDERING ─────────────────────────────────────────────
 
-    // ─── BOTTOM: Hand of Cards ──────────────────────────────
-    let hand_count = hand.card_count();
-    if hand_count > 0 {
-        let card_w = 90.0;
-        let card_h = 120.0;
-        let spacing = 105.0;
-        let hand_y = -180.0;
-        let total_width = (hand_count as f32 - 1.0) * spacing;
-        let start_x = -total_width / 2.0;
-
-        for (i, entity) in hand.cards.iter().enumerate() {
-            if let Ok((word, channel, _stage, style, _setting, _power)) = words.get(*entity) {
-                let x = start_x + i as f32 * spacing;
-                let is_selected = hand.selected == Some(i);
-
-                // Selected card lifts up and grows
-                let y_offset = if is_selected { 20.0 } else { 0.0 };
-                let scale = if is_selected { 1.15 } else { 1.0 };
-
-                // Card border
-                let border_color = if is_selected {
-                    Color::WHITE
-                } else {
-                    style.color
+/// What the hand's visuals follow: the hand itself, the trail that
+/// tells a cast from a discard, the table a cast lands on, and the
+/// locale card text is written in.
+#[derive(SystemParam)]
+pub struct HandContext<'w> {
+    hand: Res<'w, Hand>,
+    trail: Res<'w, StudentTrail>,
+    moment: Res<'w, StoryMoment>,
+    locale: Res<'w, ActiveLocale>,
+}
+
+/// System: keep one card visual per word in the hand. New words are
+/// dealt from the deck counter; words that left fly to the crafting
+/// table (cast) or slide off the bottom (discarded), then despawn.
+/// Cards that stay get their new `HandSlot`, and `Selected` follows
+/// the hand's selection.
+pub fn sync_hand(
+    mut commands: Commands,
+    context: HandContext,
+    view: Single<Entity, With<PlayView>>,
+    words: Query<(&WordCard, &Channel, &CardStyle, &Symbol, &CardType)>,
+    mut visuals: Query<(Entity, &HandCardVisual, &mut CardMotion, Option<&HandSlot>)>,
+) {
+    let HandContext { hand, trail, moment, locale } = context;
+    if !hand.is_changed() { return; }
+
+    let mut shown = Vec::new();
+    for (visual, card, mut motion, slot) in &mut visuals {
+        if motion.leaving { continue; }
+        match hand.cards.iter().position(|e| *e == card.card) {
+            Some(i) => {
+                if slot.is_none_or(|s| s.0 != i) {
+                    commands.entity(visual).insert(HandSlot(i));
+                }
+                shown.push(card.card);
+            }
+            None => {
+                // Cast: the word just became the active one, in the
+                // slot that took the last card. Otherwise discarded.
+                let cast = trail.current_word == Some(card.card)
+                    && trail.swipe_history.last() == Some(&SwipeChoice::Yes);
+                let from = motion.target.translation;
+                motion.target = match moment.last_placed.filter(|_| cast) {
+                    Some(slot) => Transform::from_translation(slot_position(slot).extend(4.8))
+                        .with_scale(Vec3::splat(0.35)),
+                    None => Transform::from_xyz(from.x - 60.0, -480.0, from.z)
+                        .with_rotation(Quat::from_rotation_z(0.4))
+                        .with_scale(Vec3::splat(0.8)),
                 };
+                motion.leaving = true;
+                commands.entity(visual).remove::<(HandSlot, Selected)>();
+            }
+        }
+    }
 
-                commands.spawn((
-                    Sprite {
-                        color: border_color,
-                        custom_size: Some(Vec2::new(card_w, card_h)),
-                        ..default()
-                    },
-                    Transform::from_xyz(x, hand_y + y_offset, 3.0)
-                        .with_scale(Vec3::splat(scale)),
-                    HandCardVisual,
-                    HandSlot(i),
-                ));
-
-                // Card inner fill
-                commands.spawn((
-                    Sprite {
-                        color: Color::srgba(0.06, 0.06, 0.1, 0.95),
-                        custom_size: Some(Vec2::new(card_w - 6.0, card_h - 6.0)),
-                        ..default()
-                    },
-                    Transform::from_xyz(x, hand_y + y_offset, 3.5)
-                        .with_scale(Vec3::splat(scale)),
-                    HandCardVisual,
-                ));
-
-                // Card word name
-                commands.spawn((
-                    Text2d::new(word.word.to_uppercase()),
-                    TextFont::from_font_size(14.0),
-                    TextColor(Color::WHITE),
-                    Transform::from_xyz(x, hand_y + y_offset + 20.0, 4.0),
-                    HandCardVisual,
-                ));
-
-                // Channel color dot + label
-                let ch_label = format!("{}", channel.label());
-                commands.spawn((
-                    Text2d::new(ch_label),
-                    TextFont::from_font_size(10.0),
-                    TextColor(channel.color()),
-                    Transform::from_xyz(x, hand_y + y_offset - 5.0, 4.0),
-                    HandCardVisual,
-                ));
-
-                // Slot number hint (keyboard shortcut)
-                commands.spawn((
-                    Text2d::new(format!("{}", i + 1)),
-                    TextFont::from_font_size(9.0),
-                    TextColor(Color::srgba(1.0, 1.0, 1.0, 0.2)),
-                    Transform::from_xyz(x, hand_y + y_offset - 25.0, 4.0),
-                    HandCardVisual,
-                ));
+    for (i, entity) in hand.cards.iter().enumerate() {
+        if shown.contains(entity) { continue; }
+        let Ok((word, channel, style, symbol, card_type)) = words.get(*entity) else { continue };
+        let dealt = Transform::from_translation(DECK_POSITION).with_scale(Vec3::splat(0.3));
+        let mut visual = commands.spawn((
+            Sprite::from_color(style.color, HAND_CARD_SIZE),
+            dealt,
+            HandCardVisual { card: *entity, border: style.color },
+            HandSlot(i),
+            CardMotion { target: dealt, leaving: false },
+            ChildOf(*view),
+        ));
+        let (ring, fill) = frame_layers(HAND_CARD_SIZE, 3.0, *card_type);
+        visual.with_children(|card| {
+            // Ally ring, card inner fill and type tag
+            if let Some(size) = ring {
+                card.spawn((Sprite::from_color(ALLY_RING, size), Transform::from_xyz(0.0, 0.0, 0.05)));
             }
+            card.spawn((Sprite::from_color(CARD_FILL, fill), Transform::from_xyz(0.0, 0.0, 0.1)));
+            card.spawn((
+                Text2d::new(kind_tag(*card_type, &locale)),
+                TextFont::from_font_size(8.0),
+                TextColor(Color::srgba(1.0, 1.0, 1.0, 0.4)),
+                Transform::from_xyz(0.0, 44.0, 0.2),
+            ));
+            // Card word name
+            card.spawn((
+                Text2d::new(word.name.to_uppercase()),
+                TextFont::from_font_size(14.0),
+                TextColor(Color::WHITE),
+                Transform::from_xyz(0.0, 20.0, 0.2),
+            ));
+            // Symbol + channel label
+            card.spawn((
+                Text2d::new(format!("{} {}", symbol.icon(), locale.channel(*channel))),
+                TextFont::from_font_size(10.0),
+                TextColor(channel.color()),
+                Transform::from_xyz(0.0, -5.0, 0.2),
+            ));
+            // Slot number hint (keyboard shortcut)
+            card.spawn((
+                Text2d::default(),
+                TextFont::from_font_size(9.0),
+                TextColor(Color::srgba(1.0, 1.0, 1.0, 0.2)),
+                Transform::from_xyz(0.0, -25.0, 0.2),
+                HandSlotNumber,
+            ));
+        });
+        if hand.selected == Some(i) {
+            visual.insert(Selected);
         }
     }
 
-    // ─── SWIPE HINTS ────────────────────────────────────────
-    let hint_text = if hand.selected.is_some() {
-        "→ play    ← skip    ↓ dig deeper    [esc] deselect"
-    } else {
-        "tap 1-3 to select a card"
-    };
-    commands.spawn((
-        Text2d::new(hint_text),
-        TextFont::from_font_size(11.0),
-        TextColor(Color::srgba(1.0, 1.0, 1.0, 0.2)),
-        TextLayout::new_with_justify(Justify::Center),
-        Transform::from_xyz(0.0, -270.0, 2.0),
-        SwipeHint,
-    ));
+    // Highlight follows the selection
+    for (visual, card, motion, _) in &visuals {
+        if motion.leaving { continue; }
+        let selected = hand.selected.and_then(|i| hand.cards.get(i)) == Some(&card.card);
+        if selected {
+            commands.entity(visual).insert(Selected);
+        } else {
+            commands.entity(visual).remove::<Selected>();
+        }
+    }
+}
 
-    // ─── HUD: Top bar ───────────────────────────────────────
-    // Deck counter (top-left)
-    commands.spawn((
-        Text2d::new(format!("⟐ {}", deck.remaining())),
-        TextFont::from_font_size(13.0),
-        TextColor(Color::srgba(1.0, 1.0, 1.0, 0.4)),
-        Transform::from_xyz(-280.0, 300.0, 2.0),
-        HudElement,
-    ));
+/// System: place each hand card in the fan by its `HandSlot`, and
+/// highlight the `Selected` one
+pub fn layout_hand(
+    hand: Res<Hand>,
+    mut cards: Query<(&HandCardVisual, &HandSlot, Has<Selected>, &mut CardMotion, &mut Sprite)>,
+    mut numbers: Query<(&ChildOf, &mut Text2d), With<HandSlotNumber>>,
+) {
+    if !hand.is_changed() { return; }
+    let count = hand.card_count();
+    for (card, slot, selected, mut motion, mut sprite) in &mut cards {
+        motion.target = fan_transform(slot.0, count, selected);
+        sprite.color = if selected { Color::WHITE } else { card.border };
+    }
+    for (parent, mut number) in &mut numbers {
+        if let Ok((_, slot, ..)) = cards.get(parent.parent()) {
+            number.0 = format!("{}", slot.0 + 1);
+        }
+    }
+}
 
-    // Channel attunement mini-bars (top-right)
-    let attn_vals = [
-        ("🟢", sheet.mind_attunement),
-        ("🟠", sheet.heart_attunement),
-        ("🔵", sheet.body_attunement),
-        ("🟡", sheet.action_attunement),
-    ];
-    let max_val = attn_vals.iter().map(|(_, v)| *v).fold(0.01_f32, f32::max);
-    let bar_text: Vec<String> = attn_vals.iter().map(|(icon, val)| {
-        let bars = (val / max_val * 5.0).round() as usize;
-        let filled = "█".repeat(bars);
-        let empty = "░".repeat(5 - bars);
-        format!("{}{}{}", icon, filled, empty)
-    }).collect();
+/// System: ease every moving card toward its target, and despawn
+/// cast or discarded cards once they arrive
+pub fn animate_card_motion(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut cards: Query<(Entity, &CardMotion, &mut Transform)>,
+) {
+    let t = 1.0 - (-CARD_EASE * time.delta_secs()).exp();
+    for (entity, motion, mut transform) in &mut cards {
+        if *transform == motion.target { continue; }
+        if transform.translation.distance(motion.target.translation) < 0.5 {
+            *transform = motion.target;
+        } else {
+            transform.translation = transform.translation.lerp(motion.target.translation, t);
+            transform.rotation = transform.rotation.slerp(motion.target.rotation, t);
+            transform.scale = transform.scale.lerp(motion.target.scale, t);
+        }
+        if motion.leaving && transform.translation.distance(motion.target.translation) < 4.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
 
-    commands.spawn((
-        Text2d::new(bar_text.join(" ")),
-        TextFont::from_font_size(10.0),
-        TextColor(Color::srgba(1.0, 1.0, 1.0, 0.35)),
-        Transform::from_xyz(180.0, 300.0, 2.0),
-        HudElement,
-    ));
+// ─── MASTERY TOASTS ─────────────────────────────────────────────
+
+/// System: announce each mastery level-up with a brief glowing notice.
+pub fn announce_mastery_gains(
+    mut commands: Commands,
+    mut level_ups: MessageReader<MasteryLevelUp>,
+    existing: Query<(), With<MasteryToast>>,
+    words: Query<&WordCard>,
+    locale: Res<ActiveLocale>,
+) {
+    // Stack notices downward if several arrive together
+    let mut row = existing.iter().count() as f32;
+    for level_up in level_ups.read() {
+        commands.spawn((
+            Text2d::new(format!(
+                "{} {} — {}",
+                level_up.to.icon(),
+                words.get(level_up.entity).map_or(&level_up.word, |w| &w.name).to_uppercase(),
+                locale.mastery(level_up.to),
+            )),
+            TextFont::from_font_size(14.0),
+            TextColor(Color::srgba(1.0, 0.95, 0.6, 0.9)),
+            TextLayout::new_with_justify(Justify::Center),
+            Transform::from_xyz(0.0, 330.0 - row * 20.0, 8.0),
+            MasteryToast(Timer::from_seconds(2.5, TimerMode::Once)),
+        ));
+        row += 1.0;
+    }
+}
+
+/// System: fade out and remove mastery notices.
+pub fn fade_mastery_toasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut toasts: Query<(Entity, &mut MasteryToast, &mut TextColor)>,
+) {
+    for (entity, mut toast, mut color) in toasts.iter_mut() {
+        toast.0.tick(time.delta());
+        color.0.set_alpha(0.9 * toast.0.fraction_remaining());
+        if toast.0.is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
 }
 
 // ─── DEPTH OVERLAY ──────────────────────────────────────────────
 
 /// System: spawn depth overlay when entering DepthView state.
+/// Past answers to the question show under it, newest last.
 pub fn spawn_depth_view(
     mut commands: Commands,
     hand: Res<Hand>,
     trail: Res<StudentTrail>,
+    spellbook: Res<SpellBook>,
+    locale: Res<ActiveLocale>,
     words: Query<(&WordCard, &Channel)>,
-    mut spellbook: ResMut<SpellBook>,
-    mut spell_powers: Query<&mut SpellPower>,
-    mut sheet: ResMut<CharacterSheet>,
 ) {
     // Figure out which word to show depth for
     let target_entity = if let Some(idx) = hand.selected {
@@ -370,16 +788,6 @@ pub fn spawn_depth_view(
     let Some(entity) = target_entity else { return };
     let Ok((word, channel)) = words.get(entity) else { return };
 
-    // Track the deeper interaction
-    if let Ok(mut sp) = spell_powers.get_mut(entity) {
-        sp.times_explored_deeper += 1;
-        if sp.mastery == MasteryLevel::Encountered {
-            sp.mastery = MasteryLevel::Experienced;
-            spellbook.upgrade_mastery(&word.word, MasteryLevel::Experienced);
-        }
-    }
-    sheet.total_deeper_swipes += 1;
-
     // Dark overlay
     commands.spawn((
         Sprite {
@@ -393,7 +801,7 @@ pub fn spawn_depth_view(
 
     // Word title — colored by channel
     commands.spawn((
-        Text2d::new(word.word.to_uppercase()),
+        Text2d::new(word.name.to_uppercase()),
         TextFont::from_font_size(40.0),
         TextColor(channel.color()),
         TextLayout::new_with_justify(Justify::Center),
@@ -403,7 +811,7 @@ pub fn spawn_depth_view(
 
     // "Dig Deeper" label
     commands.spawn((
-        Text2d::new("— dig deeper —"),
+        Text2d::new(locale.text("depth.label")),
         TextFont::from_font_size(12.0),
         TextColor(Color::srgba(1.0, 1.0, 1.0, 0.3)),
         TextLayout::new_with_justify(Justify::Center),
@@ -421,57 +829,253 @@ pub fn spawn_depth_view(
         DepthText,
     ));
 
+    // What the student wrote here before — the last few answers
+    let journal = spellbook.journal(&word.word);
+    if !journal.is_empty() {
+        let past: Vec<String> = journal.iter().rev().take(3).rev()
+            .map(|entry| format!("{} \u{201c}{}\u{201d}", entry.mastery.icon(), entry.text))
+            .collect();
+        commands.spawn((
+            Text2d::new(past.join("\n")),
+            TextFont::from_font_size(12.0),
+            TextColor(Color::srgba(1.0, 1.0, 1.0, 0.45)),
+            TextLayout::new_with_justify(Justify::Center),
+            TextBounds::new_horizontal(560.0),
+            Transform::from_xyz(0.0, -75.0, 6.0),
+            DepthText,
+        ));
+    }
+
+    // The answer being typed
+    commands.spawn((
+        Text2d::new(draft_line("", &locale)),
+        TextFont::from_font_size(15.0),
+        TextColor(channel.color()),
+        TextLayout::new_with_justify(Justify::Center),
+        TextBounds::new_horizontal(560.0),
+        Transform::from_xyz(0.0, -150.0, 6.0),
+        DepthText,
+        JournalDraftText,
+    ));
+
     // Dismiss hint
     commands.spawn((
-        Text2d::new("[esc / space to return]"),
+        Text2d::new(locale.text("depth.hint")),
         TextFont::from_font_size(11.0),
         TextColor(Color::srgba(1.0, 1.0, 1.0, 0.2)),
         TextLayout::new_with_justify(Justify::Center),
-        Transform::from_xyz(0.0, -100.0, 6.0),
+        Transform::from_xyz(0.0, -200.0, 6.0),
         DepthText,
     ));
 }
 
-/// System: dismiss depth overlay and clean up entities
-pub fn dismiss_depth_view(
-    keys: Res<ButtonInput<KeyCode>>,
-    mouse: Res<ButtonInput<MouseButton>>,
-    mut slide: ResMut<CurrentSlide>,
-    mut next_state: ResMut<NextState<GameState>>,
-    depth_entities: Query<Entity, Or<(With<DepthOverlay>, With<DepthText>)>>,
+fn draft_line(text: &str, locale: &ActiveLocale) -> String {
+    if text.is_empty() {
+        locale.text("depth.placeholder")
+    } else {
+        format!("{}▏", text)
+    }
+}
+
+/// System: keep the typed answer on screen as it's written
+pub fn update_journal_draft(
+    draft: Res<JournalDraft>,
+    locale: Res<ActiveLocale>,
+    mut texts: Query<&mut Text2d, With<JournalDraftText>>,
+) {
+    if !draft.is_changed() {
+        return;
+    }
+    for mut text in texts.iter_mut() {
+        text.0 = draft_line(&draft.text, &locale);
+    }
+}
+
+/// The depth view's visuals: its overlay and its text.
+type DepthVisuals = Or<(With<DepthOverlay>, With<DepthText>)>;
+
+/// System: clean up the depth overlay when the view closes
+pub fn clear_depth_view(
     mut commands: Commands,
+    depth_entities: Query<Entity, DepthVisuals>,
 ) {
-    if keys.just_pressed(KeyCode::Escape) || keys.just_pressed(KeyCode::Space)
-        || mouse.just_pressed(MouseButton::Left)
-    {
-        slide.depth_showing = false;
-        for e in depth_entities.iter() {
-            commands.entity(e).despawn();
-        }
-        // Return to card selected if a card was selected, otherwise playing
-        next_state.set(GameState::CardSelected);
+    for e in depth_entities.iter() {
+        commands.entity(e).despawn();
     }
 }
 
-// ─── TRAIL REVIEW ───────────────────────────────────────────────
+// ─── BRANCH POINT ───────────────────────────────────────────────
 
-/// System: spawn end-of-journey trail review with Character Sheet.
-pub fn spawn_trail_review(
+/// System: show the three ways forward when a chapter ends
+pub fn spawn_branch_view(
     mut commands: Commands,
+    branch_point: Res<BranchPoint>,
     trail: Res<StudentTrail>,
-    sheet: Res<CharacterSheet>,
-    spellbook: Res<SpellBook>,
-    // Clean up play view
-    old_visuals: Query<Entity, Or<(
-        With<CardFrame>, With<CardWordText>, With<StoryText>,
-        With<SettingBackground>, With<SwipeHint>, With<HudElement>,
-        With<HandCardVisual>, With<SynergyIndicator>,
-    )>>,
+    locale: Res<ActiveLocale>,
+    words: Query<(&WordCard, &Channel)>,
 ) {
-    for e in old_visuals.iter() {
+    commands.spawn((
+        Sprite {
+            color: Color::srgba(0.03, 0.02, 0.05, 0.94),
+            custom_size: Some(Vec2::new(2000.0, 2000.0)),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, 5.0),
+        BranchOverlay,
+    ));
+
+    let told = trail.chapter().map(|c| locale.chapter_heading(c.stage)).unwrap_or_default();
+    commands.spawn((
+        Text2d::new(format!("{}\n{}", told, locale.text("branch.told"))),
+        TextFont::from_font_size(14.0),
+        TextColor(Color::srgba(1.0, 0.9, 0.7, 0.6)),
+        TextLayout::new_with_justify(Justify::Center),
+        Transform::from_xyz(0.0, 200.0, 6.0),
+        BranchOverlay,
+    ));
+
+    let names: HashMap<&str, &str> = words.iter().map(|(w, _)| (w.word.as_str(), w.name.as_str())).collect();
+    let name = |word: &str| names.get(word).copied().unwrap_or(word).to_string();
+
+    // The moment that ended the chapter
+    if let Some(moment) = trail.chapter().and_then(|c| c.moments.last()) {
+        commands.spawn((
+            Text2d::new(locale.moment_text(moment, name)),
+            TextFont::from_font_size(15.0),
+            TextColor(Color::srgba(1.0, 1.0, 1.0, 0.7)),
+            TextLayout::new_with_justify(Justify::Center),
+            Transform::from_xyz(0.0, 130.0, 6.0),
+            BranchOverlay,
+        ));
+    }
+
+    commands.spawn((
+        Text2d::new(locale.text("branch.question")),
+        TextFont::from_font_size(20.0),
+        TextColor(Color::WHITE),
+        TextLayout::new_with_justify(Justify::Center),
+        Transform::from_xyz(0.0, 60.0, 6.0),
+        BranchOverlay,
+    ));
+
+    // Right, left, down — laid out where the swipe goes
+    let places = [("→", Vec2::new(150.0, -20.0)), ("←", Vec2::new(-150.0, -20.0)), ("↓", Vec2::new(0.0, -120.0))];
+    for (branch, (arrow, pos)) in branch_point.options.iter().zip(places) {
+        let (name, color) = words.get(branch.entity)
+            .map_or((branch.word.as_str(), Color::WHITE), |(w, c)| (w.name.as_str(), c.color()));
+        commands.spawn((
+            Text2d::new(format!("{} {}\n{}", arrow, name.to_uppercase(), locale.branch_prompt(branch.edge, name))),
+            TextFont::from_font_size(14.0),
+            TextColor(color),
+            TextLayout::new_with_justify(Justify::Center),
+            Transform::from_xyz(pos.x, pos.y, 6.0),
+            BranchOverlay,
+        ));
+    }
+}
+
+/// System: remove the branch point overlay
+pub fn clear_branch_view(mut commands: Commands, overlay: Query<Entity, With<BranchOverlay>>) {
+    for e in overlay.iter() {
         commands.entity(e).despawn();
     }
+}
 
+// ─── REST POINT ─────────────────────────────────────────────────
+
+/// System: spawn the rest point overlay when the Hero is exhausted
+pub fn spawn_rest_view(mut commands: Commands, locale: Res<ActiveLocale>) {
+    commands.spawn((
+        Sprite {
+            color: Color::srgba(0.02, 0.03, 0.06, 0.94),
+            custom_size: Some(Vec2::new(2000.0, 2000.0)),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, 5.0),
+        RestOverlay,
+    ));
+
+    commands.spawn((
+        Text2d::new(locale.text("rest.title")),
+        TextFont::from_font_size(28.0),
+        TextColor(Color::srgba(0.6, 0.75, 1.0, 0.9)),
+        TextLayout::new_with_justify(Justify::Center),
+        Transform::from_xyz(0.0, 80.0, 6.0),
+        RestOverlay,
+    ));
+
+    commands.spawn((
+        Text2d::new(locale.text("rest.guide")),
+        TextFont::from_font_size(16.0),
+        TextColor(Color::srgba(1.0, 1.0, 1.0, 0.6)),
+        TextLayout::new_with_justify(Justify::Center),
+        Transform::from_xyz(0.0, 20.0, 6.0),
+        RestOverlay,
+    ));
+
+    commands.spawn((
+        Text2d::new(""),
+        TextFont::from_font_size(18.0),
+        TextColor(Color::srgba(0.6, 0.75, 1.0, 0.8)),
+        TextLayout::new_with_justify(Justify::Center),
+        Transform::from_xyz(0.0, -50.0, 6.0),
+        RestOverlay,
+        RestProgressText,
+    ));
+}
+
+/// System: show breaths taken so far
+pub fn update_rest_view(
+    rest: Res<RestPoint>,
+    rules: Res<FocusRules>,
+    locale: Res<ActiveLocale>,
+    mut text: Query<&mut Text2d, With<RestProgressText>>,
+) {
+    if !rest.is_changed() && !text.iter().any(|t| t.0.is_empty()) {
+        return;
+    }
+    let breaths: String = (0..rules.breaths_to_rest)
+        .map(|i| if i < rest.breaths { "●" } else { "○" })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let note = if rest.rushed { format!("\n{}", locale.text("rest.slower")) } else { String::new() };
+    for mut t in &mut text {
+        t.0 = format!("{}{}", breaths, note);
+    }
+}
+
+/// System: remove the rest point overlay
+pub fn clear_rest_view(mut commands: Commands, overlay: Query<Entity, With<RestOverlay>>) {
+    for e in overlay.iter() {
+        commands.entity(e).despawn();
+    }
+}
+
+// ─── TRAIL REVIEW ───────────────────────────────────────────────
+
+/// Lines the chapter retelling may take before it folds each
+/// chapter onto one line.
+const TRAIL_REVIEW_LINES: usize = 10;
+
+/// What the trail review sums up: the path walked, the sheet and
+/// SpellBook it grew, the threads that resonated and the archetype read.
+#[derive(SystemParam)]
+pub struct SessionSummary<'w> {
+    trail: Res<'w, StudentTrail>,
+    sheet: Res<'w, CharacterSheet>,
+    spellbook: Res<'w, SpellBook>,
+    threads: Res<'w, ResonanceThreads>,
+    reading: Res<'w, ArchetypeReading>,
+}
+
+/// System: spawn end-of-journey trail review with Character Sheet.
+pub fn spawn_trail_review(
+    mut commands: Commands,
+    summary: SessionSummary,
+    locale: Res<ActiveLocale>,
+    words: Query<&WordCard>,
+) {
+    let SessionSummary { trail, sheet, spellbook, threads, reading } = summary;
     // Dark background
     commands.spawn((
         Sprite {
@@ -485,100 +1089,255 @@ pub fn spawn_trail_review(
 
     // Title
     commands.spawn((
-        Text2d::new("Your journey, traced."),
+        Text2d::new(locale.text("trail.title")),
         TextFont::from_font_size(36.0),
         TextColor(Color::WHITE),
         TextLayout::new_with_justify(Justify::Center),
-        Transform::from_xyz(0.0, 250.0, 2.0),
+        Transform::from_xyz(0.0, 300.0, 2.0),
         TrailElement,
     ));
 
-    // Trail path with arrows
-    let symbols: Vec<String> = trail.visited_words.iter().enumerate().map(|(i, w)| {
-        if i < trail.swipe_history.len() {
-            let arrow = match trail.swipe_history[i] {
-                SwipeChoice::Yes => "→",
-                SwipeChoice::No => "←",
-                SwipeChoice::Deeper => "↓",
-            };
-            format!("{} {}", w, arrow)
+    // Words as the student reads them
+    let names: HashMap<&str, &str> = words.iter().map(|w| (w.word.as_str(), w.name.as_str())).collect();
+    let name = |word: &str| names.get(word).copied().unwrap_or(word).to_string();
+
+    // Chapters: the moments built in each, and the branch that ended it
+    let told: Vec<&Chapter> = trail.chapters.iter()
+        .filter(|c| !c.moments.is_empty() || c.branch.is_some())
+        .collect();
+    let full_lines: usize = told.iter().map(|c| 1 + c.moments.len() + c.branch.is_some() as usize).sum();
+    let mut lines: Vec<String> = Vec::new();
+    for chapter in &told {
+        let taken = chapter.branch.as_ref().map(|b| format!("⤷ {}", locale.branch_prompt(b.edge, &name(&b.word))));
+        let heading = locale.chapter_heading(chapter.stage);
+        if full_lines <= TRAIL_REVIEW_LINES {
+            lines.push(heading);
+            lines.extend(chapter.moments.iter()
+                .map(|m| format!("   {}", locale.moment_text(m, name).lines().next().unwrap_or_default())));
+            lines.extend(taken.map(|t| format!("   {}", t)));
         } else {
-            w.clone()
+            // Too long to retell: one line per chapter
+            let moments = locale.plural("trail.moments", chapter.moments.len() as u64, &[]);
+            lines.push(format!("{} · {}  {}", heading, moments, taken.unwrap_or_default()));
         }
-    }).collect();
+    }
+    if lines.is_empty() {
+        lines.push(locale.text("trail.no_moments"));
+    }
 
     commands.spawn((
-        Text2d::new(symbols.join("  ")),
-        TextFont::from_font_size(14.0),
-        TextColor(Color::srgba(1.0, 1.0, 1.0, 0.5)),
+        Text2d::new(lines.join("\n")),
+        TextFont::from_font_size(12.0),
+        TextColor(Color::srgba(1.0, 1.0, 1.0, 0.55)),
         TextLayout::new_with_justify(Justify::Center),
-        Transform::from_xyz(0.0, 190.0, 2.0),
+        Anchor::TOP_CENTER,
+        Transform::from_xyz(0.0, 250.0, 2.0),
         TrailElement,
     ));
 
     // Emergent class title
     commands.spawn((
-        Text2d::new(format!("You walk the path of: {}", sheet.emergent_class)),
+        Text2d::new(locale.format("trail.path", &[("class", &locale.class_title(&sheet.emergent_class))])),
         TextFont::from_font_size(20.0),
         TextColor(Color::srgba(1.0, 0.85, 0.5, 1.0)),
         TextLayout::new_with_justify(Justify::Center),
-        Transform::from_xyz(0.0, 130.0, 2.0),
+        Transform::from_xyz(0.0, 80.0, 2.0),
         TrailElement,
     ));
 
     // Channel attunement display
     commands.spawn((
-        Text2d::new(sheet.attunement_display()),
+        Text2d::new(locale.attunement_display(&sheet)),
         TextFont::from_font_size(13.0),
         TextColor(Color::srgba(1.0, 1.0, 1.0, 0.6)),
         TextLayout::new_with_justify(Justify::Center),
-        Transform::from_xyz(0.0, 60.0, 2.0),
+        Transform::from_xyz(0.0, 10.0, 2.0),
         TrailElement,
     ));
 
+    // Words that grew this session
+    if !trail.mastery_gains.is_empty() {
+        let gains: Vec<String> = trail.mastery_gains.iter()
+            .map(|(word, level)| format!("{} {}", level.icon(), name(word)))
+            .collect();
+        commands.spawn((
+            Text2d::new(locale.format("trail.grew", &[("words", &gains.join("  "))])),
+            TextFont::from_font_size(12.0),
+            TextColor(Color::srgba(1.0, 0.95, 0.6, 0.6)),
+            TextLayout::new_with_justify(Justify::Center),
+            Transform::from_xyz(0.0, -33.0, 2.0),
+            TrailElement,
+        ));
+    }
+
     // SpellBook summary
     commands.spawn((
-        Text2d::new(spellbook.summary()),
+        Text2d::new(locale.spellbook_summary(&spellbook)),
         TextFont::from_font_size(13.0),
         TextColor(Color::srgba(1.0, 1.0, 1.0, 0.5)),
         TextLayout::new_with_justify(Justify::Center),
-        Transform::from_xyz(0.0, -30.0, 2.0),
+        Transform::from_xyz(0.0, -80.0, 2.0),
         TrailElement,
     ));
 
-    // Journey insight
-    let yes_count = trail.swipe_history.iter().filter(|s| matches!(s, SwipeChoice::Yes)).count();
-    let no_count = trail.swipe_history.iter().filter(|s| matches!(s, SwipeChoice::No)).count();
-    let deep_count = trail.swipe_history.iter().filter(|s| matches!(s, SwipeChoice::Deeper)).count();
-
-    let pattern = if deep_count >= yes_count && deep_count >= no_count {
-        "You asked 'why' more than 'what.' You look beneath the surface.\nThe Vulnerability skill is strong in you."
-    } else if yes_count >= no_count {
-        "You moved forward with trust. Your instinct is to explore.\nThe Ownership skill guides your path."
-    } else {
-        "You paused when uncertain. Wisdom often sounds like silence.\nThe Stewardship skill is your compass."
-    };
-
+    // Archetype whisper — the pattern, and the evidence behind it
     commands.spawn((
-        Text2d::new(pattern),
+        Text2d::new(locale.archetype_whisper(&reading)),
         TextFont::from_font_size(14.0),
         TextColor(Color::srgba(1.0, 1.0, 1.0, 0.45)),
         TextLayout::new_with_justify(Justify::Center),
-        Transform::from_xyz(0.0, -120.0, 2.0),
+        Transform::from_xyz(0.0, -170.0, 2.0),
         TrailElement,
     ));
 
+    // Resonance threads — what the student wove together
+    let woven: Vec<String> = threads.strongest().iter().take(3).map(|t| {
+        let (a, b) = (name(&t.word_a), name(&t.word_b));
+        locale.plural("trail.resonated", t.times_resonated as u64, &[("a", &a), ("b", &b)])
+    }).collect();
+    if !woven.is_empty() {
+        commands.spawn((
+            Text2d::new(woven.join("\n")),
+            TextFont::from_font_size(12.0),
+            TextColor(Color::srgba(1.0, 0.85, 0.3, 0.6)),
+            TextLayout::new_with_justify(Justify::Center),
+            Transform::from_xyz(0.0, -215.0, 2.0),
+            TrailElement,
+        ));
+    }
+
     // Stats
-    let stats_text = format!(
-        "Words encountered: {}  |  Deeper dives: {}",
-        sheet.words_encountered, sheet.total_deeper_swipes
-    );
+    let stats_text = locale.format("trail.stats", &[
+        ("words", &sheet.words_encountered.to_string()),
+        ("deeper", &sheet.total_deeper_swipes.to_string()),
+        ("moments", &trail.moments().count().to_string()),
+    ]);
     commands.spawn((
         Text2d::new(stats_text),
         TextFont::from_font_size(11.0),
         TextColor(Color::srgba(1.0, 1.0, 1.0, 0.3)),
         TextLayout::new_with_justify(Justify::Center),
-        Transform::from_xyz(0.0, -200.0, 2.0),
+        Transform::from_xyz(0.0, -250.0, 2.0),
         TrailElement,
     ));
 }
+
+/// System: clear the trail review when a new session starts
+/// (e.g. after the curriculum is hot-reloaded).
+pub fn clear_trail_review(
+    mut commands: Commands,
+    trail_elements: Query<Entity, With<TrailElement>>,
+) {
+    for e in trail_elements.iter() {
+        commands.entity(e).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hand_fans_out_symmetrically() {
+        let left = fan_transform(0, 3, false);
+        let middle = fan_transform(1, 3, false);
+        let right = fan_transform(2, 3, false);
+
+        assert_eq!(middle.translation.x, 0.0);
+        assert_eq!(left.translation.x, -right.translation.x);
+        assert!(left.translation.y < middle.translation.y, "outer cards dip along the arc");
+        // Left card leans left, right card leans right
+        assert!(left.rotation.to_euler(EulerRot::XYZ).2 > 0.0);
+        assert!(right.rotation.to_euler(EulerRot::XYZ).2 < 0.0);
+        // Later slots draw over earlier ones
+        assert!(left.translation.z < middle.translation.z && middle.translation.z < right.translation.z);
+    }
+
+    #[test]
+    fn test_selected_card_stands_up_over_the_fan() {
+        let resting = fan_transform(0, 5, false);
+        let selected = fan_transform(0, 5, true);
+
+        assert_eq!(selected.translation.x, resting.translation.x);
+        assert!(selected.translation.y > resting.translation.y);
+        assert_eq!(selected.rotation, Quat::IDENTITY);
+        assert!(selected.scale.x > 1.0);
+        assert!((0..5).all(|i| fan_transform(i, 5, false).translation.z < selected.translation.z));
+    }
+
+    /// Hand visuals by the word they show.
+    fn shown(app: &mut App) -> HashMap<Entity, (Entity, bool)> {
+        let mut visuals = app.world_mut().query::<(Entity, &HandCardVisual, &CardMotion)>();
+        visuals.iter(app.world()).map(|(e, v, m)| (v.card, (e, m.leaving))).collect()
+    }
+
+    /// Update until `visual` is gone, or give up after `max_frames`.
+    fn settle(app: &mut App, visual: Entity, max_frames: u32) -> bool {
+        for _ in 0..max_frames {
+            app.update();
+            if app.world().get_entity(visual).is_err() {
+                return true;
+            }
+        }
+        false
+    }
+
+    #[test]
+    fn test_hand_visuals_follow_the_hand() {
+        use std::time::Duration;
+        use bevy::time::TimeUpdateStrategy;
+        use crate::dag::CurriculumSource;
+        use crate::input::PlayerAction;
+        use crate::sim::{harness::{act, deal, state}, headless_app};
+
+        let mut app = headless_app(5, &CurriculumSource::Demo);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(50)))
+            .add_systems(Update, (sync_hand, layout_hand, animate_card_motion).chain());
+        app.world_mut().spawn((PlayView, Transform::default(), Visibility::default()));
+        deal(&mut app);
+
+        // Dealt: one visual per word in the hand
+        let dealt = shown(&mut app);
+        let hand = app.world().resource::<Hand>().cards.clone();
+        assert_eq!(dealt.len(), hand.len());
+        assert!(hand.iter().all(|card| dealt.get(card).is_some_and(|(_, leaving)| !leaving)));
+
+        // Cast the first card: it flies to the table, the rest stay put
+        let cast = hand[0];
+        act(&mut app, PlayerAction::Select(0));
+        act(&mut app, PlayerAction::Swipe(SwipeChoice::Yes));
+        app.update();
+        let after_cast = shown(&mut app);
+        let refilled = app.world().resource::<Hand>().cards.clone();
+        assert!(!refilled.contains(&cast));
+        assert_eq!(after_cast[&cast], (dealt[&cast].0, true), "the cast card animates out");
+        for card in &hand[1..] {
+            assert_eq!(after_cast[card], (dealt[card].0, false), "a kept card keeps its visual");
+        }
+        let drawn: Vec<_> = refilled.iter().filter(|c| !hand.contains(c)).collect();
+        assert!(!drawn.is_empty(), "the hand is refilled");
+        assert!(drawn.iter().all(|card| after_cast.get(*card).is_some_and(|(_, leaving)| !leaving)));
+        assert!(settle(&mut app, dealt[&cast].0, 120), "the cast card despawns on the table");
+
+        // Dig deeper and come back: the hand's visuals live through it
+        let before = shown(&mut app);
+        act(&mut app, PlayerAction::Select(0));
+        act(&mut app, PlayerAction::Swipe(SwipeChoice::Deeper));
+        assert_eq!(state(&app), GameState::DepthView);
+        act(&mut app, PlayerAction::Back);
+        assert_ne!(state(&app), GameState::DepthView);
+        assert_eq!(shown(&mut app), before);
+
+        // Skip a card: it slides off the bottom and is gone
+        let skipped = app.world().resource::<Hand>().cards[0];
+        act(&mut app, PlayerAction::Select(0));
+        act(&mut app, PlayerAction::Swipe(SwipeChoice::No));
+        app.update();
+        let (visual, leaving) = shown(&mut app)[&skipped];
+        assert_eq!(visual, before[&skipped].0);
+        assert!(leaving);
+        assert!(app.world().get::<CardMotion>(visual).unwrap().target.translation.y < -400.0);
+        assert!(settle(&mut app, visual, 120), "the discarded card despawns off screen");
+        assert_eq!(shown(&mut app).len(), app.world().resource::<Hand>().card_count());
+    }
+}
@@ -0,0 +1,547 @@
+// Daydream Engine — Session Recording & Replay
+// For research: reconstruct exactly what a student saw and did.
+//
+//   daydream-engine --record=session.json      play, and record the session
+//   daydream-engine --replay=session.json [curriculum.json]
+//
+// A recording holds what the session started from — seed, curriculum,
+// genre, clock, the constructed deck chosen and the rules it was
+// checked by, the daily challenge if one was played, and the saved
+// progress (with the deck library) — then every frame that mattered:
+// each state transition, each change to the hand or its selection, and
+// each PendingAction with the game time it arrived at (and, for an
+// answer to a depth question, the text typed).
+//
+// Replay runs the rules headless and feeds each action back into
+// PendingAction on the same frame, at the same game time, as if it had
+// come from the device. The replayed session is recorded too, and the
+// two must match: same transitions, same hands, same StudentTrail,
+// SpellBook and CharacterSheet.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use serde::{Deserialize, Serialize};
+use crate::components::*;
+use crate::challenge::{self, ActiveChallenge, DailyChallenge};
+use crate::constructed::{DeckChoice, DeckRules};
+use crate::dag::{Curriculum, CurriculumSource};
+use crate::genre::{ActiveGenre, Genre};
+use crate::input::{JournalDraft, PendingAction, PlayerAction};
+use crate::recycler::ReviewClock;
+use crate::rng::SessionRng;
+use crate::save::{SaveData, SaveStore, SavedProgress, SessionProgress, TrailRecord};
+use crate::sim::{carry_progress, headless_app, CarriedProgress};
+
+// ─── RECORDING ──────────────────────────────────────────────
+
+/// A whole session, as recorded.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct SessionRecording {
+    pub seed: u64,
+    /// The curriculum's name.
+    pub curriculum: String,
+    pub genre: String,
+    /// The Recycler's clock at the start (Unix seconds).
+    pub started_at: u64,
+    /// The constructed deck the student chose, if any, and the rules
+    /// it was checked by. The deck itself is in `start`'s library.
+    pub deck: Option<String>,
+    pub deck_rules: DeckRules,
+    /// The daily challenge played, if any: it fixes the deal, and the
+    /// Recycler sits it out.
+    pub challenge: Option<DailyChallenge>,
+    /// Saved progress the session started from.
+    pub start: SaveData,
+    pub events: Vec<RecordedEvent>,
+    /// Frames played when the recording was last written.
+    pub frames: u32,
+    /// Where the student had got to when the recording was last written.
+    pub outcome: Option<SessionOutcome>,
+}
+
+/// Something that happened on a frame, at a game time in seconds.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RecordedEvent {
+    pub frame: u32,
+    pub secs: f64,
+    pub kind: EventKind,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum EventKind {
+    /// The game moved between states.
+    Transition { from: Option<GameState>, to: Option<GameState> },
+    /// The hand's words and the selected card, after a change.
+    Hand { words: Vec<String>, selected: Option<usize> },
+    /// An action from the student's device.
+    Action(PlayerAction),
+    /// The typed answer, when an Answer action arrives.
+    Draft(String),
+}
+
+/// The student's growth at a point in the session.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SessionOutcome {
+    pub trail: TrailRecord,
+    pub spellbook: SpellBook,
+    pub character: CharacterSheet,
+}
+
+impl SessionOutcome {
+    fn new(trail: &StudentTrail, spellbook: &SpellBook, character: &CharacterSheet, curriculum: &str, finished: bool) -> Self {
+        Self {
+            trail: TrailRecord::from_trail(trail, curriculum, finished),
+            spellbook: spellbook.clone(),
+            character: character.clone(),
+        }
+    }
+}
+
+/// Resource: the session being recorded, and where it is written.
+#[derive(Resource, Default)]
+pub struct SessionRecorder {
+    pub recording: SessionRecording,
+    frame: u32,
+    /// None records in memory only (a replay's own recording).
+    path: Option<PathBuf>,
+}
+
+impl SessionRecorder {
+    pub fn to_file(path: PathBuf) -> Self {
+        Self { path: Some(path), ..default() }
+    }
+
+    /// `--record=PATH` from the command line.
+    pub fn from_args() -> Option<Self> {
+        std::env::args()
+            .find_map(|a| a.strip_prefix("--record=").map(PathBuf::from))
+            .map(Self::to_file)
+    }
+
+    fn push(&mut self, secs: f64, kind: EventKind) {
+        let frame = self.frame;
+        self.recording.events.push(RecordedEvent { frame, secs, kind });
+    }
+
+    fn last_hand(&self) -> Option<&EventKind> {
+        self.recording.events.iter().rev()
+            .map(|e| &e.kind)
+            .find(|k| matches!(k, EventKind::Hand { .. }))
+    }
+}
+
+/// Record the session played by the rules in `app`. Anything that sets
+/// PendingAction in Update should run before `record_action`.
+pub fn add_recorder(app: &mut App, recorder: SessionRecorder) {
+    app.insert_resource(recorder)
+        .add_systems(Startup, begin_recording.after(crate::save::load_progress))
+        .add_systems(First, count_frame)
+        .add_systems(Update, record_action
+            .before(crate::deck::select_card)
+            .before(crate::deck::dismiss_depth)
+            .before(crate::focus::take_rest_breaths)
+            .before(crate::journey::take_branch))
+        .add_systems(PostUpdate, (record_hand, record_transitions).chain());
+}
+
+/// Everything a session starts from that a recording keeps.
+#[derive(SystemParam)]
+struct SessionStart<'w> {
+    rng: Res<'w, SessionRng>,
+    curriculum: Res<'w, Curriculum>,
+    genre: Res<'w, ActiveGenre>,
+    clock: Res<'w, ReviewClock>,
+    saved: Res<'w, SavedProgress>,
+    deck: Res<'w, DeckChoice>,
+    deck_rules: Res<'w, DeckRules>,
+    daily: Option<Res<'w, ActiveChallenge>>,
+}
+
+/// System: note what the session starts from.
+fn begin_recording(mut recorder: ResMut<SessionRecorder>, start: SessionStart) {
+    let SessionStart { rng, curriculum, genre, clock, saved, deck, deck_rules, daily } = start;
+    let recording = &mut recorder.recording;
+    recording.seed = rng.seed();
+    recording.curriculum = curriculum.name.clone();
+    recording.genre = genre.genre.id().to_string();
+    recording.started_at = clock.now();
+    recording.deck = deck.0.clone();
+    recording.deck_rules = deck_rules.clone();
+    recording.challenge = daily.map(|d| d.challenge.clone());
+    recording.start = saved.data.clone();
+}
+
+fn count_frame(mut recorder: ResMut<SessionRecorder>) {
+    recorder.frame += 1;
+}
+
+/// System: record the action waiting this frame, before anything consumes it.
+pub fn record_action(
+    mut recorder: ResMut<SessionRecorder>,
+    pending: Res<PendingAction>,
+    draft: Res<JournalDraft>,
+    time: Res<Time>,
+) {
+    let Some(action) = pending.action else { return };
+    if action == PlayerAction::Answer {
+        recorder.push(time.elapsed_secs_f64(), EventKind::Draft(draft.text.clone()));
+    }
+    recorder.push(time.elapsed_secs_f64(), EventKind::Action(action));
+}
+
+/// System: record the hand whenever its cards or selection change.
+fn record_hand(
+    mut recorder: ResMut<SessionRecorder>,
+    hand: Res<Hand>,
+    words: Query<&WordCard>,
+    time: Res<Time>,
+) {
+    if !hand.is_changed() {
+        return;
+    }
+    let kind = EventKind::Hand {
+        words: hand.cards.iter().filter_map(|e| words.get(*e).ok().map(|w| w.word.clone())).collect(),
+        selected: hand.selected,
+    };
+    if recorder.last_hand() != Some(&kind) {
+        recorder.push(time.elapsed_secs_f64(), kind);
+    }
+}
+
+/// System: record state transitions, and write the recording out on each.
+fn record_transitions(
+    mut transitions: MessageReader<StateTransitionEvent<GameState>>,
+    mut recorder: ResMut<SessionRecorder>,
+    time: Res<Time>,
+    progress: SessionProgress,
+    curriculum: Option<Res<Curriculum>>,
+    state: Res<State<GameState>>,
+) {
+    let mut transitioned = false;
+    for t in transitions.read() {
+        transitioned = true;
+        recorder.push(time.elapsed_secs_f64(), EventKind::Transition { from: t.exited.clone(), to: t.entered.clone() });
+    }
+    if !transitioned {
+        return;
+    }
+    let finished = *state.get() == GameState::TrailReview;
+    let curriculum = curriculum.as_ref().map(|c| c.name.as_str()).unwrap_or_default();
+    recorder.recording.frames = recorder.frame;
+    recorder.recording.outcome = Some(SessionOutcome::new(
+        &progress.trail, &progress.spellbook, &progress.sheet, curriculum, finished,
+    ));
+
+    if let Some(path) = &recorder.path {
+        if let Err(e) = write_recording(path, &recorder.recording) {
+            warn!("Could not write the session recording: {}", e);
+        }
+    }
+}
+
+fn write_recording(path: &Path, recording: &SessionRecording) -> Result<(), ReplayError> {
+    let json = serde_json::to_string(recording).map_err(|e| ReplayError::BadFile(e.to_string()))?;
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir).map_err(|e| ReplayError::Io(e.to_string()))?;
+    }
+    std::fs::write(path, json).map_err(|e| ReplayError::Io(format!("{}: {}", path.display(), e)))
+}
+
+/// Read a recording file.
+pub fn load_recording(path: &Path) -> Result<SessionRecording, ReplayError> {
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| ReplayError::Io(format!("{}: {}", path.display(), e)))?;
+    serde_json::from_str(&json).map_err(|e| ReplayError::BadFile(format!("{}: {}", path.display(), e)))
+}
+
+// ─── REPLAY ─────────────────────────────────────────────────
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplayError {
+    Io(String),
+    BadFile(String),
+    /// The recording was made with another curriculum.
+    WrongCurriculum { recorded: String, found: String },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::Io(msg) => write!(f, "could not access recording: {}", msg),
+            ReplayError::BadFile(msg) => write!(f, "recording is malformed: {}", msg),
+            ReplayError::WrongCurriculum { recorded, found } => write!(
+                f, "recorded with curriculum '{}', but '{}' was loaded", recorded, found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+/// How a replay compared with its recording.
+#[derive(Serialize, Debug, Clone)]
+pub struct ReplayReport {
+    pub frames: u32,
+    /// Empty when the replay reproduced the session.
+    pub mismatches: Vec<String>,
+    pub outcome: SessionOutcome,
+}
+
+impl ReplayReport {
+    pub fn matched(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// Play a recording back through the rules and compare the result.
+pub fn replay(recording: &SessionRecording, curriculum: &CurriculumSource) -> Result<ReplayReport, ReplayError> {
+    let genre = Genre::parse(&recording.genre).unwrap_or_default();
+    let mut app = headless_app(recording.seed, curriculum);
+    app.insert_resource(SaveStore::Disabled)
+        .insert_resource(ActiveGenre::load(genre, curriculum))
+        .insert_resource(ReviewClock::Fixed(recording.started_at))
+        .insert_resource(DeckChoice(recording.deck.clone()))
+        .insert_resource(recording.deck_rules.clone())
+        .insert_resource(CarriedProgress(recording.start.clone()))
+        .add_systems(Startup, carry_progress.after(crate::save::load_progress));
+    // A challenge replayed keeps its result in memory, never on disk
+    if let Some(daily) = &recording.challenge {
+        challenge::add_challenge(&mut app, ActiveChallenge::new(daily.clone()));
+    }
+    add_recorder(&mut app, SessionRecorder::default());
+    // Game time is set by the recording, however long the gaps were
+    app.world_mut().resource_mut::<Time<Virtual>>().set_max_delta(Duration::from_secs(u32::MAX as u64));
+
+    let mut drafts = recording.events.iter()
+        .filter_map(|e| match &e.kind {
+            EventKind::Draft(text) => Some((e.frame, text)),
+            _ => None,
+        })
+        .peekable();
+    let mut actions = recording.events.iter()
+        .filter_map(|e| match e.kind {
+            EventKind::Action(action) => Some((e.frame, e.secs, action)),
+            _ => None,
+        })
+        .peekable();
+    for frame in 1..=recording.frames {
+        if let Some((_, text)) = drafts.next_if(|(f, _)| *f == frame) {
+            app.world_mut().resource_mut::<JournalDraft>().text = text.clone();
+        }
+        let mut step = Duration::ZERO;
+        if let Some((_, secs, action)) = actions.next_if(|(f, _, _)| *f == frame) {
+            let now = app.world().resource::<Time>().elapsed_secs_f64();
+            step = Duration::from_secs_f64((secs - now).max(0.0));
+            app.world_mut().resource_mut::<PendingAction>().action = Some(action);
+        }
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(step));
+        app.update();
+
+        if frame == 1 {
+            let found = app.world().resource::<Curriculum>().name.clone();
+            if found != recording.curriculum {
+                return Err(ReplayError::WrongCurriculum { recorded: recording.curriculum.clone(), found });
+            }
+        }
+    }
+
+    let world = app.world();
+    let replayed = &world.resource::<SessionRecorder>().recording;
+    let outcome = SessionOutcome::new(
+        world.resource::<StudentTrail>(),
+        world.resource::<SpellBook>(),
+        world.resource::<CharacterSheet>(),
+        &recording.curriculum,
+        world.resource::<State<GameState>>().get() == &GameState::TrailReview,
+    );
+    Ok(ReplayReport {
+        frames: recording.frames,
+        mismatches: compare(recording, replayed, &outcome),
+        outcome,
+    })
+}
+
+/// Where a replay differs from its recording. Times between actions
+/// aren't replayed, so events are compared by frame and kind.
+fn compare(recorded: &SessionRecording, replayed: &SessionRecording, outcome: &SessionOutcome) -> Vec<String> {
+    let mut mismatches = Vec::new();
+    let events = |r: &SessionRecording| -> Vec<(u32, EventKind)> {
+        r.events.iter().map(|e| (e.frame, e.kind.clone())).collect()
+    };
+    let (a, b) = (events(recorded), events(replayed));
+    if let Some(i) = (0..a.len().max(b.len())).find(|i| a.get(*i) != b.get(*i)) {
+        mismatches.push(format!("event {} differs: recorded {:?}, replayed {:?}", i, a.get(i), b.get(i)));
+    }
+
+    if let Some(expected) = &recorded.outcome {
+        if !same(&expected.trail, &outcome.trail) {
+            mismatches.push("StudentTrail differs".to_string());
+        }
+        if !same(&expected.spellbook, &outcome.spellbook) {
+            mismatches.push("SpellBook differs".to_string());
+        }
+        if !same(&expected.character, &outcome.character) {
+            mismatches.push("CharacterSheet differs".to_string());
+        }
+    }
+    mismatches
+}
+
+/// Compare records by their saved form, which is what research reads.
+fn same<T: Serialize>(a: &T, b: &T) -> bool {
+    serde_json::to_value(a).ok() == serde_json::to_value(b).ok()
+}
+
+/// Entry point for `--replay=FILE`. Returns the process exit code.
+pub fn run_from_args() -> i32 {
+    let Some(path) = std::env::args().find_map(|a| a.strip_prefix("--replay=").map(PathBuf::from)) else {
+        return 1;
+    };
+    let result = load_recording(&path)
+        .and_then(|recording| replay(&recording, &CurriculumSource::from_args()));
+    match result {
+        Ok(report) => {
+            println!("{}", serde_json::to_string_pretty(&report).unwrap_or_default());
+            if report.matched() {
+                0
+            } else {
+                for mismatch in &report.mismatches {
+                    eprintln!("daydream --replay: {}", mismatch);
+                }
+                1
+            }
+        }
+        Err(e) => {
+            eprintln!("daydream --replay: {}", e);
+            1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::harness::play_to_review;
+    use crate::sim::{drive_simulation, SimDriver, SimPolicy};
+
+    /// Frames take 0.3s, 2.5s or 1.2s in turn, so some breaths are
+    /// rushed and some paced — timing that replay must match.
+    fn uneven_frames(mut strategy: ResMut<TimeUpdateStrategy>, mut frame: Local<usize>) {
+        const STEPS_MS: [u64; 3] = [300, 2_500, 1_200];
+        *frame += 1;
+        *strategy = TimeUpdateStrategy::ManualDuration(Duration::from_millis(STEPS_MS[*frame % 3]));
+    }
+
+    /// Play an all-cast session to the trail review.
+    fn record_session(seed: u64) -> SessionRecording {
+        record_session_with(seed, |_| {})
+    }
+
+    /// Play an all-cast session to the trail review, from what `setup` adds.
+    fn record_session_with(seed: u64, setup: impl FnOnce(&mut App)) -> SessionRecording {
+        let mut app = headless_app(seed, &CurriculumSource::Demo);
+        setup(&mut app);
+        app.insert_resource(ReviewClock::Fixed(1_735_689_600))
+            .insert_resource(SimDriver::new(Vec::new(), SimPolicy::Cast, seed))
+            .add_systems(Update, drive_simulation.before(record_action))
+            .add_systems(Last, uneven_frames);
+        app.world_mut().resource_mut::<Time<Virtual>>().set_max_delta(Duration::from_secs(10));
+        add_recorder(&mut app, SessionRecorder::default());
+
+        play_to_review(&mut app, 5_000);
+        app.world().resource::<SessionRecorder>().recording.clone()
+    }
+
+    #[test]
+    fn test_replay_reproduces_the_session() {
+        let recording = record_session(11);
+        let rested = recording.events.iter()
+            .any(|e| matches!(&e.kind, EventKind::Transition { to: Some(GameState::Resting), .. }));
+        assert!(rested);
+        let outcome = recording.outcome.as_ref().unwrap();
+        assert!(outcome.trail.finished);
+
+        let report = replay(&recording, &CurriculumSource::Demo).unwrap();
+        assert!(report.matched(), "{:?}", report.mismatches);
+    }
+
+    #[test]
+    fn test_replay_deals_the_constructed_deck_again() {
+        let words = ["Presence", "Bias", "Patience", "Clarity"];
+        let mut start = SaveData::default();
+        for (word, channel) in words.iter().zip([Channel::Body, Channel::Mind, Channel::Heart, Channel::Mind]) {
+            start.spellbook.sync_power(word, channel, &SpellPower { mastery: MasteryLevel::Owned, ..default() });
+        }
+        start.deck_library.decks.push(crate::constructed::ConstructedDeck {
+            name: "Still".to_string(),
+            words: words.map(String::from).to_vec(),
+        });
+        let recording = record_session_with(14, |app| {
+            app.insert_resource(DeckRules { min_size: 4, ..default() })
+                .insert_resource(DeckChoice(Some("Still".to_string())))
+                .insert_resource(CarriedProgress(start))
+                .add_systems(Startup, carry_progress.after(crate::save::load_progress).before(begin_recording));
+        });
+        assert_eq!(recording.deck.as_deref(), Some("Still"));
+        let cast = &recording.outcome.as_ref().unwrap().trail.cast_words;
+        assert!(cast.iter().all(|w| words.contains(&w.as_str())), "{:?}", cast);
+
+        let report = replay(&recording, &CurriculumSource::Demo).unwrap();
+        assert!(report.matched(), "{:?}", report.mismatches);
+
+        // Without the choice, the whole curriculum is dealt instead
+        let forgotten = SessionRecording { deck: None, ..recording };
+        assert!(!replay(&forgotten, &CurriculumSource::Demo).unwrap().matched());
+    }
+
+    #[test]
+    fn test_replay_plays_the_daily_challenge_again() {
+        let daily = DailyChallenge::new(
+            challenge::ChallengeDate::parse("2026-10-18").unwrap(), "",
+            &CurriculumSource::Demo.resolve(),
+        );
+        let recording = record_session_with(15, |app| {
+            challenge::add_challenge(app, ActiveChallenge::new(daily.clone()));
+        });
+        assert_eq!(recording.challenge.as_ref(), Some(&daily));
+        assert_eq!(recording.seed, daily.seed);
+
+        let report = replay(&recording, &CurriculumSource::Demo).unwrap();
+        assert!(report.matched(), "{:?}", report.mismatches);
+        assert_eq!(report.outcome.trail.cast_words.len(), daily.deck.len());
+
+        // Without the challenge, the student's own deal comes back instead
+        let forgotten = SessionRecording { challenge: None, ..recording };
+        assert!(!replay(&forgotten, &CurriculumSource::Demo).unwrap().matched());
+    }
+
+    #[test]
+    fn test_a_tampered_recording_does_not_match() {
+        let mut recording = record_session(12);
+        let swipe = recording.events.iter_mut()
+            .find(|e| matches!(e.kind, EventKind::Action(PlayerAction::Swipe(_))))
+            .unwrap();
+        swipe.kind = EventKind::Action(match swipe.kind {
+            EventKind::Action(PlayerAction::Swipe(SwipeChoice::Yes)) => PlayerAction::Swipe(SwipeChoice::No),
+            _ => PlayerAction::Swipe(SwipeChoice::Yes),
+        });
+        let report = replay(&recording, &CurriculumSource::Demo).unwrap();
+        assert!(!report.matched());
+    }
+
+    #[test]
+    fn test_recording_round_trips_through_json() {
+        let recording = record_session(13);
+        let json = serde_json::to_string(&recording).unwrap();
+        let loaded: SessionRecording = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.events, recording.events);
+        assert_eq!(loaded.seed, 13);
+        assert_eq!(loaded.curriculum, "Bias & Mirrors");
+    }
+}
@@ -0,0 +1,127 @@
+// Daydream Engine — Session RNG
+// A tiny seedable generator (SplitMix64) so every draw in a session
+// can be reproduced from its seed. Research runs pin the seed with
+// `--seed=N`; classroom runs take a fresh seed so every student's
+// deck unfolds differently.
+//
+// (We avoid the rand dependency to keep the build minimal.)
+
+use bevy::prelude::*;
+
+/// Resource: the seeded random stream for one play session.
+#[derive(Resource, Clone, Debug)]
+pub struct SessionRng {
+    seed: u64,
+    state: u64,
+}
+
+impl SessionRng {
+    /// Start a stream from a known seed.
+    pub fn from_seed(seed: u64) -> Self {
+        Self { seed, state: seed }
+    }
+
+    /// Use `--seed=N` from the command line if present, otherwise a fresh seed.
+    pub fn from_args_or_entropy() -> Self {
+        let seed = std::env::args()
+            .find_map(|a| a.strip_prefix("--seed=").and_then(|n| n.parse().ok()))
+            .unwrap_or_else(entropy_seed);
+        Self::from_seed(seed)
+    }
+
+    /// The seed this session started from — log it to reproduce the run.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Next raw 64-bit value (SplitMix64).
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform float in [0, 1).
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Uniform integer in [0, n). Returns 0 when n is 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        if n == 0 { 0 } else { (self.next_u64() % n as u64) as usize }
+    }
+
+    /// Fisher–Yates shuffle in place.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i + 1);
+            items.swap(i, j);
+        }
+    }
+
+    /// Pick an index with probability proportional to its weight.
+    /// Returns None if there are no positive weights.
+    pub fn pick_weighted(&mut self, weights: &[f32]) -> Option<usize> {
+        let total: f32 = weights.iter().filter(|w| **w > 0.0).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut roll = self.next_f32() * total;
+        for (i, w) in weights.iter().enumerate() {
+            if *w <= 0.0 { continue; }
+            if roll < *w {
+                return Some(i);
+            }
+            roll -= w;
+        }
+        // Float rounding: fall back to the last positive weight
+        weights.iter().rposition(|w| *w > 0.0)
+    }
+}
+
+/// A seed for when none was asked for: the clock, and in the browser
+/// (whose clock only counts milliseconds) some of Math.random too.
+pub(crate) fn entropy_seed() -> u64 {
+    let seed = unix_now().as_nanos() as u64;
+    #[cfg(target_arch = "wasm32")]
+    let seed = seed ^ (js_sys::Math::random() * u32::MAX as f64) as u64;
+    seed
+}
+
+/// Time since the Unix epoch, by the wall clock.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn unix_now() -> std::time::Duration {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default()
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn unix_now() -> std::time::Duration {
+    // SystemTime is unavailable in the browser; use the JS clock.
+    std::time::Duration::from_secs_f64(js_sys::Date::now() / 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_stream() {
+        let mut a = SessionRng::from_seed(42);
+        let mut b = SessionRng::from_seed(42);
+        for _ in 0..16 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn test_pick_weighted_skips_zero_weights() {
+        let mut rng = SessionRng::from_seed(7);
+        for _ in 0..100 {
+            assert_eq!(rng.pick_weighted(&[0.0, 3.0, 0.0]), Some(1));
+        }
+        assert_eq!(rng.pick_weighted(&[0.0, 0.0]), None);
+    }
+}
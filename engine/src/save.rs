@@ -0,0 +1,437 @@
+// Daydream Engine — Save / Load
+// A student's growth outlives the session.
+//
+// What persists: the CharacterSheet, the SpellBook, every word's
+// SpellPower (keyed by word, so it survives curriculum edits), the
+// Recycler's review schedule, the student's constructed decks, and the
+// StudentTrail of each session as a plain-text record.
+//
+// Where: a JSON file on native (`--save=PATH`, default
+// `saves/progress.json`), browser localStorage on wasm. `--no-save`
+// disables persistence for research runs.
+//
+// Format: versioned JSON. Adding a field needs nothing but
+// #[serde(default)] — older saves fill it in, older engines ignore it.
+// Renaming or reshaping a field needs a step in MIGRATIONS. An older
+// engine plays a newer save read-only: writing it back would drop the
+// fields it doesn't know, so it never autosaves over one.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::components::*;
+use crate::constructed::DeckLibrary;
+use crate::dag::Curriculum;
+use crate::recycler::ReviewSchedule;
+
+/// The format written by this build.
+pub const SAVE_VERSION: u32 = 1;
+
+/// One step per format change: MIGRATIONS[n] upgrades a version n+1
+/// save to version n+2. Empty until the first breaking change.
+const MIGRATIONS: &[fn(&mut Value)] = &[];
+
+#[cfg(not(target_arch = "wasm32"))]
+const DEFAULT_SAVE_PATH: &str = "saves/progress.json";
+
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY: &str = "daydream.progress";
+
+// ─── SAVE DATA ──────────────────────────────────────────────
+
+/// Everything the engine remembers between sessions.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct SaveData {
+    pub version: u32,
+    pub character: CharacterSheet,
+    pub spellbook: SpellBook,
+    /// Per-word SpellPower, including words not in the current curriculum.
+    pub spell_powers: BTreeMap<String, SpellPower>,
+    /// When each word returns from the Recycler.
+    pub review_schedule: ReviewSchedule,
+    /// Decks the student built from their SpellBook.
+    pub deck_library: DeckLibrary,
+    /// The latest session's trail (still growing while it is played).
+    pub trail: TrailRecord,
+    /// Earlier sessions, oldest first.
+    pub past_trails: Vec<TrailRecord>,
+}
+
+impl Default for SaveData {
+    fn default() -> Self {
+        Self {
+            version: SAVE_VERSION,
+            character: CharacterSheet::default(),
+            spellbook: SpellBook::default(),
+            spell_powers: BTreeMap::new(),
+            review_schedule: ReviewSchedule::default(),
+            deck_library: DeckLibrary::default(),
+            trail: TrailRecord::default(),
+            past_trails: Vec::new(),
+        }
+    }
+}
+
+/// A StudentTrail without entity handles — the session as the student
+/// would retell it.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct TrailRecord {
+    pub curriculum: String,
+    pub visited_words: Vec<String>,
+    pub swipe_history: Vec<SwipeChoice>,
+    pub cast_words: Vec<String>,
+    /// The text of each completed story moment.
+    pub moments: Vec<String>,
+    /// The chapters those moments were told in.
+    pub chapters: Vec<ChapterRecord>,
+    pub mastery_gains: Vec<(String, MasteryLevel)>,
+    /// Whether the session reached the trail review.
+    pub finished: bool,
+}
+
+/// One chapter of a saved trail.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct ChapterRecord {
+    pub stage: JourneyStage,
+    /// How many of the trail's moments belong to this chapter.
+    pub moments: usize,
+    /// The word the student branched to at the chapter's end.
+    pub branch: Option<String>,
+    pub branch_edge: Option<BranchEdge>,
+}
+
+impl TrailRecord {
+    pub fn from_trail(trail: &StudentTrail, curriculum: &str, finished: bool) -> Self {
+        Self {
+            curriculum: curriculum.to_string(),
+            visited_words: trail.visited_words.clone(),
+            swipe_history: trail.swipe_history.clone(),
+            cast_words: trail.cast_words.clone(),
+            moments: trail.moments().map(|m| m.generated_text.clone()).collect(),
+            chapters: trail.chapters.iter()
+                .filter(|c| !c.moments.is_empty() || c.branch.is_some())
+                .map(|c| ChapterRecord {
+                    stage: c.stage,
+                    moments: c.moments.len(),
+                    branch: c.branch.as_ref().map(|b| b.word.clone()),
+                    branch_edge: c.branch.as_ref().map(|b| b.edge),
+                })
+                .collect(),
+            mastery_gains: trail.mastery_gains.clone(),
+            finished,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.visited_words.is_empty() && self.swipe_history.is_empty()
+    }
+}
+
+impl SaveData {
+    /// Parse a save of any known version, migrating it to this one.
+    pub fn from_json(json: &str) -> Result<Self, SaveError> {
+        let mut value: Value = serde_json::from_str(json)
+            .map_err(|e| SaveError::Parse(e.to_string()))?;
+        let from = value.get("version").and_then(Value::as_u64).unwrap_or(1) as u32;
+
+        if from > SAVE_VERSION {
+            // Written by a newer engine: keep what we understand.
+            warn!("Save is version {} (this build writes {}); loading known fields", from, SAVE_VERSION);
+        } else {
+            for step in MIGRATIONS.iter().skip(from.saturating_sub(1) as usize) {
+                step(&mut value);
+            }
+        }
+
+        let mut data: SaveData = serde_json::from_value(value)
+            .map_err(|e| SaveError::Parse(e.to_string()))?;
+        data.version = from.max(SAVE_VERSION);
+        Ok(data)
+    }
+
+    /// Written by a newer engine, so this build must not write it back.
+    pub fn is_newer(&self) -> bool {
+        self.version > SAVE_VERSION
+    }
+
+    pub fn to_json(&self) -> Result<String, SaveError> {
+        serde_json::to_string_pretty(self).map_err(|e| SaveError::Parse(e.to_string()))
+    }
+
+    /// Move an unfinished or finished trail into history so a new
+    /// session starts with a clean one.
+    fn archive_trail(&mut self) {
+        if !self.trail.is_empty() {
+            self.past_trails.push(std::mem::take(&mut self.trail));
+        }
+    }
+}
+
+/// Why a save could not be read or written.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SaveError {
+    Io(String),
+    Parse(String),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::Io(msg) => write!(f, "could not access save: {}", msg),
+            SaveError::Parse(msg) => write!(f, "save is malformed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+// ─── STORAGE ────────────────────────────────────────────────
+
+/// Resource: where progress is kept.
+#[derive(Resource, Clone, Debug)]
+pub enum SaveStore {
+    /// Persistence switched off (`--no-save`).
+    Disabled,
+    /// A JSON file on disk.
+    #[cfg(not(target_arch = "wasm32"))]
+    File(std::path::PathBuf),
+    /// A key in the browser's localStorage.
+    #[cfg(target_arch = "wasm32")]
+    Browser(String),
+}
+
+impl SaveStore {
+    /// `--no-save`, `--save=PATH`, or the platform default.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = std::env::args().collect();
+        if args.iter().any(|a| a == "--no-save") {
+            return SaveStore::Disabled;
+        }
+        Self::platform_default(&args)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn platform_default(args: &[String]) -> Self {
+        let path = args.iter()
+            .find_map(|a| a.strip_prefix("--save="))
+            .unwrap_or(DEFAULT_SAVE_PATH);
+        SaveStore::File(path.into())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn platform_default(_args: &[String]) -> Self {
+        SaveStore::Browser(STORAGE_KEY.to_string())
+    }
+
+    /// Read the raw save, or None if nothing has been saved yet.
+    pub fn read(&self) -> Result<Option<String>, SaveError> {
+        match self {
+            SaveStore::Disabled => Ok(None),
+            #[cfg(not(target_arch = "wasm32"))]
+            SaveStore::File(path) => match std::fs::read_to_string(path) {
+                Ok(json) => Ok(Some(json)),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                Err(e) => Err(SaveError::Io(format!("{}: {}", path.display(), e))),
+            },
+            #[cfg(target_arch = "wasm32")]
+            SaveStore::Browser(key) => local_storage()?
+                .get_item(key)
+                .map_err(|_| SaveError::Io("localStorage read failed".to_string())),
+        }
+    }
+
+    /// Write the raw save, replacing the previous one.
+    pub fn write(&self, json: &str) -> Result<(), SaveError> {
+        match self {
+            SaveStore::Disabled => Ok(()),
+            #[cfg(not(target_arch = "wasm32"))]
+            SaveStore::File(path) => {
+                let io = |e: std::io::Error| SaveError::Io(format!("{}: {}", path.display(), e));
+                if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+                    std::fs::create_dir_all(dir).map_err(io)?;
+                }
+                // Write beside the save, then swap, so a crash never leaves half a file
+                let tmp = path.with_extension("json.tmp");
+                std::fs::write(&tmp, json).map_err(io)?;
+                std::fs::rename(&tmp, path).map_err(io)
+            }
+            #[cfg(target_arch = "wasm32")]
+            SaveStore::Browser(key) => local_storage()?
+                .set_item(key, json)
+                .map_err(|_| SaveError::Io("localStorage write failed".to_string())),
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Result<web_sys::Storage, SaveError> {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .ok_or_else(|| SaveError::Io("localStorage unavailable".to_string()))
+}
+
+// ─── SYSTEMS ────────────────────────────────────────────────
+
+/// Resource: the save as last loaded or written. Holds SpellPower for
+/// words that are not (or not yet) spawned.
+#[derive(Resource, Default, Debug, Clone)]
+pub struct SavedProgress {
+    pub data: SaveData,
+}
+
+/// System: load saved progress over the fresh defaults.
+/// Runs after the curriculum has spawned its resources.
+pub fn load_progress(mut commands: Commands, store: Res<SaveStore>) {
+    let mut data = match store.read().and_then(|raw| raw.map(|json| SaveData::from_json(&json)).transpose()) {
+        Ok(Some(data)) => {
+            info!("Loaded progress: {} words in the SpellBook", data.spellbook.entries.len());
+            if data.is_newer() {
+                // Saving would drop what the newer engine wrote; play read-only
+                warn!("Save is from a newer engine; this session's progress won't be saved");
+                commands.insert_resource(SaveStore::Disabled);
+            }
+            data
+        }
+        Ok(None) => SaveData::default(),
+        Err(e) => {
+            // Keep the bad save untouched on disk; play on from scratch
+            error!("{}; starting fresh", e);
+            commands.insert_resource(SaveStore::Disabled);
+            SaveData::default()
+        }
+    };
+    data.archive_trail();
+
+    commands.insert_resource(data.character.clone());
+    commands.insert_resource(data.spellbook.clone());
+    commands.insert_resource(data.review_schedule.clone());
+    commands.insert_resource(data.deck_library.clone());
+    commands.insert_resource(SavedProgress { data });
+}
+
+/// System: give newly spawned word entities their saved SpellPower.
+/// Also covers words respawned by a curriculum hot reload.
+pub fn restore_spell_powers(
+    saved: Res<SavedProgress>,
+    mut words: Query<(&WordCard, &mut SpellPower), Added<WordCard>>,
+) {
+    for (card, mut power) in &mut words {
+        if let Some(stored) = saved.data.spell_powers.get(&card.word) {
+            *power = stored.clone();
+        }
+    }
+}
+
+/// System param: the progress a session makes, as it stands.
+#[derive(SystemParam)]
+pub struct SessionProgress<'w> {
+    pub sheet: Res<'w, CharacterSheet>,
+    pub spellbook: Res<'w, SpellBook>,
+    pub trail: Res<'w, StudentTrail>,
+    pub schedule: Res<'w, ReviewSchedule>,
+    pub decks: Res<'w, DeckLibrary>,
+}
+
+/// System: write progress whenever the game changes state.
+pub fn autosave_on_transition(
+    mut transitions: MessageReader<StateTransitionEvent<GameState>>,
+    store: Res<SaveStore>,
+    mut saved: ResMut<SavedProgress>,
+    progress: SessionProgress,
+    curriculum: Option<Res<Curriculum>>,
+    words: Query<(&WordCard, &SpellPower)>,
+) {
+    let SessionProgress { sheet, spellbook, trail, schedule, decks } = progress;
+    let Some(last) = transitions.read().last() else { return };
+    if matches!(store.as_ref(), SaveStore::Disabled) {
+        return;
+    }
+    let finished = last.entered == Some(GameState::TrailReview);
+    let curriculum = curriculum.as_ref().map(|c| c.name.as_str()).unwrap_or_default();
+
+    let data = &mut saved.data;
+    data.character = sheet.clone();
+    data.spellbook = spellbook.clone();
+    data.review_schedule = schedule.clone();
+    data.deck_library = decks.clone();
+    for (card, power) in &words {
+        data.spell_powers.insert(card.word.clone(), power.clone());
+    }
+    data.trail = TrailRecord::from_trail(&trail, curriculum, finished);
+
+    if let Err(e) = data.to_json().and_then(|json| store.write(&json)) {
+        warn!("Autosave failed: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut data = SaveData::default();
+        data.character.total_deeper_swipes = 4;
+        let power = SpellPower { times_cast: 2, mastery: MasteryLevel::Owned, ..default() };
+        data.spellbook.sync_power("Presence", Channel::Body, &power);
+        data.spell_powers.insert("Presence".to_string(), power);
+        data.trail.cast_words.push("Presence".to_string());
+        data.review_schedule.cards.insert("Presence".to_string(), crate::recycler::ReviewCard { due: 42, ..default() });
+
+        let loaded = SaveData::from_json(&data.to_json().unwrap()).unwrap();
+        assert_eq!(loaded.character.total_deeper_swipes, 4);
+        assert_eq!(loaded.spellbook.entries[0].mastery, MasteryLevel::Owned);
+        assert_eq!(loaded.spell_powers["Presence"].times_cast, 2);
+        assert_eq!(loaded.trail.cast_words, vec!["Presence"]);
+        assert_eq!(loaded.review_schedule.cards["Presence"].due, 42);
+    }
+
+    #[test]
+    fn test_missing_and_unknown_fields_are_tolerated() {
+        // An older save lacks fields; a newer one carries fields we don't know
+        let json = r#"{
+            "version": 99,
+            "character": { "mind_attunement": 0.5, "future_stat": 3 },
+            "spellbook": { "entries": [{ "word": "Bias", "channel": "Mind" }] },
+            "spell_powers": { "Bias": { "times_encountered": 2 } },
+            "new_section": {}
+        }"#;
+        let loaded = SaveData::from_json(json).unwrap();
+        assert_eq!(loaded.version, 99);
+        assert!(loaded.is_newer());
+        assert_eq!(loaded.character.mind_attunement, 0.5);
+        assert_eq!(loaded.character.emergent_class, "Newcomer");
+        assert_eq!(loaded.spellbook.entries[0].mastery, MasteryLevel::Encountered);
+        assert_eq!(loaded.spell_powers["Bias"].times_encountered, 2);
+        assert!(loaded.past_trails.is_empty());
+    }
+
+    #[test]
+    fn test_newer_save_is_never_overwritten() {
+        let dir = std::env::temp_dir().join(format!("daydream-save-{}", std::process::id()));
+        let path = dir.join("progress.json");
+        let newer = r#"{ "version": 99, "character": { "future_stat": 3 } }"#;
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&path, newer).unwrap();
+
+        let mut app = App::new();
+        app.insert_resource(SaveStore::File(path.clone()));
+        app.add_systems(Update, load_progress);
+        app.update();
+
+        assert!(matches!(app.world().resource::<SaveStore>(), SaveStore::Disabled));
+        assert!(app.world().resource::<SavedProgress>().data.is_newer());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_malformed_save_is_an_error() {
+        assert!(matches!(SaveData::from_json("{ not json"), Err(SaveError::Parse(_))));
+    }
+}
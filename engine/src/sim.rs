@@ -0,0 +1,599 @@
+// Daydream Engine — Headless Simulation
+// Play whole sessions without a window, for balancing curricula and
+// for integration tests of the deck/mastery loop.
+//
+//   daydream-engine --headless [curriculum.json] [--seed=N]
+//       [--policy=cast|skip|deeper|random] [--script=steps.json]
+//       [--sessions=N] [--genre=noir] [--max-focus=N] [--chapter-moments=N]
+//       [--days-between=N] [--challenge[=DATE]] [--out=report.json] [--save=PATH]
+//
+// The simulated student follows the script first, then the policy.
+// A script is a JSON list of steps: `{"select": 2}` picks a hand card,
+// `{"swipe": "Deeper"}` acts on the selected one. Growth carries over
+// from one session to the next; `--save=PATH` also writes it to disk.
+// Sessions run on a fixed calendar, `--days-between` days apart, so
+// the Recycler's schedule plays out the same way every run.
+// The report (CharacterSheet, archetype, SpellBook and trail per session) goes to
+// stdout or `--out`.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use bevy::prelude::*;
+use bevy::input::InputPlugin;
+use bevy::state::app::StatesPlugin;
+use serde::{Deserialize, Serialize};
+use crate::archetype::ArchetypeReading;
+use crate::challenge::{self, ActiveChallenge, DailyChallenge};
+use crate::components::*;
+use crate::dag::{Curriculum, CurriculumSource};
+use crate::focus::FocusRules;
+use crate::journey::JourneyRules;
+use crate::genre::{ActiveGenre, Genre};
+use crate::input::{PendingAction, PlayerAction};
+use crate::recycler::{ReviewClock, ReviewSchedule, DAY_SECS};
+use crate::rng::SessionRng;
+use crate::save::{SaveData, SaveStore, SavedProgress, TrailRecord};
+use crate::DaydreamPlugin;
+
+/// A session that hasn't reached the trail review by now is stuck.
+const MAX_FRAMES: u32 = 20_000;
+/// The simulated calendar starts here (2025-01-01, UTC).
+const SIM_EPOCH: u64 = 1_735_689_600;
+
+// ─── SCRIPT & POLICY ────────────────────────────────────────
+
+/// One scripted student action.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SimStep {
+    /// Select the hand card at this index (clamped to the hand).
+    Select(usize),
+    /// Swipe the selected card.
+    Swipe(SwipeChoice),
+}
+
+/// What the simulated student does once the script runs out.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SimPolicy {
+    /// Cast the first card in hand, every time.
+    Cast,
+    /// Skip the first card in hand, every time.
+    Skip,
+    /// Dig deeper into the first card, then cast it.
+    Deeper,
+    /// Pick any card and any swipe (seeded).
+    Random,
+}
+
+impl SimPolicy {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "cast" | "yes" => Some(SimPolicy::Cast),
+            "skip" | "no" => Some(SimPolicy::Skip),
+            "deeper" => Some(SimPolicy::Deeper),
+            "random" => Some(SimPolicy::Random),
+            _ => None,
+        }
+    }
+}
+
+/// Resource: the simulated student.
+#[derive(Resource, Debug)]
+pub struct SimDriver {
+    pub script: VecDeque<SimStep>,
+    pub policy: SimPolicy,
+    /// Separate from SessionRng so choices never disturb the draws.
+    rng: SessionRng,
+    /// The card already explored deeper, so policies move on from it.
+    explored: Option<Entity>,
+}
+
+impl SimDriver {
+    pub fn new(script: Vec<SimStep>, policy: SimPolicy, seed: u64) -> Self {
+        Self {
+            script: script.into(),
+            policy,
+            rng: SessionRng::from_seed(seed ^ 0xD15C_0DE5),
+            explored: None,
+        }
+    }
+
+    /// Which hand card to select.
+    fn choose_card(&mut self, hand_size: usize) -> usize {
+        if let Some(SimStep::Select(i)) = self.script.front().copied() {
+            self.script.pop_front();
+            return i.min(hand_size.saturating_sub(1));
+        }
+        match self.policy {
+            SimPolicy::Random => self.rng.below(hand_size),
+            _ => 0,
+        }
+    }
+
+    /// Which way to go at a branch point (right, left or down).
+    fn choose_branch(&mut self, options: usize) -> SwipeChoice {
+        if let Some(SimStep::Swipe(choice)) = self.script.front().copied() {
+            self.script.pop_front();
+            return choice;
+        }
+        let index = match self.policy {
+            SimPolicy::Cast => 0,
+            SimPolicy::Skip => 1,
+            SimPolicy::Deeper => 2,
+            SimPolicy::Random => self.rng.below(options),
+        };
+        BranchPoint::SWIPES[index.min(options.saturating_sub(1))]
+    }
+
+    /// How to swipe the selected card.
+    fn choose_swipe(&mut self, card: Entity) -> SwipeChoice {
+        if let Some(SimStep::Swipe(choice)) = self.script.front().copied() {
+            self.script.pop_front();
+            return choice;
+        }
+        let explored = self.explored == Some(card);
+        match self.policy {
+            SimPolicy::Cast => SwipeChoice::Yes,
+            SimPolicy::Skip => SwipeChoice::No,
+            SimPolicy::Deeper if explored => SwipeChoice::Yes,
+            SimPolicy::Deeper => SwipeChoice::Deeper,
+            SimPolicy::Random => {
+                let options: &[SwipeChoice] = if explored {
+                    &[SwipeChoice::Yes, SwipeChoice::No]
+                } else {
+                    &[SwipeChoice::Yes, SwipeChoice::No, SwipeChoice::Deeper]
+                };
+                options[self.rng.below(options.len())]
+            }
+        }
+    }
+}
+
+/// System: act for the student in whatever state the game is in —
+/// select in Playing, swipe in CardSelected, close the depth view,
+/// pick a branch.
+/// Actions go through PendingAction, just like keys and gestures.
+pub fn drive_simulation(
+    mut driver: ResMut<SimDriver>,
+    hand: Res<Hand>,
+    branch_point: Res<BranchPoint>,
+    mut pending: ResMut<PendingAction>,
+    state: Res<State<GameState>>,
+) {
+    if pending.action.is_some() {
+        return;
+    }
+    pending.action = match state.get() {
+        GameState::Playing if hand.card_count() > 0 => {
+            Some(PlayerAction::Select(driver.choose_card(hand.card_count())))
+        }
+        GameState::CardSelected => {
+            let Some(card) = hand.selected.and_then(|i| hand.cards.get(i).copied()) else { return };
+            let choice = driver.choose_swipe(card);
+            if choice == SwipeChoice::Deeper {
+                driver.explored = Some(card);
+            }
+            Some(PlayerAction::Swipe(choice))
+        }
+        GameState::DepthView => Some(PlayerAction::Back),
+        // One breath per frame until the Hero is rested
+        GameState::Resting => Some(PlayerAction::Back),
+        GameState::BranchPoint if !branch_point.options.is_empty() => {
+            Some(PlayerAction::Swipe(driver.choose_branch(branch_point.options.len())))
+        }
+        _ => None,
+    };
+}
+
+/// Resource: progress from the previous simulated session.
+#[derive(Resource)]
+pub(crate) struct CarriedProgress(pub SaveData);
+
+/// System: start the session from the previous one's progress.
+pub(crate) fn carry_progress(mut commands: Commands, carried: Option<Res<CarriedProgress>>) {
+    let Some(carried) = carried else { return };
+    let data = carried.0.clone();
+    commands.insert_resource(data.character.clone());
+    commands.insert_resource(data.spellbook.clone());
+    commands.insert_resource(data.review_schedule.clone());
+    commands.insert_resource(data.deck_library.clone());
+    commands.insert_resource(SavedProgress { data });
+}
+
+// ─── RUNNING ────────────────────────────────────────────────
+
+/// Everything needed to run a batch of sessions.
+#[derive(Clone, Debug)]
+pub struct SimConfig {
+    pub curriculum: CurriculumSource,
+    pub genre: Genre,
+    pub seed: u64,
+    pub policy: SimPolicy,
+    pub script: Vec<SimStep>,
+    pub sessions: u32,
+    pub store: SaveStore,
+    /// The Hero's Focus pool; lower it to exercise rest points.
+    pub max_focus: u32,
+    /// Story moments per chapter; lower it to reach branch points sooner.
+    pub moments_per_chapter: usize,
+    /// Days between simulated sessions, as the Recycler sees them.
+    pub days_between: u64,
+    /// Play this daily challenge instead of a seeded deal (see challenge.rs).
+    pub challenge: Option<DailyChallenge>,
+}
+
+/// The outcome of one session.
+#[derive(Serialize, Debug, Clone)]
+pub struct SessionReport {
+    pub seed: u64,
+    pub frames: u32,
+    /// Rest points the Hero needed along the way.
+    pub rests: u32,
+    pub character: CharacterSheet,
+    /// The archetype read at the trail review, with its evidence.
+    pub archetype: ArchetypeReading,
+    pub spellbook: SpellBook,
+    pub trail: TrailRecord,
+}
+
+/// The outcome of a batch.
+#[derive(Serialize, Debug, Clone)]
+pub struct SimReport {
+    pub curriculum: String,
+    pub genre: String,
+    pub policy: String,
+    pub sessions: Vec<SessionReport>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SimError {
+    /// The session never reached the trail review.
+    Stuck { session: u32, state: String },
+    BadArgs(String),
+}
+
+impl fmt::Display for SimError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimError::Stuck { session, state } => write!(
+                f, "session {} did not finish within {} frames (stuck in {})", session, MAX_FRAMES, state
+            ),
+            SimError::BadArgs(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for SimError {}
+
+/// Build a windowless app running the game rules from `seed`, with
+/// nobody playing. Resources inserted afterwards replace the plugin's
+/// defaults, as long as it's before the first update.
+pub(crate) fn headless_app(seed: u64, curriculum: &CurriculumSource) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin))
+        .insert_resource(SessionRng::from_seed(seed))
+        .add_plugins(DaydreamPlugin { curriculum: curriculum.clone() });
+    app
+}
+
+/// Build a windowless app running the game rules and the driver.
+pub(crate) fn build_app(config: &SimConfig, session: u32, script: Vec<SimStep>, carried: Option<SaveData>) -> App {
+    let seed = config.seed.wrapping_add(session as u64);
+    let mut app = headless_app(seed, &config.curriculum);
+    app.add_plugins(InputPlugin)
+        .insert_resource(config.store.clone())
+        .insert_resource(ActiveGenre::load(config.genre, &config.curriculum));
+    if let Some(daily) = &config.challenge {
+        challenge::add_challenge(&mut app, ActiveChallenge::new(daily.clone()));
+    }
+    // Breaths come one per frame; nobody is there to pace them
+    app.insert_resource(FocusRules { max_focus: config.max_focus, breath_pace_secs: 0.0, ..default() })
+        .insert_resource(JourneyRules { moments_per_chapter: config.moments_per_chapter })
+        .insert_resource(ReviewClock::Fixed(SIM_EPOCH + session as u64 * config.days_between * DAY_SECS));
+
+    if let Some(data) = carried {
+        app.insert_resource(CarriedProgress(data));
+    }
+    app.insert_resource(SimDriver::new(script, config.policy, seed))
+        .add_systems(Startup, carry_progress.after(crate::save::load_progress))
+        .add_systems(Update, drive_simulation
+            .before(crate::deck::select_card)
+            .before(crate::deck::dismiss_depth)
+            .before(crate::focus::take_rest_breaths)
+            .before(crate::journey::take_branch));
+    app
+}
+
+/// Play every session to its trail review.
+pub fn run(config: &SimConfig) -> Result<SimReport, SimError> {
+    let mut report = SimReport {
+        curriculum: String::new(),
+        // A challenge brings its own genre
+        genre: config.challenge.as_ref().map_or(config.genre, |c| c.genre()).id().to_string(),
+        policy: format!("{:?}", config.policy),
+        sessions: Vec::new(),
+    };
+    let mut carried = None;
+    let mut script = config.script.clone();
+
+    for session in 0..config.sessions {
+        let seed = config.seed.wrapping_add(session as u64);
+        let mut app = build_app(config, session, std::mem::take(&mut script), carried.take());
+
+        let mut frames = 0;
+        let mut rests = 0;
+        let mut previous = GameState::Loading;
+        loop {
+            app.update();
+            frames += 1;
+            let state = app.world().resource::<State<GameState>>().get().clone();
+            if state == GameState::Resting && previous != GameState::Resting {
+                rests += 1;
+            }
+            previous = state.clone();
+            if state == GameState::TrailReview {
+                break;
+            }
+            if frames >= MAX_FRAMES {
+                return Err(SimError::Stuck { session, state: format!("{:?}", state) });
+            }
+        }
+
+        let world = app.world_mut();
+        // Steps the session didn't use carry on into the next one
+        script = world.resource::<SimDriver>().script.iter().copied().collect();
+        let curriculum = world.resource::<Curriculum>().name.clone();
+        let character = world.resource::<CharacterSheet>().clone();
+        let archetype = world.resource::<ArchetypeReading>().clone();
+        let spellbook = world.resource::<SpellBook>().clone();
+        let trail = TrailRecord::from_trail(world.resource::<StudentTrail>(), &curriculum, true);
+
+        let mut data = world.resource::<SavedProgress>().data.clone();
+        data.character = character.clone();
+        data.spellbook = spellbook.clone();
+        data.review_schedule = world.resource::<ReviewSchedule>().clone();
+        let mut words = world.query::<(&WordCard, &SpellPower)>();
+        for (card, power) in words.iter(world) {
+            data.spell_powers.insert(card.word.clone(), power.clone());
+        }
+        data.trail = trail.clone();
+        carried = Some(data);
+
+        report.curriculum = curriculum;
+        report.sessions.push(SessionReport { seed, frames, rests, character, archetype, spellbook, trail });
+    }
+    Ok(report)
+}
+
+/// Read a script file: a JSON list of steps.
+pub fn load_script(path: &Path) -> Result<Vec<SimStep>, SimError> {
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| SimError::BadArgs(format!("{}: {}", path.display(), e)))?;
+    serde_json::from_str(&json)
+        .map_err(|e| SimError::BadArgs(format!("{}: {}", path.display(), e)))
+}
+
+/// Parse the command line into a config and an output path.
+fn config_from_args() -> Result<(SimConfig, Option<PathBuf>), SimError> {
+    let args: Vec<String> = std::env::args().collect();
+    let value = |flag: &str| args.iter().find_map(|a| a.strip_prefix(flag));
+
+    let policy = match value("--policy=") {
+        Some(name) => SimPolicy::parse(name)
+            .ok_or_else(|| SimError::BadArgs(format!("unknown policy '{}'", name)))?,
+        None => SimPolicy::Cast,
+    };
+    let script = match value("--script=") {
+        Some(path) => load_script(Path::new(path))?,
+        None => Vec::new(),
+    };
+    let sessions = match value("--sessions=") {
+        Some(n) => n.parse().map_err(|_| SimError::BadArgs(format!("bad session count '{}'", n)))?,
+        None => 1,
+    };
+    let max_focus = match value("--max-focus=") {
+        Some(n) => n.parse().ok().filter(|n| *n > 0)
+            .ok_or_else(|| SimError::BadArgs(format!("bad max focus '{}'", n)))?,
+        None => FocusRules::default().max_focus,
+    };
+    let moments_per_chapter = match value("--chapter-moments=") {
+        Some(n) => n.parse().ok().filter(|n| *n > 0)
+            .ok_or_else(|| SimError::BadArgs(format!("bad chapter length '{}'", n)))?,
+        None => JourneyRules::default().moments_per_chapter,
+    };
+    let days_between = match value("--days-between=") {
+        Some(n) => n.parse().map_err(|_| SimError::BadArgs(format!("bad day count '{}'", n)))?,
+        None => 1,
+    };
+    // Only touch disk when asked to
+    let store = if value("--save=").is_some() { SaveStore::from_args() } else { SaveStore::Disabled };
+
+    let curriculum = CurriculumSource::from_args();
+    let challenge = ActiveChallenge::from_args(&curriculum).map(|a| a.challenge);
+    let config = SimConfig {
+        genre: ActiveGenre::from_args(&curriculum).genre,
+        curriculum,
+        seed: SessionRng::from_args_or_entropy().seed(),
+        policy,
+        script,
+        sessions,
+        store,
+        max_focus,
+        moments_per_chapter,
+        days_between,
+        challenge,
+    };
+    Ok((config, value("--out=").map(PathBuf::from)))
+}
+
+/// Entry point for `--headless`. Returns the process exit code.
+pub fn run_from_args() -> i32 {
+    let result = config_from_args().and_then(|(config, out)| {
+        let report = run(&config)?;
+        let json = serde_json::to_string_pretty(&report)
+            .map_err(|e| SimError::BadArgs(e.to_string()))?;
+        match out {
+            Some(path) => std::fs::write(&path, json)
+                .map_err(|e| SimError::BadArgs(format!("{}: {}", path.display(), e))),
+            None => {
+                println!("{}", json);
+                Ok(())
+            }
+        }
+    });
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("daydream --headless: {}", e);
+            1
+        }
+    }
+}
+
+// ─── TEST HARNESS ───
+
+/// Driving a headless app from the crate's tests.
+#[cfg(test)]
+pub(crate) mod harness {
+    use super::*;
+
+    /// The frames it takes to load the curriculum and deal the first hand.
+    pub fn deal(app: &mut App) {
+        for _ in 0..5 {
+            app.update();
+        }
+    }
+
+    /// Take an action, and give the rules the frames to apply it.
+    pub fn act(app: &mut App, action: PlayerAction) {
+        app.world_mut().resource_mut::<PendingAction>().action = Some(action);
+        app.update();
+        app.update();
+    }
+
+    pub fn state(app: &App) -> GameState {
+        app.world().resource::<State<GameState>>().get().clone()
+    }
+
+    /// Update until the trail review, giving up after `max_frames`.
+    pub fn play_to_review(app: &mut App, max_frames: u32) {
+        for _ in 0..max_frames {
+            app.update();
+            if state(app) == GameState::TrailReview {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(policy: SimPolicy, script: Vec<SimStep>, sessions: u32) -> SimConfig {
+        SimConfig {
+            curriculum: CurriculumSource::Demo,
+            genre: Genre::Fantasy,
+            seed: 42,
+            policy,
+            script,
+            sessions,
+            store: SaveStore::Disabled,
+            max_focus: FocusRules::default().max_focus,
+            moments_per_chapter: JourneyRules::default().moments_per_chapter,
+            days_between: 1,
+            challenge: None,
+        }
+    }
+
+    #[test]
+    fn test_cast_policy_plays_the_whole_deck() {
+        let report = run(&config(SimPolicy::Cast, Vec::new(), 1)).unwrap();
+        let session = &report.sessions[0];
+        assert_eq!(session.trail.cast_words.len(), 5);
+        assert_eq!(session.trail.visited_words.len(), 5);
+        assert!(session.trail.swipe_history.iter().all(|s| *s == SwipeChoice::Yes));
+        // Three casts fill a moment; the last two close at the review
+        assert_eq!(session.trail.moments.len(), 2);
+        assert!(session.spellbook.entries.iter().all(|e| e.mastery >= MasteryLevel::Experienced));
+    }
+
+    #[test]
+    fn test_deeper_policy_explores_then_casts() {
+        let report = run(&config(SimPolicy::Deeper, Vec::new(), 1)).unwrap();
+        let session = &report.sessions[0];
+        assert_eq!(session.character.total_deeper_swipes, 5);
+        assert_eq!(session.trail.cast_words.len(), 5);
+        assert_eq!(session.character.emergent_class, "The Hermeticist");
+    }
+
+    #[test]
+    fn test_same_seed_same_session() {
+        let a = run(&config(SimPolicy::Random, Vec::new(), 1)).unwrap();
+        let b = run(&config(SimPolicy::Random, Vec::new(), 1)).unwrap();
+        assert_eq!(a.sessions[0].trail.swipe_history, b.sessions[0].trail.swipe_history);
+        assert_eq!(a.sessions[0].trail.visited_words, b.sessions[0].trail.visited_words);
+    }
+
+    #[test]
+    fn test_script_runs_before_policy_and_growth_carries_over() {
+        let script = vec![SimStep::Select(9), SimStep::Swipe(SwipeChoice::No)];
+        let report = run(&config(SimPolicy::Cast, script, 2)).unwrap();
+        assert_eq!(report.sessions[0].trail.swipe_history[0], SwipeChoice::No);
+        // The Recycler brings the skipped word back once the deck runs dry
+        assert_eq!(report.sessions[0].trail.cast_words.len(), 5);
+
+        let first = &report.sessions[0].character;
+        let second = &report.sessions[1].character;
+        assert!(second.words_encountered > first.words_encountered);
+    }
+
+    #[test]
+    fn test_exhausted_hero_rests_and_the_session_still_finishes() {
+        let mut low = config(SimPolicy::Cast, Vec::new(), 1);
+        low.max_focus = 3;
+        let report = run(&low).unwrap();
+        let session = &report.sessions[0];
+        assert!(session.rests >= 2);
+        assert_eq!(session.trail.cast_words.len(), 5);
+
+        let full = run(&config(SimPolicy::Cast, Vec::new(), 1)).unwrap();
+        assert!(full.sessions[0].rests <= 1);
+    }
+
+    #[test]
+    fn test_chapters_end_in_branch_points() {
+        // A longer curriculum than the demo: a chain of ten words
+        let words: Vec<String> = (0..10).map(|i| format!(
+            r#"{{"word": "W{i}", "depth_prompt": "?", "themes": [], "mood": "calm", "story_text": "",
+                "yes_targets": [{yes}], "no_targets": [{no}]}}"#,
+            yes = if i < 9 { format!("\"W{}\"", i + 1) } else { String::new() },
+            no = if i < 8 { format!("\"W{}\"", i + 2) } else { String::new() },
+        )).collect();
+        let json = format!(r#"{{"name": "Chain", "start_word": "W0", "words": [{}]}}"#, words.join(","));
+        let path = std::env::temp_dir().join(format!("daydream-chapters-{}.json", std::process::id()));
+        std::fs::write(&path, json).unwrap();
+
+        let mut chain = config(SimPolicy::Cast, Vec::new(), 1);
+        chain.curriculum = CurriculumSource::File(path.clone());
+        chain.moments_per_chapter = 1;
+        let report = run(&chain);
+        std::fs::remove_file(&path).ok();
+
+        let trail = &report.unwrap().sessions[0].trail;
+        assert_eq!(trail.cast_words.len(), 10);
+        let stages: Vec<JourneyStage> = trail.chapters.iter().map(|c| c.stage).collect();
+        assert_eq!(&stages[..2], &[JourneyStage::OrdinaryWorld, JourneyStage::CallToAdventure]);
+        // Chapters end at branches the student took
+        let branched = trail.chapters.iter().filter(|c| c.branch.is_some()).count();
+        assert!(branched >= 2);
+        assert_eq!(trail.chapters.iter().map(|c| c.moments).sum::<usize>(), trail.moments.len());
+    }
+
+    #[test]
+    fn test_script_steps_parse_from_json() {
+        let steps: Vec<SimStep> = serde_json::from_str(r#"[{"select": 2}, {"swipe": "Deeper"}]"#).unwrap();
+        assert_eq!(steps, vec![SimStep::Select(2), SimStep::Swipe(SwipeChoice::Deeper)]);
+    }
+}
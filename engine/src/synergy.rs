@@ -0,0 +1,274 @@
+// Daydream Engine — Synergy Resolution
+// Words don't exist alone — meaning is relational.
+//
+// Two layers:
+//   Preview:   cards in hand that could resonate with each other or
+//              with the table are shown with their synergy type.
+//   Resonance: when linked words are actually placed in the same story
+//              moment, both grow and a Resonance Thread is woven.
+//
+// The synergy type is never authored — it is inferred from the two
+// words' Channels (see SynergyType::between). A ☆ Star card anchors
+// a chain: every word linked to the Star resonates with every other.
+// An Ally card boosts each word it resonates with: the partner grows
+// as though it had been cast twice.
+
+use bevy::prelude::*;
+use crate::components::*;
+use crate::locale::ActiveLocale;
+use crate::mastery::{ProgressKind, SpellProgress};
+
+/// A card on the crafting table, with what resolution needs to know.
+#[derive(Clone, Debug)]
+pub struct MomentCard {
+    pub entity: Entity,
+    pub word: String,
+    pub channel: Channel,
+    pub symbol: Symbol,
+    pub partners: Vec<String>,
+}
+
+impl MomentCard {
+    /// Synergy links count in either direction.
+    fn linked(&self, other: &MomentCard) -> bool {
+        self.partners.contains(&other.word) || other.partners.contains(&self.word)
+    }
+}
+
+/// Index pairs (i < j) of cards that resonate in this moment:
+/// directly linked words, plus every pair chained through a ☆ Star.
+pub fn resonant_pairs(cards: &[MomentCard]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    let mut push = |i: usize, j: usize| {
+        let pair = (i.min(j), i.max(j));
+        if i != j && !pairs.contains(&pair) {
+            pairs.push(pair);
+        }
+    };
+
+    for i in 0..cards.len() {
+        for j in (i + 1)..cards.len() {
+            if cards[i].linked(&cards[j]) {
+                push(i, j);
+            }
+        }
+    }
+
+    for (anchor, star) in cards.iter().enumerate().filter(|(_, c)| c.symbol == Symbol::Star) {
+        let chain: Vec<usize> = cards.iter().enumerate()
+            .filter(|(i, c)| *i != anchor && c.linked(star))
+            .map(|(i, _)| i)
+            .collect();
+        for (n, &i) in chain.iter().enumerate() {
+            for &j in &chain[n + 1..] {
+                push(i, j);
+            }
+        }
+    }
+
+    pairs
+}
+
+// ─── PREVIEW ────────────────────────────────────────────────
+
+/// System: show which hand cards would resonate with each other
+/// or with cards already on the table. Rebuilt only when either changes.
+pub fn detect_synergies(
+    hand: Res<Hand>,
+    moment: Res<StoryMoment>,
+    words: Query<(&WordCard, &Channel, &SynergyLinks)>,
+    mut synergies: ResMut<ActiveSynergies>,
+) {
+    if !hand.is_changed() && !moment.is_changed() {
+        return;
+    }
+    synergies.synergies.clear();
+
+    let card = |e: &Entity| words.get(*e).ok().map(|(w, ch, links)| (w.word.clone(), *ch, links));
+    let hand_cards: Vec<_> = hand.cards.iter().filter_map(card).collect();
+    let table_cards: Vec<_> = moment.placed_entities().iter().filter_map(card).collect();
+
+    for (i, (word, channel, links)) in hand_cards.iter().enumerate() {
+        let others = hand_cards[i + 1..].iter().chain(table_cards.iter());
+        for (other, other_channel, other_links) in others {
+            let linked = links.links.iter().any(|l| &l.partner == other)
+                || other_links.links.iter().any(|l| &l.partner == word);
+            if !linked {
+                continue;
+            }
+            let synergy_type = SynergyType::between(*channel, *other_channel);
+            synergies.synergies.push(ActiveSynergy {
+                source_word: word.clone(),
+                target_word: other.clone(),
+                synergy_type,
+                bonus: synergy_type.bonus(),
+                flavor_text: synergy_type.flavor(word, other),
+            });
+        }
+    }
+}
+
+// ─── RESONANCE ──────────────────────────────────────────────
+
+/// System: when the table changes, find newly resonating pairs,
+/// credit both words, and add the echo to the story.
+pub fn resolve_moment_synergies(
+    mut moment: ResMut<StoryMoment>,
+    mut slide: ResMut<CurrentSlide>,
+    mut progress: MessageWriter<SpellProgress>,
+    words: Query<(&WordCard, &Channel, &SynergyLinks, &CardType)>,
+    locale: Res<ActiveLocale>,
+) {
+    if !moment.is_changed() {
+        return;
+    }
+
+    let cards: Vec<MomentCard> = moment.placed_cards().iter()
+        .filter_map(|placed| words.get(placed.entity).ok().map(|(w, ch, links, _)| MomentCard {
+            entity: placed.entity,
+            word: w.word.clone(),
+            channel: *ch,
+            symbol: placed.symbol,
+            partners: links.links.iter().map(|l| l.partner.clone()).collect(),
+        }))
+        .collect();
+
+    let mut found = Vec::new();
+    for (i, j) in resonant_pairs(&cards) {
+        let (a, b) = (&cards[i], &cards[j]);
+        let known = moment.resonances.iter()
+            .any(|(x, y, _)| (x == &a.word && y == &b.word) || (x == &b.word && y == &a.word));
+        if known {
+            continue;
+        }
+
+        let synergy_type = SynergyType::between(a.channel, b.channel);
+        found.push((a.word.clone(), b.word.clone(), synergy_type));
+
+        progress.write(SpellProgress {
+            entity: a.entity,
+            kind: ProgressKind::SynergyDiscovered { partner: b.word.clone() },
+        });
+        progress.write(SpellProgress {
+            entity: b.entity,
+            kind: ProgressKind::SynergyDiscovered { partner: a.word.clone() },
+        });
+        let name = |card: &MomentCard| words.get(card.entity).map_or_else(|_| card.word.clone(), |(w, ..)| w.name.clone());
+        slide.story_text.push_str(&format!("\n✦ {}", locale.synergy_flavor(synergy_type, &name(a), &name(b))));
+
+        // An Ally lends its strength to the word beside it
+        let is_ally = |card: &MomentCard| words.get(card.entity).is_ok_and(|(.., t)| *t == CardType::Ally);
+        for (ally, partner) in [(a, b), (b, a)] {
+            if is_ally(ally) {
+                progress.write(SpellProgress {
+                    entity: partner.entity,
+                    kind: ProgressKind::AllyBoost { ally: ally.word.clone() },
+                });
+                slide.story_text.push_str(&format!("\n⚑ {}", locale.format("synergy.ally", &[("ally", &name(ally)), ("word", &name(partner))])));
+            }
+        }
+    }
+
+    if !found.is_empty() {
+        moment.resonances.extend(found);
+        moment.generated_text = moment.assemble_text();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(n: u32, word: &str, symbol: Symbol, partners: &[&str]) -> MomentCard {
+        MomentCard {
+            entity: Entity::from_raw_u32(n).unwrap(),
+            word: word.to_string(),
+            channel: Channel::Body,
+            symbol,
+            partners: partners.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_synergy_type_from_channels() {
+        assert_eq!(SynergyType::between(Channel::Body, Channel::Body), SynergyType::Resonant);
+        assert_eq!(SynergyType::between(Channel::Heart, Channel::Mind), SynergyType::Nourishing);
+        assert_eq!(SynergyType::between(Channel::Body, Channel::Action), SynergyType::Generating);
+        assert_eq!(SynergyType::between(Channel::Mind, Channel::Action), SynergyType::Complementary);
+    }
+
+    #[test]
+    fn test_links_count_in_either_direction() {
+        let cards = vec![
+            card(1, "Presence", Symbol::Stone, &["Patience"]),
+            card(2, "Patience", Symbol::Void, &[]),
+            card(3, "Clarity", Symbol::Void, &[]),
+        ];
+        assert_eq!(resonant_pairs(&cards), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn test_star_anchors_a_chain() {
+        // Neither Patience nor Clarity links to the other, but both link to Bias (☆)
+        let cards = vec![
+            card(1, "Patience", Symbol::Stone, &["Bias"]),
+            card(2, "Bias", Symbol::Star, &["Clarity"]),
+            card(3, "Clarity", Symbol::Void, &[]),
+        ];
+        assert_eq!(resonant_pairs(&cards), vec![(0, 1), (1, 2), (0, 2)]);
+
+        let mut unanchored = cards.clone();
+        unanchored[1].symbol = Symbol::Stone;
+        assert_eq!(resonant_pairs(&unanchored), vec![(0, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn test_an_ally_boosts_the_word_beside_it() {
+        let mut app = App::new();
+        app.add_message::<SpellProgress>()
+            .init_resource::<StoryMoment>()
+            .init_resource::<CurrentSlide>()
+            .init_resource::<ActiveLocale>()
+            .add_systems(Update, resolve_moment_synergies);
+        let mut spawn = |word: &str, partners: &[&str], card_type: CardType| {
+            app.world_mut().spawn((
+                WordCard { word: word.to_string(), name: word.to_string(), ..default() },
+                Channel::Body,
+                SynergyLinks {
+                    links: partners.iter().map(|p| SynergyEntry {
+                        partner: p.to_string(),
+                        synergy_type: SynergyType::Resonant,
+                        bonus: String::new(),
+                    }).collect(),
+                },
+                card_type,
+            )).id()
+        };
+        let ally = spawn("Patience", &["Resilience"], CardType::Ally);
+        let partner = spawn("Resilience", &[], CardType::Spell);
+
+        let mut moment = app.world_mut().resource_mut::<StoryMoment>();
+        moment.place(ally, "Patience", Symbol::Stone);
+        moment.place(partner, "Resilience", Symbol::Spark);
+        app.update();
+
+        let boosts: Vec<(Entity, ProgressKind)> = app.world_mut().resource_mut::<Messages<SpellProgress>>()
+            .drain()
+            .filter(|p| matches!(p.kind, ProgressKind::AllyBoost { .. }))
+            .map(|p| (p.entity, p.kind))
+            .collect();
+        assert_eq!(boosts, vec![(partner, ProgressKind::AllyBoost { ally: "Patience".to_string() })]);
+        assert!(app.world().resource::<CurrentSlide>().story_text.contains("Patience stands with Resilience"));
+
+        // In Spanish, by the words' Spanish names
+        app.insert_resource(ActiveLocale::load("es", &crate::dag::CurriculumSource::Demo));
+        app.world_mut().get_mut::<WordCard>(ally).unwrap().name = "Paciencia".to_string();
+        app.world_mut().get_mut::<WordCard>(partner).unwrap().name = "Resiliencia".to_string();
+        let mut moment = app.world_mut().resource_mut::<StoryMoment>();
+        *moment = StoryMoment::default();
+        moment.place(ally, "Patience", Symbol::Stone);
+        moment.place(partner, "Resilience", Symbol::Spark);
+        app.update();
+        assert!(app.world().resource::<CurrentSlide>().story_text.contains("Paciencia acompaña a Resiliencia"));
+    }
+}
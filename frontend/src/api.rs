@@ -107,14 +107,80 @@ pub async fn save_graph(graph: StoryGraph) -> Result<StoryGraph, String> {
 // --- Bevy ECS Virtue Bridge ---
 
 use common::expert::{ChoiceAction, VirtueSnapshot};
+use gloo_net::http::{Method, Request, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+
+/// The header the backend reads to know whose world a request acts on.
+const PLAYER_ID_HEADER: &str = "X-Player-Id";
+const PLAYER_ID_KEY: &str = "daydream_player_id";
+
+/// This browser's player id: made up on first use and kept in
+/// localStorage, so each student's choices land on their own entity.
+pub fn player_id() -> String {
+    let storage = web_sys::window().and_then(|w| w.local_storage().ok().flatten());
+    if let Some(id) = storage.as_ref().and_then(|s| s.get_item(PLAYER_ID_KEY).ok().flatten()) {
+        return id;
+    }
+    let id = Uuid::new_v4().to_string();
+    if let Some(storage) = storage {
+        let _ = storage.set_item(PLAYER_ID_KEY, &id);
+    }
+    id
+}
 
-pub async fn submit_choice_action(action: ChoiceAction) -> Result<VirtueSnapshot, String> {
-    let res = gloo_net::http::Request::post("http://localhost:3000/api/quest/action")
-        .json(&action)
-        .map_err(|e| e.to_string())?
+/// A request made as this browser's player.
+fn as_player(request: RequestBuilder) -> RequestBuilder {
+    request.header(PLAYER_ID_HEADER, &player_id())
+}
+
+/// Sends a per-player request to `url`, built by `request`. Every
+/// per-player request goes through here, so none reaches the backend
+/// without the id. The backend only knows players who've logged in,
+/// and forgets them when they go idle: on a 404 this logs in, on the
+/// same backend, and sends the request again.
+async fn send_as_player(
+    url: &str,
+    request: impl Fn(RequestBuilder) -> Result<Request, gloo_net::Error>,
+) -> Result<Response, String> {
+    let send = || async {
+        request(as_player(RequestBuilder::new(url)))
+            .map_err(|e| e.to_string())?
+            .send()
+            .await
+            .map_err(|e| e.to_string())
+    };
+    let res = send().await?;
+    if res.status() != 404 {
+        return Ok(res);
+    }
+
+    let base = url.find("/api/").map_or(url, |i| &url[..i]);
+    let login = as_player(Request::post(&format!("{}/api/players/login", base)))
         .send()
         .await
         .map_err(|e| e.to_string())?;
+    if !login.ok() {
+        return Err(format!("Failed to log in: {}", login.status()));
+    }
+    send().await
+}
+
+/// GETs one of this player's resources.
+pub async fn get_as_player<T: DeserializeOwned>(url: &str) -> Result<T, String> {
+    let res = send_as_player(url, |request| request.build()).await?;
+
+    if res.ok() {
+        res.json().await.map_err(|e| e.to_string())
+    } else {
+        Err(format!("Failed to fetch {}: {}", url, res.status()))
+    }
+}
+
+pub async fn submit_choice_action(action: ChoiceAction) -> Result<VirtueSnapshot, String> {
+    let res = send_as_player("http://localhost:3000/api/quest/action", |request| {
+        request.method(Method::POST).json(&action)
+    })
+    .await?;
 
     if res.ok() {
         let snapshot: VirtueSnapshot = res.json().await.map_err(|e| e.to_string())?;
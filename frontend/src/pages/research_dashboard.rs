@@ -1,3 +1,4 @@
+use crate::api::get_as_player;
 use leptos::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -31,18 +32,12 @@ pub fn ResearchDashboard() -> impl IntoView {
     // Fetch data on mount
     Effect::new(move |_| {
         wasm_bindgen_futures::spawn_local(async move {
-            let log_res = reqwest::get("http://192.168.2.141:3000/api/research/log").await;
-            if let Ok(resp) = log_res {
-                if let Ok(log) = resp.json::<ResearchLog>().await {
-                    set_research_log.set(log);
-                }
+            if let Ok(log) = get_as_player::<ResearchLog>("http://192.168.2.141:3000/api/research/log").await {
+                set_research_log.set(log);
             }
 
-            let virtues_res = reqwest::get("http://192.168.2.141:3000/api/research/virtues").await;
-            if let Ok(resp) = virtues_res {
-                if let Ok(v) = resp.json::<VirtueTopology>().await {
-                    set_virtues.set(v);
-                }
+            if let Ok(v) = get_as_player::<VirtueTopology>("http://192.168.2.141:3000/api/research/virtues").await {
+                set_virtues.set(v);
             }
         });
     });